@@ -74,7 +74,7 @@ fn print_fields(iter: VerboseIter, indent: usize) -> Result<(), VerboseDecodeErr
 
         match value {
             Bool(v) => println!("value = {}", v.value),
-            Str(v) => println!("value = {}", v.value),
+            Str(v) => println!("value = {}", v.to_string_lossy()),
             TraceInfo(v) => println!("value = {}", v.value),
             I8(v) => println!("value = {}", v.value),
             I16(v) => println!("value = {}", v.value),
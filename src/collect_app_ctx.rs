@@ -0,0 +1,117 @@
+use crate::*;
+use arrayvec::CapacityError;
+
+/// Walks over the DLT messages in the given buffer and adds all distinct
+/// application id & context id pairs to `out`.
+///
+/// Messages without an extended header are skipped (they contain no
+/// application & context id). Pairs already present in `out` are not
+/// added a second time. The walk stops at the first message that can
+/// not be decoded.
+///
+/// A [`CapacityError`] is returned if there are more distinct pairs
+/// than `out` has capacity for (`out` then contains the first `N` pairs).
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use dlt_parse::collect_app_ctx;
+///
+/// # let buffer: [u8; 0] = [];
+/// let mut pairs = ArrayVec::<([u8; 4], [u8; 4]), 16>::new();
+/// collect_app_ctx(&buffer, &mut pairs).expect("more than 16 app & ctx pairs present");
+/// ```
+pub fn collect_app_ctx<const N: usize>(
+    buf: &[u8],
+    out: &mut ArrayVec<([u8; 4], [u8; 4]), N>,
+) -> Result<(), CapacityError> {
+    for packet in SliceIterator::new(buf) {
+        let packet = match packet {
+            Ok(packet) => packet,
+            Err(_) => break,
+        };
+        if let Some(ext) = packet.extended_header() {
+            let pair = (ext.application_id, ext.context_id);
+            if false == out.contains(&pair) {
+                out.try_push(pair).map_err(|_| CapacityError::new(()))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod collect_app_ctx_tests {
+    use super::*;
+
+    fn packet(buffer: &mut Vec<u8>, ids: Option<([u8; 4], [u8; 4])>) {
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: ids.map(|(app, ctx)| {
+                DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, app, ctx)
+            }),
+        };
+        header.length = header.header_len() + 4;
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn collect_app_ctx() {
+        let mut buffer = Vec::new();
+        packet(&mut buffer, Some((*b"APP1", *b"CTX1")));
+        packet(&mut buffer, Some((*b"APP1", *b"CTX1")));
+        packet(&mut buffer, None);
+        packet(&mut buffer, Some((*b"APP1", *b"CTX2")));
+        packet(&mut buffer, Some((*b"APP2", *b"CTX1")));
+        packet(&mut buffer, Some((*b"APP1", *b"CTX2")));
+
+        // enough capacity
+        {
+            let mut out = ArrayVec::<([u8; 4], [u8; 4]), 3>::new();
+            assert_eq!(Ok(()), super::collect_app_ctx(&buffer, &mut out));
+            assert_eq!(
+                &out[..],
+                &[
+                    (*b"APP1", *b"CTX1"),
+                    (*b"APP1", *b"CTX2"),
+                    (*b"APP2", *b"CTX1"),
+                ]
+            );
+        }
+
+        // not enough capacity
+        {
+            let mut out = ArrayVec::<([u8; 4], [u8; 4]), 2>::new();
+            assert_eq!(
+                Err(CapacityError::new(())),
+                super::collect_app_ctx(&buffer, &mut out)
+            );
+            assert_eq!(&out[..], &[(*b"APP1", *b"CTX1"), (*b"APP1", *b"CTX2")]);
+        }
+
+        // stop at malformed data
+        {
+            let mut buffer = buffer.clone();
+            buffer.extend_from_slice(&[0, 0, 0, 1]);
+            packet(&mut buffer, Some((*b"APP3", *b"CTX3")));
+
+            let mut out = ArrayVec::<([u8; 4], [u8; 4]), 8>::new();
+            assert_eq!(Ok(()), super::collect_app_ctx(&buffer, &mut out));
+            assert_eq!(3, out.len());
+        }
+
+        // empty buffer
+        {
+            let mut out = ArrayVec::<([u8; 4], [u8; 4]), 1>::new();
+            assert_eq!(Ok(()), super::collect_app_ctx(&[], &mut out));
+            assert!(out.is_empty());
+        }
+    }
+}
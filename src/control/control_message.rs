@@ -0,0 +1,482 @@
+use super::*;
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+use crate::{DltControlMessageType, DltMessageType, DltPacketSlice};
+
+/// Decoded non verbose control message (request or response) with a
+/// typed payload for the services supported by the `control` module.
+///
+/// Services without a typed payload (or requests/responses of a
+/// supported service that are not decoded, e.g. the "Get Log Info"
+/// request) are returned as [`ControlMessage::Unknown`].
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::{control::ControlMessage, SliceIterator};
+///
+/// # let data = [0u8; 0];
+/// for packet in SliceIterator::new(&data).filter_map(|p| p.ok()) {
+///     match ControlMessage::from_packet(&packet) {
+///         Some(Ok(ControlMessage::SetLogLevelRequest(req))) => {
+///             println!("set log level {:?}", req.new_log_level)
+///         }
+///         Some(Ok(other)) => println!("control message {:?}", other),
+///         Some(Err(err)) => println!("malformed control message: {}", err),
+///         None => {} // not a control message
+///     }
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ControlMessage<'a> {
+    /// "Set Log Level" request ([`CMD_ID_SET_LOG_LEVEL`]).
+    SetLogLevelRequest(SetLogLevelRequest),
+    /// "Get Log Info" response ([`CMD_ID_GET_LOG_INFO`]).
+    GetLogInfoResponse(GetLogInfoResponse<'a>),
+    /// "Get Local Time" request ([`CMD_ID_GET_LOCAL_TIME`]).
+    GetLocalTimeRequest(GetLocalTimeRequest),
+    /// "Get Local Time" response ([`CMD_ID_GET_LOCAL_TIME`]).
+    GetLocalTimeResponse(GetLocalTimeResponse),
+    /// "Store Configuration" request ([`CMD_ID_STORE_CONFIGURATION`]).
+    StoreConfigurationRequest(StoreConfigurationRequest),
+    /// "Reset to Factory Default" request ([`CMD_ID_RESET_TO_FACTORY_DEFAULT`]).
+    ResetToFactoryDefaultRequest(ResetToFactoryDefaultRequest),
+    /// "Get ECU Software Version" request ([`CMD_ID_GET_SOFTWARE_VERSION`]).
+    GetSoftwareVersionRequest(GetSoftwareVersionRequest),
+    /// "Get ECU Software Version" response ([`CMD_ID_GET_SOFTWARE_VERSION`]).
+    GetSoftwareVersionResponse(GetSoftwareVersionResponse<'a>),
+    /// "Get Default Trace Status" request ([`CMD_ID_GET_DEFAULT_TRACE_STATUS`]).
+    GetDefaultTraceStatusRequest(GetDefaultTraceStatusRequest),
+    /// "Get Default Trace Status" response ([`CMD_ID_GET_DEFAULT_TRACE_STATUS`]).
+    GetDefaultTraceStatusResponse(GetDefaultTraceStatusResponse),
+    /// "Timezone" response ([`CMD_ID_TIMEZONE`]).
+    TimezoneResponse(TimezoneResponse),
+    /// Control message without a typed payload.
+    Unknown {
+        /// Request or response.
+        msg_type: DltControlMessageType,
+        /// Service id of the message.
+        service_id: u32,
+        /// Payload following the service id.
+        payload: &'a [u8],
+    },
+}
+
+impl<'a> ControlMessage<'a> {
+    /// Decodes the control message contained in the given packet.
+    ///
+    /// `None` is returned if the packet is not a non verbose control
+    /// message. An error is returned if the payload is too short to
+    /// contain the service id or if the payload of a supported service
+    /// can not be decoded.
+    pub fn from_packet(
+        packet: &DltPacketSlice<'a>,
+    ) -> Option<Result<ControlMessage<'a>, VerboseDecodeError>> {
+        use DltControlMessageType::*;
+
+        let msg_type = match packet.message_type() {
+            Some(DltMessageType::Control(msg_type)) if false == packet.is_verbose() => msg_type,
+            _ => return None,
+        };
+        let Some((service_id, payload)) = packet.message_id_and_payload() else {
+            return Some(Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: 4,
+                    actual_size: packet.payload().len(),
+                },
+            )));
+        };
+        let is_big_endian = packet.is_big_endian();
+
+        Some(match (msg_type, service_id) {
+            (Request, CMD_ID_SET_LOG_LEVEL) => {
+                SetLogLevelRequest::from_slice(payload).map(ControlMessage::SetLogLevelRequest)
+            }
+            (Response, CMD_ID_GET_LOG_INFO) => {
+                GetLogInfoResponse::from_slice(payload, is_big_endian)
+                    .map(ControlMessage::GetLogInfoResponse)
+            }
+            (Request, CMD_ID_GET_LOCAL_TIME) => {
+                EmptyRequest::from_slice(payload).map(ControlMessage::GetLocalTimeRequest)
+            }
+            (Response, CMD_ID_GET_LOCAL_TIME) => {
+                GetLocalTimeResponse::from_slice(payload).map(ControlMessage::GetLocalTimeResponse)
+            }
+            (Request, CMD_ID_STORE_CONFIGURATION) => {
+                EmptyRequest::from_slice(payload).map(ControlMessage::StoreConfigurationRequest)
+            }
+            (Request, CMD_ID_RESET_TO_FACTORY_DEFAULT) => {
+                EmptyRequest::from_slice(payload).map(ControlMessage::ResetToFactoryDefaultRequest)
+            }
+            (Request, CMD_ID_GET_SOFTWARE_VERSION) => {
+                EmptyRequest::from_slice(payload).map(ControlMessage::GetSoftwareVersionRequest)
+            }
+            (Response, CMD_ID_GET_SOFTWARE_VERSION) => {
+                GetSoftwareVersionResponse::from_slice(payload, is_big_endian)
+                    .map(ControlMessage::GetSoftwareVersionResponse)
+            }
+            (Request, CMD_ID_GET_DEFAULT_TRACE_STATUS) => {
+                EmptyRequest::from_slice(payload).map(ControlMessage::GetDefaultTraceStatusRequest)
+            }
+            (Response, CMD_ID_GET_DEFAULT_TRACE_STATUS) => {
+                GetDefaultTraceStatusResponse::from_slice(payload)
+                    .map(ControlMessage::GetDefaultTraceStatusResponse)
+            }
+            (Response, CMD_ID_TIMEZONE) => TimezoneResponse::from_slice(payload, is_big_endian)
+                .map(ControlMessage::TimezoneResponse),
+            (msg_type, service_id) => Ok(ControlMessage::Unknown {
+                msg_type,
+                service_id,
+                payload,
+            }),
+        })
+    }
+
+    /// Returns if the message is a request or a response.
+    pub fn msg_type(&self) -> DltControlMessageType {
+        use ControlMessage::*;
+        match self {
+            SetLogLevelRequest(_)
+            | GetLocalTimeRequest(_)
+            | StoreConfigurationRequest(_)
+            | ResetToFactoryDefaultRequest(_)
+            | GetSoftwareVersionRequest(_)
+            | GetDefaultTraceStatusRequest(_) => DltControlMessageType::Request,
+            GetLogInfoResponse(_)
+            | GetLocalTimeResponse(_)
+            | GetSoftwareVersionResponse(_)
+            | GetDefaultTraceStatusResponse(_)
+            | TimezoneResponse(_) => DltControlMessageType::Response,
+            Unknown { msg_type, .. } => *msg_type,
+        }
+    }
+
+    /// Returns the service id of the message.
+    pub fn service_id(&self) -> u32 {
+        use ControlMessage::*;
+        match self {
+            SetLogLevelRequest(_) => CMD_ID_SET_LOG_LEVEL,
+            GetLogInfoResponse(_) => CMD_ID_GET_LOG_INFO,
+            GetLocalTimeRequest(_) | GetLocalTimeResponse(_) => CMD_ID_GET_LOCAL_TIME,
+            StoreConfigurationRequest(_) => CMD_ID_STORE_CONFIGURATION,
+            ResetToFactoryDefaultRequest(_) => CMD_ID_RESET_TO_FACTORY_DEFAULT,
+            GetSoftwareVersionRequest(_) | GetSoftwareVersionResponse(_) => {
+                CMD_ID_GET_SOFTWARE_VERSION
+            }
+            GetDefaultTraceStatusRequest(_) | GetDefaultTraceStatusResponse(_) => {
+                CMD_ID_GET_DEFAULT_TRACE_STATUS
+            }
+            TimezoneResponse(_) => CMD_ID_TIMEZONE,
+            Unknown { service_id, .. } => *service_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DltExtendedHeader, DltHeader, DltLogLevel};
+    use alloc::vec::Vec;
+
+    fn packet(
+        is_big_endian: bool,
+        message_type: DltMessageType,
+        service_id: u32,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let mut header = DltHeader {
+            is_big_endian,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: Some(
+                DltExtendedHeader::new_non_verbose(message_type, *b"APP1", *b"CTX1").unwrap(),
+            ),
+        };
+        header.length = header.header_len() + 4 + payload.len() as u16;
+        let mut bytes = header.to_bytes().to_vec();
+        if is_big_endian {
+            bytes.extend_from_slice(&service_id.to_be_bytes());
+        } else {
+            bytes.extend_from_slice(&service_id.to_le_bytes());
+        }
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn control_packet(
+        msg_type: DltControlMessageType,
+        service_id: u32,
+        payload: &[u8],
+        is_big_endian: bool,
+    ) -> Vec<u8> {
+        packet(
+            is_big_endian,
+            DltMessageType::Control(msg_type),
+            service_id,
+            payload,
+        )
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Result<ControlMessage<'_>, VerboseDecodeError>> {
+        ControlMessage::from_packet(&DltPacketSlice::from_slice(bytes).unwrap())
+    }
+
+    #[test]
+    fn from_packet() {
+        use DltControlMessageType::*;
+
+        for is_big_endian in [false, true] {
+            // set log level request
+            {
+                let req = SetLogLevelRequest {
+                    app_id: *b"APP1",
+                    context_id: *b"CTX1",
+                    new_log_level: DltLogLevelConfig::Level(DltLogLevel::Warn),
+                    com_interface: *b"remo",
+                };
+                let bytes = control_packet(
+                    Request,
+                    CMD_ID_SET_LOG_LEVEL,
+                    &req.to_bytes(),
+                    is_big_endian,
+                );
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_eq!(ControlMessage::SetLogLevelRequest(req), msg);
+                assert_eq!(Request, msg.msg_type());
+                assert_eq!(CMD_ID_SET_LOG_LEVEL, msg.service_id());
+
+                // decoding error
+                let bytes = control_packet(Request, CMD_ID_SET_LOG_LEVEL, &[1, 2], is_big_endian);
+                assert_matches!(
+                    decode(&bytes),
+                    Some(Err(VerboseDecodeError::UnexpectedEndOfSlice(_)))
+                );
+            }
+
+            // empty requests
+            for (service_id, expected) in [
+                (
+                    CMD_ID_GET_LOCAL_TIME,
+                    ControlMessage::GetLocalTimeRequest(EmptyRequest),
+                ),
+                (
+                    CMD_ID_STORE_CONFIGURATION,
+                    ControlMessage::StoreConfigurationRequest(EmptyRequest),
+                ),
+                (
+                    CMD_ID_RESET_TO_FACTORY_DEFAULT,
+                    ControlMessage::ResetToFactoryDefaultRequest(EmptyRequest),
+                ),
+                (
+                    CMD_ID_GET_SOFTWARE_VERSION,
+                    ControlMessage::GetSoftwareVersionRequest(EmptyRequest),
+                ),
+                (
+                    CMD_ID_GET_DEFAULT_TRACE_STATUS,
+                    ControlMessage::GetDefaultTraceStatusRequest(EmptyRequest),
+                ),
+            ] {
+                let bytes = control_packet(Request, service_id, &[], is_big_endian);
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_eq!(expected, msg);
+                assert_eq!(Request, msg.msg_type());
+                assert_eq!(service_id, msg.service_id());
+
+                // trailing bytes (e.g. a repeated service id)
+                let bytes = control_packet(
+                    Request,
+                    service_id,
+                    &service_id.to_be_bytes(),
+                    is_big_endian,
+                );
+                assert_eq!(
+                    Some(Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                        expected_len: 0,
+                        actual_len: 4,
+                    })),
+                    decode(&bytes)
+                );
+            }
+
+            // get log info response
+            {
+                let payload = [GetLogInfoResponse::STATUS_NO_MATCHING_CONTEXTS.to_byte()];
+                let bytes = control_packet(Response, CMD_ID_GET_LOG_INFO, &payload, is_big_endian);
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_eq!(
+                    ControlMessage::GetLogInfoResponse(
+                        GetLogInfoResponse::from_slice(&payload, is_big_endian).unwrap()
+                    ),
+                    msg
+                );
+                assert_eq!(Response, msg.msg_type());
+                assert_eq!(CMD_ID_GET_LOG_INFO, msg.service_id());
+            }
+
+            // get software version response
+            {
+                let mut payload = Vec::from([0u8]);
+                if is_big_endian {
+                    payload.extend_from_slice(&3u32.to_be_bytes());
+                } else {
+                    payload.extend_from_slice(&3u32.to_le_bytes());
+                }
+                payload.extend_from_slice(b"1.0");
+                let bytes = control_packet(
+                    Response,
+                    CMD_ID_GET_SOFTWARE_VERSION,
+                    &payload,
+                    is_big_endian,
+                );
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_matches!(
+                    &msg,
+                    ControlMessage::GetSoftwareVersionResponse(r) if r.version() == Some("1.0")
+                );
+                assert_eq!(Response, msg.msg_type());
+                assert_eq!(CMD_ID_GET_SOFTWARE_VERSION, msg.service_id());
+            }
+
+            // get default trace status response
+            {
+                let bytes = control_packet(
+                    Response,
+                    CMD_ID_GET_DEFAULT_TRACE_STATUS,
+                    &[0, 1],
+                    is_big_endian,
+                );
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_matches!(
+                    &msg,
+                    ControlMessage::GetDefaultTraceStatusResponse(r) if r.trace_status() == Some(true)
+                );
+                assert_eq!(Response, msg.msg_type());
+                assert_eq!(CMD_ID_GET_DEFAULT_TRACE_STATUS, msg.service_id());
+            }
+
+            // get local time response
+            {
+                let bytes = control_packet(Response, CMD_ID_GET_LOCAL_TIME, &[0], is_big_endian);
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_eq!(
+                    ControlMessage::GetLocalTimeResponse(
+                        GetLocalTimeResponse::from_slice(&[0]).unwrap()
+                    ),
+                    msg
+                );
+                assert_eq!(Response, msg.msg_type());
+                assert_eq!(CMD_ID_GET_LOCAL_TIME, msg.service_id());
+            }
+
+            // timezone response
+            {
+                let mut payload = Vec::from([0u8]);
+                if is_big_endian {
+                    payload.extend_from_slice(&(-3600i32).to_be_bytes());
+                } else {
+                    payload.extend_from_slice(&(-3600i32).to_le_bytes());
+                }
+                payload.push(1);
+                let bytes = control_packet(Response, CMD_ID_TIMEZONE, &payload, is_big_endian);
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_matches!(
+                    &msg,
+                    ControlMessage::TimezoneResponse(r)
+                        if r.utc_offset_secs() == Some(-3600) && r.is_dst() == Some(true)
+                );
+                assert_eq!(Response, msg.msg_type());
+                assert_eq!(CMD_ID_TIMEZONE, msg.service_id());
+            }
+
+            // unknown services & directions
+            for (msg_type, service_id) in [
+                (Request, CMD_ID_SET_TRACE_STATUS),
+                (Response, CMD_ID_SET_LOG_LEVEL),
+                (Request, CMD_ID_GET_LOG_INFO),
+                (Request, CMD_ID_TIMEZONE),
+                (Response, 0x1234),
+            ] {
+                let bytes = control_packet(msg_type, service_id, &[1, 2, 3], is_big_endian);
+                let msg = decode(&bytes).unwrap().unwrap();
+                assert_eq!(
+                    ControlMessage::Unknown {
+                        msg_type,
+                        service_id,
+                        payload: &[1, 2, 3],
+                    },
+                    msg
+                );
+                assert_eq!(msg_type, msg.msg_type());
+                assert_eq!(service_id, msg.service_id());
+            }
+        }
+    }
+
+    #[test]
+    fn from_packet_non_control() {
+        // log message
+        let bytes = packet(
+            true,
+            DltMessageType::Log(DltLogLevel::Info),
+            CMD_ID_SET_LOG_LEVEL,
+            &[],
+        );
+        let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+        assert_eq!(None, ControlMessage::from_packet(&slice));
+
+        // verbose control message
+        let mut header = DltHeader {
+            extended_header: Some(
+                DltExtendedHeader::new_non_verbose(
+                    DltMessageType::Control(DltControlMessageType::Request),
+                    *b"APP1",
+                    *b"CTX1",
+                )
+                .unwrap(),
+            ),
+            ..Default::default()
+        };
+        header
+            .extended_header
+            .as_mut()
+            .unwrap()
+            .set_is_verbose(true);
+        header.length = header.header_len() + 4;
+        let mut bytes = header.to_bytes().to_vec();
+        bytes.extend_from_slice(&[0, 0, 0, 1]);
+        let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+        assert_eq!(None, ControlMessage::from_packet(&slice));
+    }
+
+    #[test]
+    fn from_packet_missing_service_id() {
+        let mut header = DltHeader {
+            extended_header: Some(
+                DltExtendedHeader::new_non_verbose(
+                    DltMessageType::Control(DltControlMessageType::Request),
+                    *b"APP1",
+                    *b"CTX1",
+                )
+                .unwrap(),
+            ),
+            ..Default::default()
+        };
+        header.length = header.header_len() + 2;
+        let mut bytes = header.to_bytes().to_vec();
+        bytes.extend_from_slice(&[0, 1]);
+        let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+        assert_eq!(
+            Some(Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: 4,
+                    actual_size: 2,
+                }
+            ))),
+            ControlMessage::from_packet(&slice)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+/// Status returned in the first byte of control message responses.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ControlServiceStatus {
+    /// Request was processed successfully (`0`).
+    Ok,
+    /// Service is not supported (`1`).
+    NotSupported,
+    /// An error occurred while processing the request (`2`).
+    Error,
+    /// Service specific status value (e.g. the status values `3` to `9`
+    /// used by "Get Log Info" responses).
+    Other(u8),
+}
+
+impl ControlServiceStatus {
+    /// Decodes the status byte of a control response.
+    #[inline]
+    pub fn from_byte(value: u8) -> ControlServiceStatus {
+        use ControlServiceStatus::*;
+        match value {
+            0 => Ok,
+            1 => NotSupported,
+            2 => Error,
+            other => Other(other),
+        }
+    }
+
+    /// Returns the byte value of the status.
+    #[inline]
+    pub fn to_byte(&self) -> u8 {
+        use ControlServiceStatus::*;
+        match self {
+            Ok => 0,
+            NotSupported => 1,
+            Error => 2,
+            Other(value) => *value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = ControlServiceStatus::Other(3);
+        assert_eq!(v, v.clone());
+        assert_eq!("Other(3)", format!("{:?}", v));
+    }
+
+    #[test]
+    fn from_byte_to_byte() {
+        use ControlServiceStatus::*;
+        for (byte, status) in [(0, Ok), (1, NotSupported), (2, Error)] {
+            assert_eq!(status, ControlServiceStatus::from_byte(byte));
+            assert_eq!(byte, status.to_byte());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_byte_other(value in 3u8..=u8::MAX) {
+            let status = ControlServiceStatus::from_byte(value);
+            assert_eq!(ControlServiceStatus::Other(value), status);
+            assert_eq!(value, status.to_byte());
+        }
+    }
+}
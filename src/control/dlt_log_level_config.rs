@@ -0,0 +1,121 @@
+use crate::DltLogLevel;
+
+/// Log level as used in control messages (e.g. "Set Log Level" or
+/// "Get Log Info"), where the level byte can also contain the
+/// sentinel values for "use the default" & "block all messages".
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum DltLogLevelConfig {
+    /// Use the default log level (encoded as `-1`/`0xff`).
+    Default,
+    /// Block all log messages, also called "off" (encoded as `0`).
+    Block,
+    /// Pass messages up to the given log level.
+    Level(DltLogLevel),
+}
+
+impl DltLogLevelConfig {
+    /// Byte value representing [`DltLogLevelConfig::Default`] (`-1`).
+    pub const DEFAULT_BYTE: u8 = 0xff;
+
+    /// Byte value representing [`DltLogLevelConfig::Block`].
+    pub const BLOCK_BYTE: u8 = 0;
+
+    /// Decodes a log level byte of a control message.
+    ///
+    /// Returns `None` if the byte is neither a sentinel value
+    /// nor a known log level.
+    pub fn from_byte(value: u8) -> Option<DltLogLevelConfig> {
+        use DltLogLevel::*;
+        use DltLogLevelConfig::*;
+        match value {
+            DltLogLevelConfig::DEFAULT_BYTE => Some(Default),
+            DltLogLevelConfig::BLOCK_BYTE => Some(Block),
+            0x1 => Some(Level(Fatal)),
+            0x2 => Some(Level(Error)),
+            0x3 => Some(Level(Warn)),
+            0x4 => Some(Level(Info)),
+            0x5 => Some(Level(Debug)),
+            0x6 => Some(Level(Verbose)),
+            _ => None,
+        }
+    }
+
+    /// Returns the byte value representing the log level config in
+    /// control messages.
+    #[inline]
+    pub fn to_byte(&self) -> u8 {
+        use DltLogLevelConfig::*;
+        match self {
+            Default => DltLogLevelConfig::DEFAULT_BYTE,
+            Block => DltLogLevelConfig::BLOCK_BYTE,
+            Level(level) => *level as u8,
+        }
+    }
+}
+
+impl From<DltLogLevel> for DltLogLevelConfig {
+    #[inline]
+    fn from(value: DltLogLevel) -> Self {
+        DltLogLevelConfig::Level(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn clone_eq() {
+        let v = DltLogLevelConfig::Level(DltLogLevel::Info);
+        assert_eq!(v, v.clone());
+        assert_ne!(DltLogLevelConfig::Default, DltLogLevelConfig::Block);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!("Default", format!("{:?}", DltLogLevelConfig::Default));
+        assert_eq!("Block", format!("{:?}", DltLogLevelConfig::Block));
+        assert_eq!(
+            "Level(Warn)",
+            format!("{:?}", DltLogLevelConfig::Level(DltLogLevel::Warn))
+        );
+    }
+
+    #[test]
+    fn from_byte_to_byte() {
+        use DltLogLevel::*;
+        use DltLogLevelConfig::*;
+
+        let values = [
+            (0xff, Default),
+            (0x00, Block),
+            (0x01, Level(Fatal)),
+            (0x02, Level(Error)),
+            (0x03, Level(Warn)),
+            (0x04, Level(Info)),
+            (0x05, Level(Debug)),
+            (0x06, Level(Verbose)),
+        ];
+        for (byte, config) in values {
+            assert_eq!(Some(config), DltLogLevelConfig::from_byte(byte));
+            assert_eq!(byte, config.to_byte());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_byte_unknown(value in 0x07u8..0xff) {
+            assert_eq!(None, DltLogLevelConfig::from_byte(value));
+        }
+    }
+
+    #[test]
+    fn from_log_level() {
+        assert_eq!(
+            DltLogLevelConfig::Level(DltLogLevel::Debug),
+            DltLogLevelConfig::from(DltLogLevel::Debug)
+        );
+    }
+}
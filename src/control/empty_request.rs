@@ -0,0 +1,94 @@
+use crate::error::VerboseDecodeError;
+
+/// Payload of a control request that contains no data besides
+/// the service id (e.g. "Store Configuration" or "Reset to Factory Default").
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct EmptyRequest;
+
+/// Payload of a "Store Configuration" ([`super::CMD_ID_STORE_CONFIGURATION`]) request.
+pub type StoreConfigurationRequest = EmptyRequest;
+
+/// Payload of a "Reset to Factory Default" ([`super::CMD_ID_RESET_TO_FACTORY_DEFAULT`]) request.
+pub type ResetToFactoryDefaultRequest = EmptyRequest;
+
+/// Payload of a "Get Software Version" ([`super::CMD_ID_GET_SOFTWARE_VERSION`]) request.
+pub type GetSoftwareVersionRequest = EmptyRequest;
+
+/// Payload of a "Get Local Time" ([`super::CMD_ID_GET_LOCAL_TIME`]) request.
+pub type GetLocalTimeRequest = EmptyRequest;
+
+/// Payload of a "Get Default Trace Status" ([`super::CMD_ID_GET_DEFAULT_TRACE_STATUS`]) request.
+pub type GetDefaultTraceStatusRequest = EmptyRequest;
+
+impl EmptyRequest {
+    /// Validates that the given control message payload (following
+    /// the 4 byte service id) is empty.
+    ///
+    /// Any non empty slice results in an
+    /// [`VerboseDecodeError::UnexpectedTrailingBytes`] error.
+    pub fn from_slice(slice: &[u8]) -> Result<EmptyRequest, VerboseDecodeError> {
+        if slice.is_empty() {
+            Ok(EmptyRequest)
+        } else {
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 0,
+                actual_len: slice.len(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = EmptyRequest;
+        assert_eq!(v, v.clone());
+        assert_eq!("EmptyRequest", format!("{:?}", v));
+    }
+
+    #[test]
+    fn from_slice() {
+        // empty
+        assert_eq!(Ok(EmptyRequest), EmptyRequest::from_slice(&[]));
+
+        // trailing bytes
+        for len in 1..=8 {
+            let buffer = [0u8; 8];
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: 0,
+                    actual_len: len,
+                }),
+                EmptyRequest::from_slice(&buffer[..len])
+            );
+        }
+    }
+
+    #[test]
+    fn request_aliases() {
+        assert_eq!(
+            Ok(StoreConfigurationRequest {}),
+            StoreConfigurationRequest::from_slice(&[])
+        );
+        assert_eq!(
+            Ok(ResetToFactoryDefaultRequest {}),
+            ResetToFactoryDefaultRequest::from_slice(&[])
+        );
+        assert_eq!(
+            Ok(GetSoftwareVersionRequest {}),
+            GetSoftwareVersionRequest::from_slice(&[])
+        );
+        assert_eq!(
+            Ok(GetLocalTimeRequest {}),
+            GetLocalTimeRequest::from_slice(&[])
+        );
+        assert_eq!(
+            Ok(GetDefaultTraceStatusRequest {}),
+            GetDefaultTraceStatusRequest::from_slice(&[])
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use super::ControlServiceStatus;
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+
+/// Payload of a "Get Default Trace Status" ([`super::CMD_ID_GET_DEFAULT_TRACE_STATUS`])
+/// control response.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GetDefaultTraceStatusResponse {
+    status: ControlServiceStatus,
+    trace_status: Option<bool>,
+}
+
+impl GetDefaultTraceStatusResponse {
+    /// Decodes the response from the control message payload following
+    /// the 4 byte service id.
+    ///
+    /// The trace status byte is only expected if the status is
+    /// [`ControlServiceStatus::Ok`].
+    pub fn from_slice(slice: &[u8]) -> Result<GetDefaultTraceStatusResponse, VerboseDecodeError> {
+        let status = ControlServiceStatus::from_byte(*slice.first().ok_or(
+            VerboseDecodeError::UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                layer: Layer::ControlPayload,
+                minimum_size: 1,
+                actual_size: 0,
+            }),
+        )?);
+
+        let expected_len = if ControlServiceStatus::Ok == status {
+            2
+        } else {
+            1
+        };
+        if slice.len() < expected_len {
+            return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: expected_len,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        if slice.len() > expected_len {
+            return Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len,
+                actual_len: slice.len(),
+            });
+        }
+
+        let trace_status = if ControlServiceStatus::Ok == status {
+            match slice[1] {
+                0 => Some(false),
+                1 => Some(true),
+                value => return Err(VerboseDecodeError::InvalidBoolValue(value)),
+            }
+        } else {
+            None
+        };
+
+        Ok(GetDefaultTraceStatusResponse {
+            status,
+            trace_status,
+        })
+    }
+
+    /// Status of the response.
+    #[inline]
+    pub fn status(&self) -> ControlServiceStatus {
+        self.status
+    }
+
+    /// Default trace status (`true` if tracing is enabled). Only present
+    /// if the status is [`ControlServiceStatus::Ok`].
+    #[inline]
+    pub fn trace_status(&self) -> Option<bool> {
+        self.trace_status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = GetDefaultTraceStatusResponse::from_slice(&[0, 1]).unwrap();
+        assert_eq!(v, v.clone());
+        assert_eq!(
+            "GetDefaultTraceStatusResponse { status: Ok, trace_status: Some(true) }",
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        // enabled & disabled
+        for (byte, expected) in [(0, false), (1, true)] {
+            let v = GetDefaultTraceStatusResponse::from_slice(&[0, byte]).unwrap();
+            assert_eq!(ControlServiceStatus::Ok, v.status());
+            assert_eq!(Some(expected), v.trace_status());
+        }
+
+        // error statuses without trace status
+        for status in [1, 2] {
+            let v = GetDefaultTraceStatusResponse::from_slice(&[status]).unwrap();
+            assert_eq!(ControlServiceStatus::from_byte(status), v.status());
+            assert_eq!(None, v.trace_status());
+        }
+
+        // invalid trace status
+        assert_eq!(
+            Err(VerboseDecodeError::InvalidBoolValue(2)),
+            GetDefaultTraceStatusResponse::from_slice(&[0, 2])
+        );
+
+        // too short
+        for data in [&[][..], &[0][..]] {
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                    UnexpectedEndOfSliceError {
+                        layer: Layer::ControlPayload,
+                        minimum_size: if data.is_empty() { 1 } else { 2 },
+                        actual_size: data.len(),
+                    }
+                )),
+                GetDefaultTraceStatusResponse::from_slice(data)
+            );
+        }
+
+        // trailing bytes
+        assert_eq!(
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 2,
+                actual_len: 3
+            }),
+            GetDefaultTraceStatusResponse::from_slice(&[0, 1, 0])
+        );
+        assert_eq!(
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 1,
+                actual_len: 2
+            }),
+            GetDefaultTraceStatusResponse::from_slice(&[1, 0])
+        );
+    }
+}
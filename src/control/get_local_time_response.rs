@@ -0,0 +1,87 @@
+use super::ControlServiceStatus;
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+
+/// Payload of a "Get Local Time" ([`super::CMD_ID_GET_LOCAL_TIME`])
+/// control response.
+///
+/// The response only contains a status. The local time of the ECU is
+/// transported via the timestamp in the DLT header of the response
+/// (and the storage header if the message was recorded by a logger),
+/// which allows to correlate the relative DLT timestamps with the
+/// wall-clock time.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GetLocalTimeResponse {
+    status: ControlServiceStatus,
+}
+
+impl GetLocalTimeResponse {
+    /// Decodes the response from the control message payload following
+    /// the 4 byte service id.
+    pub fn from_slice(slice: &[u8]) -> Result<GetLocalTimeResponse, VerboseDecodeError> {
+        match slice.len() {
+            0 => Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: 1,
+                    actual_size: 0,
+                },
+            )),
+            1 => Ok(GetLocalTimeResponse {
+                status: ControlServiceStatus::from_byte(slice[0]),
+            }),
+            len => Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 1,
+                actual_len: len,
+            }),
+        }
+    }
+
+    /// Status of the response.
+    #[inline]
+    pub fn status(&self) -> ControlServiceStatus {
+        self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = GetLocalTimeResponse::from_slice(&[0]).unwrap();
+        assert_eq!(v, v.clone());
+        assert_eq!("GetLocalTimeResponse { status: Ok }", format!("{:?}", v));
+    }
+
+    #[test]
+    fn from_slice() {
+        // statuses
+        for status in [0, 1, 2] {
+            let v = GetLocalTimeResponse::from_slice(&[status]).unwrap();
+            assert_eq!(ControlServiceStatus::from_byte(status), v.status());
+        }
+
+        // too short
+        assert_eq!(
+            Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: 1,
+                    actual_size: 0,
+                }
+            )),
+            GetLocalTimeResponse::from_slice(&[])
+        );
+
+        // trailing bytes
+        assert_eq!(
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 1,
+                actual_len: 2
+            }),
+            GetLocalTimeResponse::from_slice(&[0, 0])
+        );
+    }
+}
@@ -0,0 +1,573 @@
+use super::{ControlServiceStatus, DltLogLevelConfig};
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+use crate::verbose::FieldSlicer;
+
+/// Payload of a "Get Log Info" ([`super::CMD_ID_GET_LOG_INFO`]) control response.
+///
+/// For the statuses 3 to 7 the response contains a list of applications
+/// and their contexts. Which fields are present for each context depends
+/// on the status:
+///
+/// * `3`: only the application & context ids
+/// * `4`: ids & log levels
+/// * `5`: ids & trace statuses
+/// * `6`: ids, log levels & trace statuses
+/// * `7`: ids, log levels, trace statuses & descriptions
+///
+/// The list is validated in [`GetLogInfoResponse::from_slice`] and can
+/// afterwards be iterated without copying via [`GetLogInfoResponse::apps`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GetLogInfoResponse<'a> {
+    status: ControlServiceStatus,
+    is_big_endian: bool,
+    num_apps: u16,
+    apps_data: &'a [u8],
+    com_interface: Option<[u8; 4]>,
+}
+
+impl<'a> GetLogInfoResponse<'a> {
+    /// Status if the service is not supported.
+    pub const STATUS_NOT_SUPPORTED: ControlServiceStatus = ControlServiceStatus::NotSupported;
+    /// Status if an error occurred.
+    pub const STATUS_ERROR: ControlServiceStatus = ControlServiceStatus::Error;
+    /// Status if no contexts matched the request.
+    pub const STATUS_NO_MATCHING_CONTEXTS: ControlServiceStatus = ControlServiceStatus::Other(8);
+    /// Status if the response data would exceed the maximum message size.
+    pub const STATUS_OVERFLOW: ControlServiceStatus = ControlServiceStatus::Other(9);
+
+    /// Decodes a "Get Log Info" response from the control message payload
+    /// following the 4 byte service id.
+    ///
+    /// `is_big_endian` has to be set based on the DLT header of the message
+    /// as the counts & description lengths are encoded in the endianness
+    /// of the message payload.
+    ///
+    /// The 4 byte com interface is required after an application list
+    /// & optional for the other statuses.
+    pub fn from_slice(
+        slice: &'a [u8],
+        is_big_endian: bool,
+    ) -> Result<GetLogInfoResponse<'a>, VerboseDecodeError> {
+        let mut slicer = FieldSlicer::with_layer(slice, 0, Layer::ControlPayload);
+        let status = ControlServiceStatus::from_byte(slicer.read_u8()?);
+
+        if false == has_app_list(status) {
+            // no application list present, only the com interface (if any)
+            let com_interface = match slicer.rest().len() {
+                0 => None,
+                1..=3 => {
+                    return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                        UnexpectedEndOfSliceError {
+                            layer: Layer::ControlPayload,
+                            minimum_size: 5,
+                            actual_size: slice.len(),
+                        },
+                    ))
+                }
+                _ => Some(slicer.read_4bytes()?),
+            };
+            check_no_trailing_bytes(&slicer, slice)?;
+            return Ok(GetLogInfoResponse {
+                status,
+                is_big_endian,
+                num_apps: 0,
+                apps_data: &[],
+                com_interface,
+            });
+        }
+
+        let num_apps = slicer.read_u16(is_big_endian)?;
+        let apps_start = slicer.offset();
+        for _ in 0..num_apps {
+            read_app(&mut slicer, status, is_big_endian)?;
+        }
+        let apps_data = &slice[apps_start..slicer.offset()];
+        let com_interface = Some(slicer.read_4bytes()?);
+        check_no_trailing_bytes(&slicer, slice)?;
+
+        Ok(GetLogInfoResponse {
+            status,
+            is_big_endian,
+            num_apps,
+            apps_data,
+            com_interface,
+        })
+    }
+
+    /// Status of the response (see the type documentation & the
+    /// `STATUS_*` constants).
+    #[inline]
+    pub fn status(&self) -> ControlServiceStatus {
+        self.status
+    }
+
+    /// Returns true if the response contains a list of applications
+    /// (status 3 to 7).
+    #[inline]
+    pub fn has_app_list(&self) -> bool {
+        has_app_list(self.status)
+    }
+
+    /// Returns true if the contexts contain log levels (status 4, 6 or 7).
+    #[inline]
+    pub fn has_log_levels(&self) -> bool {
+        has_log_levels(self.status)
+    }
+
+    /// Returns true if the contexts contain trace statuses (status 5, 6 or 7).
+    #[inline]
+    pub fn has_trace_statuses(&self) -> bool {
+        has_trace_statuses(self.status)
+    }
+
+    /// Returns true if the applications & contexts contain descriptions
+    /// (status 7).
+    #[inline]
+    pub fn has_descriptions(&self) -> bool {
+        has_descriptions(self.status)
+    }
+
+    /// Number of applications in the response.
+    #[inline]
+    pub fn num_apps(&self) -> u16 {
+        self.num_apps
+    }
+
+    /// Communication interface the response was sent on (e.g. "remo").
+    #[inline]
+    pub fn com_interface(&self) -> Option<[u8; 4]> {
+        self.com_interface
+    }
+
+    /// Returns an iterator over the applications in the response.
+    #[inline]
+    pub fn apps(&self) -> LogInfoAppIter<'a> {
+        LogInfoAppIter {
+            slicer: FieldSlicer::with_layer(self.apps_data, 0, Layer::ControlPayload),
+            status: self.status,
+            is_big_endian: self.is_big_endian,
+            remaining: self.num_apps,
+        }
+    }
+}
+
+/// Application entry in a [`GetLogInfoResponse`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogInfoApp<'a> {
+    /// Id of the application.
+    pub app_id: [u8; 4],
+    /// Description of the application (only present for status 7).
+    pub description: Option<&'a [u8]>,
+    contexts: LogInfoContextIter<'a>,
+}
+
+impl<'a> LogInfoApp<'a> {
+    /// Returns an iterator over the contexts of the application.
+    #[inline]
+    pub fn contexts(&self) -> LogInfoContextIter<'a> {
+        self.contexts.clone()
+    }
+}
+
+/// Context entry of a [`LogInfoApp`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogInfoContext<'a> {
+    /// Id of the context.
+    pub context_id: [u8; 4],
+    /// Log level of the context (only present for status 4, 6 & 7).
+    pub log_level: Option<DltLogLevelConfig>,
+    /// Raw trace status of the context (only present for status 5, 6 & 7).
+    ///
+    /// `0` means off, `1` on & `0xff` (-1) the default trace status.
+    pub trace_status: Option<u8>,
+    /// Description of the context (only present for status 7).
+    pub description: Option<&'a [u8]>,
+}
+
+/// Iterator over the applications of a [`GetLogInfoResponse`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogInfoAppIter<'a> {
+    slicer: FieldSlicer<'a>,
+    status: ControlServiceStatus,
+    is_big_endian: bool,
+    remaining: u16,
+}
+
+impl<'a> Iterator for LogInfoAppIter<'a> {
+    type Item = LogInfoApp<'a>;
+
+    fn next(&mut self) -> Option<LogInfoApp<'a>> {
+        if 0 == self.remaining {
+            return None;
+        }
+        self.remaining -= 1;
+        // the data was already validated in GetLogInfoResponse::from_slice
+        read_app(&mut self.slicer, self.status, self.is_big_endian).ok()
+    }
+}
+
+/// Iterator over the contexts of a [`LogInfoApp`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogInfoContextIter<'a> {
+    slicer: FieldSlicer<'a>,
+    status: ControlServiceStatus,
+    is_big_endian: bool,
+    remaining: u16,
+}
+
+impl<'a> Iterator for LogInfoContextIter<'a> {
+    type Item = LogInfoContext<'a>;
+
+    fn next(&mut self) -> Option<LogInfoContext<'a>> {
+        if 0 == self.remaining {
+            return None;
+        }
+        self.remaining -= 1;
+        // the data was already validated in GetLogInfoResponse::from_slice
+        read_context(&mut self.slicer, self.status, self.is_big_endian).ok()
+    }
+}
+
+#[inline]
+fn has_app_list(status: ControlServiceStatus) -> bool {
+    (3..=7).contains(&status.to_byte())
+}
+
+#[inline]
+fn has_log_levels(status: ControlServiceStatus) -> bool {
+    matches!(status.to_byte(), 4 | 6 | 7)
+}
+
+#[inline]
+fn has_trace_statuses(status: ControlServiceStatus) -> bool {
+    matches!(status.to_byte(), 5..=7)
+}
+
+#[inline]
+fn has_descriptions(status: ControlServiceStatus) -> bool {
+    7 == status.to_byte()
+}
+
+/// Returns an error if the slicer did not consume the complete slice.
+fn check_no_trailing_bytes(
+    slicer: &FieldSlicer<'_>,
+    slice: &[u8],
+) -> Result<(), VerboseDecodeError> {
+    if slicer.rest().is_empty() {
+        Ok(())
+    } else {
+        Err(VerboseDecodeError::UnexpectedTrailingBytes {
+            expected_len: slicer.offset(),
+            actual_len: slice.len(),
+        })
+    }
+}
+
+/// Reads an application entry including all its contexts.
+fn read_app<'a>(
+    slicer: &mut FieldSlicer<'a>,
+    status: ControlServiceStatus,
+    is_big_endian: bool,
+) -> Result<LogInfoApp<'a>, VerboseDecodeError> {
+    let app_id = slicer.read_4bytes()?;
+    let num_contexts = slicer.read_u16(is_big_endian)?;
+
+    let contexts_start = slicer.clone();
+    for _ in 0..num_contexts {
+        read_context(slicer, status, is_big_endian)?;
+    }
+    let contexts = LogInfoContextIter {
+        slicer: contexts_start,
+        status,
+        is_big_endian,
+        remaining: num_contexts,
+    };
+
+    let description = if has_descriptions(status) {
+        Some(read_description(slicer, is_big_endian)?)
+    } else {
+        None
+    };
+
+    Ok(LogInfoApp {
+        app_id,
+        description,
+        contexts,
+    })
+}
+
+/// Reads a context entry.
+fn read_context<'a>(
+    slicer: &mut FieldSlicer<'a>,
+    status: ControlServiceStatus,
+    is_big_endian: bool,
+) -> Result<LogInfoContext<'a>, VerboseDecodeError> {
+    let context_id = slicer.read_4bytes()?;
+    let log_level = if has_log_levels(status) {
+        let value = slicer.read_u8()?;
+        Some(
+            DltLogLevelConfig::from_byte(value)
+                .ok_or(VerboseDecodeError::InvalidLogLevel(value))?,
+        )
+    } else {
+        None
+    };
+    let trace_status = if has_trace_statuses(status) {
+        Some(slicer.read_u8()?)
+    } else {
+        None
+    };
+    let description = if has_descriptions(status) {
+        Some(read_description(slicer, is_big_endian)?)
+    } else {
+        None
+    };
+    Ok(LogInfoContext {
+        context_id,
+        log_level,
+        trace_status,
+        description,
+    })
+}
+
+/// Reads a description (16 bit length followed by the data).
+fn read_description<'a>(
+    slicer: &mut FieldSlicer<'a>,
+    is_big_endian: bool,
+) -> Result<&'a [u8], VerboseDecodeError> {
+    let len = slicer.read_u16(is_big_endian)?;
+    slicer.read_raw(usize::from(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DltLogLevel;
+    use alloc::{format, vec::Vec};
+
+    /// Context test data (id, log level, trace status, description).
+    type TestContext<'a> = ([u8; 4], u8, u8, &'a [u8]);
+
+    /// App test data (id, description, contexts).
+    type TestApp<'a> = ([u8; 4], &'a [u8], &'a [TestContext<'a>]);
+
+    /// Serializes a response with the given status & apps.
+    fn serialize(status: u8, is_big_endian: bool, apps: &[TestApp<'_>]) -> Vec<u8> {
+        let status_byte = status;
+        let status = ControlServiceStatus::from_byte(status);
+        let u16_bytes = |v: u16| {
+            if is_big_endian {
+                v.to_be_bytes()
+            } else {
+                v.to_le_bytes()
+            }
+        };
+        let mut result = Vec::new();
+        result.push(status_byte);
+        result.extend_from_slice(&u16_bytes(apps.len() as u16));
+        for (app_id, app_desc, contexts) in apps {
+            result.extend_from_slice(app_id);
+            result.extend_from_slice(&u16_bytes(contexts.len() as u16));
+            for (ctx_id, log_level, trace_status, ctx_desc) in contexts.iter() {
+                result.extend_from_slice(ctx_id);
+                if has_log_levels(status) {
+                    result.push(*log_level);
+                }
+                if has_trace_statuses(status) {
+                    result.push(*trace_status);
+                }
+                if has_descriptions(status) {
+                    result.extend_from_slice(&u16_bytes(ctx_desc.len() as u16));
+                    result.extend_from_slice(ctx_desc);
+                }
+            }
+            if has_descriptions(status) {
+                result.extend_from_slice(&u16_bytes(app_desc.len() as u16));
+                result.extend_from_slice(app_desc);
+            }
+        }
+        result.extend_from_slice(b"remo");
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = serialize(3, true, &[]);
+        let v = GetLogInfoResponse::from_slice(&data, true).unwrap();
+        assert_eq!(v, v.clone());
+        assert_eq!(
+            format!(
+                "GetLogInfoResponse {{ status: Other(3), is_big_endian: true, num_apps: 0, apps_data: [], com_interface: {:?} }}",
+                Some(*b"remo")
+            ),
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let apps: [TestApp<'_>; 2] = [
+            (
+                *b"APP1",
+                b"first app",
+                &[
+                    (*b"CTX1", 0x04, 0x01, b"ctx 1"),
+                    (*b"CTX2", 0xff, 0x00, b""),
+                ],
+            ),
+            (*b"APP2", b"", &[]),
+        ];
+
+        for is_big_endian in [false, true] {
+            for status in 3..=7 {
+                let data = serialize(status, is_big_endian, &apps);
+                let response = GetLogInfoResponse::from_slice(&data, is_big_endian).unwrap();
+                let status = ControlServiceStatus::from_byte(status);
+                assert_eq!(status, response.status());
+                assert!(response.has_app_list());
+                assert_eq!(has_log_levels(status), response.has_log_levels());
+                assert_eq!(has_trace_statuses(status), response.has_trace_statuses());
+                assert_eq!(has_descriptions(status), response.has_descriptions());
+                assert_eq!(2, response.num_apps());
+                assert_eq!(Some(*b"remo"), response.com_interface());
+
+                let actual_apps: Vec<_> = response.apps().collect();
+                assert_eq!(2, actual_apps.len());
+                for (actual, expected) in actual_apps.iter().zip(apps.iter()) {
+                    assert_eq!(expected.0, actual.app_id);
+                    assert_eq!(
+                        if has_descriptions(status) {
+                            Some(expected.1)
+                        } else {
+                            None
+                        },
+                        actual.description
+                    );
+                    let contexts: Vec<_> = actual.contexts().collect();
+                    assert_eq!(expected.2.len(), contexts.len());
+                    for (actual, expected) in contexts.iter().zip(expected.2.iter()) {
+                        assert_eq!(expected.0, actual.context_id);
+                        assert_eq!(
+                            if has_log_levels(status) {
+                                DltLogLevelConfig::from_byte(expected.1)
+                            } else {
+                                None
+                            },
+                            actual.log_level
+                        );
+                        assert_eq!(
+                            if has_trace_statuses(status) {
+                                Some(expected.2)
+                            } else {
+                                None
+                            },
+                            actual.trace_status
+                        );
+                        assert_eq!(
+                            if has_descriptions(status) {
+                                Some(expected.3)
+                            } else {
+                                None
+                            },
+                            actual.description
+                        );
+                    }
+                }
+
+                // unexpected end of slice
+                for len in 0..data.len() {
+                    assert_matches!(
+                        GetLogInfoResponse::from_slice(&data[..len], is_big_endian),
+                        Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                            UnexpectedEndOfSliceError {
+                                layer: Layer::ControlPayload,
+                                ..
+                            }
+                        ))
+                    );
+                }
+
+                // trailing bytes
+                let mut long = data.clone();
+                long.push(0);
+                assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                        expected_len: data.len(),
+                        actual_len: data.len() + 1,
+                    }),
+                    GetLogInfoResponse::from_slice(&long, is_big_endian)
+                );
+            }
+        }
+
+        // log level values
+        {
+            let data = serialize(4, true, &[(*b"APP1", b"", &[(*b"CTX1", 0x03, 0, b"")])]);
+            let response = GetLogInfoResponse::from_slice(&data, true).unwrap();
+            let app = response.apps().next().unwrap();
+            assert_eq!(
+                Some(DltLogLevelConfig::Level(DltLogLevel::Warn)),
+                app.contexts().next().unwrap().log_level
+            );
+        }
+
+        // invalid log level
+        {
+            let data = serialize(4, true, &[(*b"APP1", b"", &[(*b"CTX1", 0x07, 0, b"")])]);
+            assert_eq!(
+                Err(VerboseDecodeError::InvalidLogLevel(0x07)),
+                GetLogInfoResponse::from_slice(&data, true)
+            );
+        }
+
+        // statuses without application list
+        for status in [
+            GetLogInfoResponse::STATUS_NOT_SUPPORTED,
+            GetLogInfoResponse::STATUS_ERROR,
+            GetLogInfoResponse::STATUS_NO_MATCHING_CONTEXTS,
+            GetLogInfoResponse::STATUS_OVERFLOW,
+        ] {
+            // with com interface
+            {
+                let data = [status.to_byte(), b'r', b'e', b'm', b'o'];
+                let response = GetLogInfoResponse::from_slice(&data, true).unwrap();
+                assert_eq!(status, response.status());
+                assert!(false == response.has_app_list());
+                assert_eq!(0, response.num_apps());
+                assert_eq!(None, response.apps().next());
+                assert_eq!(Some(*b"remo"), response.com_interface());
+            }
+            // without com interface
+            {
+                let data = [status.to_byte()];
+                let response = GetLogInfoResponse::from_slice(&data, true).unwrap();
+                assert_eq!(None, response.com_interface());
+            }
+            // truncated com interface
+            for len in 2..5 {
+                let data = [status.to_byte(), b'r', b'e', b'm', b'o'];
+                assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                        UnexpectedEndOfSliceError {
+                            layer: Layer::ControlPayload,
+                            minimum_size: 5,
+                            actual_size: len,
+                        }
+                    )),
+                    GetLogInfoResponse::from_slice(&data[..len], true)
+                );
+            }
+            // trailing bytes
+            {
+                let data = [status.to_byte(), b'r', b'e', b'm', b'o', 0];
+                assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                        expected_len: 5,
+                        actual_len: 6,
+                    }),
+                    GetLogInfoResponse::from_slice(&data, true)
+                );
+            }
+        }
+    }
+}
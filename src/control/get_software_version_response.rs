@@ -0,0 +1,209 @@
+use super::ControlServiceStatus;
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+
+/// Payload of a "Get Software Version" ([`super::CMD_ID_GET_SOFTWARE_VERSION`])
+/// control response.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GetSoftwareVersionResponse<'a> {
+    status: ControlServiceStatus,
+    version: Option<&'a str>,
+}
+
+impl<'a> GetSoftwareVersionResponse<'a> {
+    /// Decodes the response from the control message payload following
+    /// the 4 byte service id.
+    ///
+    /// The 4 byte length & the version string are only expected if the
+    /// status is [`ControlServiceStatus::Ok`]. `is_big_endian` has to be
+    /// set based on the DLT header of the message as the length is encoded
+    /// in the endianness of the message payload.
+    pub fn from_slice(
+        slice: &'a [u8],
+        is_big_endian: bool,
+    ) -> Result<GetSoftwareVersionResponse<'a>, VerboseDecodeError> {
+        let status = ControlServiceStatus::from_byte(*slice.first().ok_or(
+            VerboseDecodeError::UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                layer: Layer::ControlPayload,
+                minimum_size: 1,
+                actual_size: 0,
+            }),
+        )?);
+
+        if ControlServiceStatus::Ok != status {
+            if slice.len() > 1 {
+                return Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: 1,
+                    actual_len: slice.len(),
+                });
+            }
+            return Ok(GetSoftwareVersionResponse {
+                status,
+                version: None,
+            });
+        }
+
+        if slice.len() < 5 {
+            return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: 5,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        let len_bytes = [slice[1], slice[2], slice[3], slice[4]];
+        let len = if is_big_endian {
+            u32::from_be_bytes(len_bytes)
+        } else {
+            u32::from_le_bytes(len_bytes)
+        };
+
+        // saturate on 16 bit targets, the slice can never be that long anyway
+        let expected_len = usize::try_from(len).unwrap_or(usize::MAX).saturating_add(5);
+        if slice.len() < expected_len {
+            return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: expected_len,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        if slice.len() > expected_len {
+            return Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len,
+                actual_len: slice.len(),
+            });
+        }
+
+        // some ECUs include the zero termination in the length
+        let raw = &slice[5..];
+        let raw = match raw.split_last() {
+            Some((0, rest)) => rest,
+            _ => raw,
+        };
+        let version = core::str::from_utf8(raw)?;
+
+        Ok(GetSoftwareVersionResponse {
+            status,
+            version: Some(version),
+        })
+    }
+
+    /// Status of the response.
+    #[inline]
+    pub fn status(&self) -> ControlServiceStatus {
+        self.status
+    }
+
+    /// Software version string (without a zero termination). Only present
+    /// if the status is [`ControlServiceStatus::Ok`].
+    #[inline]
+    pub fn version(&self) -> Option<&'a str> {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    /// Serializes an ok response with the given version data.
+    fn serialize(data: &[u8], is_big_endian: bool) -> Vec<u8> {
+        let len = data.len() as u32;
+        let mut result = Vec::new();
+        result.push(0);
+        if is_big_endian {
+            result.extend_from_slice(&len.to_be_bytes());
+        } else {
+            result.extend_from_slice(&len.to_le_bytes());
+        }
+        result.extend_from_slice(data);
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = serialize(b"1.0", true);
+        let v = GetSoftwareVersionResponse::from_slice(&data, true).unwrap();
+        assert_eq!(v, v.clone());
+        assert_eq!(
+            "GetSoftwareVersionResponse { status: Ok, version: Some(\"1.0\") }",
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        for is_big_endian in [false, true] {
+            // with & without zero termination
+            for (data, expected) in [
+                (
+                    &b"DLT Package Version: 2.18.8"[..],
+                    "DLT Package Version: 2.18.8",
+                ),
+                (&b"1.2.3\0"[..], "1.2.3"),
+                (&b""[..], ""),
+                (&b"\0"[..], ""),
+            ] {
+                let bytes = serialize(data, is_big_endian);
+                let v = GetSoftwareVersionResponse::from_slice(&bytes, is_big_endian).unwrap();
+                assert_eq!(ControlServiceStatus::Ok, v.status());
+                assert_eq!(Some(expected), v.version());
+            }
+
+            // too short
+            let bytes = serialize(b"1.2.3", is_big_endian);
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                        UnexpectedEndOfSliceError {
+                            layer: Layer::ControlPayload,
+                            minimum_size: match len {
+                                0 => 1,
+                                1..=4 => 5,
+                                _ => bytes.len(),
+                            },
+                            actual_size: len,
+                        }
+                    )),
+                    GetSoftwareVersionResponse::from_slice(&bytes[..len], is_big_endian)
+                );
+            }
+
+            // trailing bytes
+            let mut bytes = serialize(b"1.2.3", is_big_endian);
+            bytes.push(0);
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: bytes.len() - 1,
+                    actual_len: bytes.len(),
+                }),
+                GetSoftwareVersionResponse::from_slice(&bytes, is_big_endian)
+            );
+
+            // invalid utf8
+            let bytes = serialize(&[0, 159, 146, 150], is_big_endian);
+            assert_matches!(
+                GetSoftwareVersionResponse::from_slice(&bytes, is_big_endian),
+                Err(VerboseDecodeError::Utf8(_))
+            );
+        }
+
+        // error statuses without version
+        for status in [1, 2] {
+            let data = [status];
+            let v = GetSoftwareVersionResponse::from_slice(&data, true).unwrap();
+            assert_eq!(ControlServiceStatus::from_byte(status), v.status());
+            assert_eq!(None, v.version());
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: 1,
+                    actual_len: 2
+                }),
+                GetSoftwareVersionResponse::from_slice(&[status, 0], true)
+            );
+        }
+    }
+}
@@ -1,3 +1,32 @@
+mod control_message;
+pub use control_message::*;
+
+mod control_service_status;
+pub use control_service_status::*;
+
+mod dlt_log_level_config;
+pub use dlt_log_level_config::*;
+
+mod empty_request;
+pub use empty_request::*;
+
+mod get_default_trace_status_response;
+pub use get_default_trace_status_response::*;
+
+mod get_local_time_response;
+pub use get_local_time_response::*;
+
+mod get_log_info_response;
+pub use get_log_info_response::*;
+
+mod get_software_version_response;
+pub use get_software_version_response::*;
+
+mod set_log_level_request;
+pub use set_log_level_request::*;
+
+mod timezone_response;
+pub use timezone_response::*;
 
 /// "Set Log Level" service id
 pub const CMD_ID_SET_LOG_LEVEL: u32 = 0x01;
@@ -34,6 +63,11 @@ pub const CMD_ID_SET_MESSAGE_FILTERING: u32 = 0x0A;
 /// "Set Message Filtering" name.
 pub const CMD_NAME_SET_MESSAGE_FILTERING: &str = "SetMessageFiltering";
 
+/// "Get Local Time" service id.
+pub const CMD_ID_GET_LOCAL_TIME: u32 = 0x0C;
+/// "Get Local Time" name.
+pub const CMD_NAME_GET_LOCAL_TIME: &str = "GetLocalTime";
+
 /// "Set Default LogLevel" service id.
 pub const CMD_ID_SET_DEFAULT_LOG_LEVEL: u32 = 0x11;
 /// "Set Default LogLevel" name.
@@ -84,6 +118,11 @@ pub const CMD_ID_BUFFER_OVERFLOW_NOTIFICATION: u32 = 0x23;
 /// "BufferOverflowNotification" name.
 pub const CMD_NAME_BUFFER_OVERFLOW_NOTIFICATION: &str = "BufferOverflowNotification";
 
+/// "Timezone" service id (dlt-daemon specific service).
+pub const CMD_ID_TIMEZONE: u32 = 0xF03;
+/// "Timezone" name.
+pub const CMD_NAME_TIMEZONE: &str = "Timezone";
+
 /// "Call SWC Injection" service ids range.
 pub const CMD_IDS_CALL_SWC_INJECTIONS: core::ops::RangeInclusive<u32> = 0xFFF..=0xFFFFFFFF;
 /// "Call SWC Injection" name.
@@ -99,6 +138,7 @@ pub fn get_control_command_name(service_id: u32) -> Option<&'static str> {
         0x05 => Some(CMD_NAME_STORE_CONFIGURATION),
         0x06 => Some(CMD_NAME_RESET_TO_FACTORY_DEFAULT),
         0x0A => Some(CMD_NAME_SET_MESSAGE_FILTERING),
+        0x0C => Some(CMD_NAME_GET_LOCAL_TIME),
         0x11 => Some(CMD_NAME_SET_DEFAULT_LOG_LEVEL),
         0x12 => Some(CMD_NAME_SET_DEFAULT_TRACE_STATUS),
         0x13 => Some(CMD_NAME_GET_SOFTWARE_VERSION),
@@ -109,11 +149,34 @@ pub fn get_control_command_name(service_id: u32) -> Option<&'static str> {
         0x21 => Some(CMD_NAME_SET_LOG_CHANNEL_THRESHOLD),
         0x22 => Some(CMD_NAME_GET_LOG_CHANNEL_THRESHOLD),
         0x23 => Some(CMD_NAME_BUFFER_OVERFLOW_NOTIFICATION),
+        0xF03 => Some(CMD_NAME_TIMEZONE),
         0xFFF..=0xFFFFFFFF => Some(CMD_NAME_CALL_SWC_INJECTIONS),
         _ => None,
     }
 }
 
+/// Returns true if the given application id is a wildcard that matches
+/// all applications.
+///
+/// The DLT specification uses an id that is "not set" (all bytes set to
+/// `0x00`) in control requests like "Set Log Level" to address all
+/// applications. Some implementations use `0xFFFFFFFF` as sentinel instead,
+/// so this value is treated as a wildcard as well.
+#[inline]
+pub fn is_wildcard_app_id(app_id: [u8; 4]) -> bool {
+    [0u8; 4] == app_id || [0xffu8; 4] == app_id
+}
+
+/// Returns true if the given context id is a wildcard that matches
+/// all contexts of an application.
+///
+/// Same sentinels as for [`is_wildcard_app_id`] are used (`0x00000000`
+/// as defined by the DLT specification & `0xFFFFFFFF`).
+#[inline]
+pub fn is_wildcard_ctx_id(ctx_id: [u8; 4]) -> bool {
+    is_wildcard_app_id(ctx_id)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -138,7 +201,7 @@ mod test {
                 (0x09, None),
                 (0x0A, Some("SetMessageFiltering")),
                 (0x0B, None),
-                (0x0C, None),
+                (0x0C, Some("GetLocalTime")),
                 (0x0D, None),
                 (0x0E, None),
                 (0x0F, None),
@@ -161,12 +224,31 @@ mod test {
                 (0x21, Some("SetLogChannelThreshold")),
                 (0x22, Some("GetLogChannelThreshold")),
                 (0x23, Some("BufferOverflowNotification")),
+                (0xF03, Some("Timezone")),
             ];
             for test in tests {
                 assert_eq!(test.1, get_control_command_name(test.0))
             }
-            assert_eq!(None, get_control_command_name(unknown_id));
+            if CMD_ID_TIMEZONE != unknown_id {
+                assert_eq!(None, get_control_command_name(unknown_id));
+            }
             assert_eq!(Some("CallSWCInjection"), get_control_command_name(sw_injections_id));
         }
     }
+
+    #[test]
+    fn wildcard_ids() {
+        // wildcards
+        assert!(is_wildcard_app_id([0; 4]));
+        assert!(is_wildcard_app_id([0xff; 4]));
+        assert!(is_wildcard_ctx_id([0; 4]));
+        assert!(is_wildcard_ctx_id([0xff; 4]));
+
+        // specific ids
+        assert!(false == is_wildcard_app_id(*b"APP1"));
+        assert!(false == is_wildcard_app_id([0, 0, 0, 1]));
+        assert!(false == is_wildcard_app_id([0xff, 0xff, 0xff, 0]));
+        assert!(false == is_wildcard_ctx_id(*b"CTX1"));
+        assert!(false == is_wildcard_ctx_id([1, 0, 0, 0]));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,202 @@
+use super::{is_wildcard_app_id, is_wildcard_ctx_id, DltLogLevelConfig};
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+
+/// Payload of a "Set Log Level" ([`super::CMD_ID_SET_LOG_LEVEL`]) control request.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SetLogLevelRequest {
+    /// Application id of the application the log level is set for.
+    pub app_id: [u8; 4],
+    /// Context id of the context the log level is set for.
+    pub context_id: [u8; 4],
+    /// Log level that should be set (can also be "default" or "block").
+    pub new_log_level: DltLogLevelConfig,
+    /// Communication interface ("remo" for remote, "null" or empty for all).
+    pub com_interface: [u8; 4],
+}
+
+impl SetLogLevelRequest {
+    /// Length of the request payload in bytes (without the service id).
+    pub const BYTE_LEN: usize = 4 + 4 + 1 + 4;
+
+    /// Decodes a "Set Log Level" request from the control message
+    /// payload following the 4 byte service id.
+    ///
+    /// All fields of the request are byte arrays or single bytes, so
+    /// the decoding does not depend on the endianness of the message.
+    pub fn from_slice(slice: &[u8]) -> Result<SetLogLevelRequest, VerboseDecodeError> {
+        if slice.len() < SetLogLevelRequest::BYTE_LEN {
+            return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: SetLogLevelRequest::BYTE_LEN,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        if slice.len() > SetLogLevelRequest::BYTE_LEN {
+            return Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: SetLogLevelRequest::BYTE_LEN,
+                actual_len: slice.len(),
+            });
+        }
+        Ok(SetLogLevelRequest {
+            app_id: [slice[0], slice[1], slice[2], slice[3]],
+            context_id: [slice[4], slice[5], slice[6], slice[7]],
+            new_log_level: DltLogLevelConfig::from_byte(slice[8])
+                .ok_or(VerboseDecodeError::InvalidLogLevel(slice[8]))?,
+            com_interface: [slice[9], slice[10], slice[11], slice[12]],
+        })
+    }
+
+    /// Serializes the request payload (without the service id).
+    pub fn to_bytes(&self) -> [u8; SetLogLevelRequest::BYTE_LEN] {
+        let a = self.app_id;
+        let c = self.context_id;
+        let i = self.com_interface;
+        [
+            a[0],
+            a[1],
+            a[2],
+            a[3],
+            c[0],
+            c[1],
+            c[2],
+            c[3],
+            self.new_log_level.to_byte(),
+            i[0],
+            i[1],
+            i[2],
+            i[3],
+        ]
+    }
+
+    /// Returns true if the request applies to all applications
+    /// (app id is a wildcard, see [`super::is_wildcard_app_id`]).
+    #[inline]
+    pub fn applies_to_all_apps(&self) -> bool {
+        is_wildcard_app_id(self.app_id)
+    }
+
+    /// Returns true if the request applies to all contexts of the
+    /// application (context id is a wildcard, see [`super::is_wildcard_ctx_id`]).
+    #[inline]
+    pub fn applies_to_all_contexts(&self) -> bool {
+        is_wildcard_ctx_id(self.context_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn request(app_id: [u8; 4], context_id: [u8; 4]) -> SetLogLevelRequest {
+        SetLogLevelRequest {
+            app_id,
+            context_id,
+            new_log_level: DltLogLevelConfig::Level(crate::DltLogLevel::Info),
+            com_interface: [0; 4],
+        }
+    }
+
+    #[test]
+    fn clone_eq() {
+        let v = request(*b"APP1", *b"CTX1");
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        let v = request(*b"APP1", *b"CTX1");
+        assert_eq!(
+            format!(
+                "SetLogLevelRequest {{ app_id: {:?}, context_id: {:?}, new_log_level: {:?}, com_interface: {:?} }}",
+                v.app_id, v.context_id, v.new_log_level, v.com_interface
+            ),
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn from_slice_to_bytes() {
+        use crate::DltLogLevel;
+
+        // ok
+        let bytes = [
+            b'A', b'P', b'P', b'1', b'C', b'T', b'X', b'1', 0x05, b'r', b'e', b'm', b'o',
+        ];
+        let expected = SetLogLevelRequest {
+            app_id: *b"APP1",
+            context_id: *b"CTX1",
+            new_log_level: DltLogLevelConfig::Level(DltLogLevel::Debug),
+            com_interface: *b"remo",
+        };
+        assert_eq!(Ok(expected.clone()), SetLogLevelRequest::from_slice(&bytes));
+        assert_eq!(bytes, expected.to_bytes());
+
+        // sentinel log level
+        {
+            let mut bytes = bytes;
+            bytes[8] = 0xff;
+            assert_eq!(
+                DltLogLevelConfig::Default,
+                SetLogLevelRequest::from_slice(&bytes)
+                    .unwrap()
+                    .new_log_level
+            );
+        }
+
+        // invalid log level
+        {
+            let mut bytes = bytes;
+            bytes[8] = 0x07;
+            assert_eq!(
+                Err(VerboseDecodeError::InvalidLogLevel(0x07)),
+                SetLogLevelRequest::from_slice(&bytes)
+            );
+        }
+
+        // too short
+        for len in 0..bytes.len() {
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                    UnexpectedEndOfSliceError {
+                        layer: Layer::ControlPayload,
+                        minimum_size: SetLogLevelRequest::BYTE_LEN,
+                        actual_size: len,
+                    }
+                )),
+                SetLogLevelRequest::from_slice(&bytes[..len])
+            );
+        }
+
+        // trailing bytes
+        {
+            let mut buffer = [0u8; SetLogLevelRequest::BYTE_LEN + 1];
+            buffer[..bytes.len()].copy_from_slice(&bytes);
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: SetLogLevelRequest::BYTE_LEN,
+                    actual_len: SetLogLevelRequest::BYTE_LEN + 1,
+                }),
+                SetLogLevelRequest::from_slice(&buffer)
+            );
+        }
+    }
+
+    #[test]
+    fn applies_to_all_apps() {
+        assert!(request([0; 4], *b"CTX1").applies_to_all_apps());
+        assert!(request([0xff; 4], *b"CTX1").applies_to_all_apps());
+        assert!(false == request(*b"APP1", [0; 4]).applies_to_all_apps());
+        assert!(false == request([0, 0, 0, 0xff], [0; 4]).applies_to_all_apps());
+    }
+
+    #[test]
+    fn applies_to_all_contexts() {
+        assert!(request(*b"APP1", [0; 4]).applies_to_all_contexts());
+        assert!(request(*b"APP1", [0xff; 4]).applies_to_all_contexts());
+        assert!(false == request([0; 4], *b"CTX1").applies_to_all_contexts());
+        assert!(false == request([0; 4], [0xff, 0, 0, 0]).applies_to_all_contexts());
+    }
+}
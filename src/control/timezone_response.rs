@@ -0,0 +1,193 @@
+use super::ControlServiceStatus;
+use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+
+/// Payload of a "Timezone" ([`super::CMD_ID_TIMEZONE`]) control response.
+///
+/// The message is sent by the dlt-daemon (e.g. after a client connected)
+/// & contains the UTC offset of the ECUs local time as well as if daylight
+/// saving time is active. The payload consists of the status (1 byte),
+/// the UTC offset in seconds (4 byte signed integer, encoded in the
+/// endianness of the message) & a daylight saving time flag (1 byte).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TimezoneResponse {
+    status: ControlServiceStatus,
+    utc_offset_secs: Option<i32>,
+    is_dst: Option<bool>,
+}
+
+impl TimezoneResponse {
+    /// Length of the payload (after the service id) of an ok response.
+    pub const OK_LEN: usize = 6;
+
+    /// Decodes the response from the control message payload following
+    /// the 4 byte service id.
+    ///
+    /// The UTC offset & daylight saving time flag are only expected if
+    /// the status is [`ControlServiceStatus::Ok`]. `is_big_endian` has
+    /// to be set based on the DLT header of the message.
+    pub fn from_slice(
+        slice: &[u8],
+        is_big_endian: bool,
+    ) -> Result<TimezoneResponse, VerboseDecodeError> {
+        let status = ControlServiceStatus::from_byte(*slice.first().ok_or(
+            VerboseDecodeError::UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                layer: Layer::ControlPayload,
+                minimum_size: 1,
+                actual_size: 0,
+            }),
+        )?);
+
+        let expected_len = if ControlServiceStatus::Ok == status {
+            Self::OK_LEN
+        } else {
+            1
+        };
+        if slice.len() < expected_len {
+            return Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::ControlPayload,
+                    minimum_size: expected_len,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        if slice.len() > expected_len {
+            return Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len,
+                actual_len: slice.len(),
+            });
+        }
+
+        if ControlServiceStatus::Ok != status {
+            return Ok(TimezoneResponse {
+                status,
+                utc_offset_secs: None,
+                is_dst: None,
+            });
+        }
+
+        let offset_bytes = [slice[1], slice[2], slice[3], slice[4]];
+        Ok(TimezoneResponse {
+            status,
+            utc_offset_secs: Some(if is_big_endian {
+                i32::from_be_bytes(offset_bytes)
+            } else {
+                i32::from_le_bytes(offset_bytes)
+            }),
+            // the dlt-daemon passes on "tm_isdst", which is positive if
+            // daylight saving time is active & negative if the
+            // information is not available
+            is_dst: Some(0 < slice[5] as i8),
+        })
+    }
+
+    /// Status of the response.
+    #[inline]
+    pub fn status(&self) -> ControlServiceStatus {
+        self.status
+    }
+
+    /// Offset of the local time of the ECU to UTC in seconds. Only
+    /// present if the status is [`ControlServiceStatus::Ok`].
+    #[inline]
+    pub fn utc_offset_secs(&self) -> Option<i32> {
+        self.utc_offset_secs
+    }
+
+    /// True if daylight saving time is active. Only present if the
+    /// status is [`ControlServiceStatus::Ok`].
+    #[inline]
+    pub fn is_dst(&self) -> Option<bool> {
+        self.is_dst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    /// Serializes an ok response.
+    fn serialize(utc_offset_secs: i32, is_dst: u8, is_big_endian: bool) -> Vec<u8> {
+        let mut result = Vec::from([0u8]);
+        if is_big_endian {
+            result.extend_from_slice(&utc_offset_secs.to_be_bytes());
+        } else {
+            result.extend_from_slice(&utc_offset_secs.to_le_bytes());
+        }
+        result.push(is_dst);
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = serialize(3600, 1, true);
+        let v = TimezoneResponse::from_slice(&data, true).unwrap();
+        assert_eq!(v, v.clone());
+        assert_eq!(
+            "TimezoneResponse { status: Ok, utc_offset_secs: Some(3600), is_dst: Some(true) }",
+            format!("{:?}", v)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            utc_offset_secs in any::<i32>(),
+            is_dst in any::<u8>(),
+            is_big_endian in any::<bool>(),
+        ) {
+            // ok
+            let bytes = serialize(utc_offset_secs, is_dst, is_big_endian);
+            {
+                let v = TimezoneResponse::from_slice(&bytes, is_big_endian).unwrap();
+                prop_assert_eq!(ControlServiceStatus::Ok, v.status());
+                prop_assert_eq!(Some(utc_offset_secs), v.utc_offset_secs());
+                prop_assert_eq!(Some(0 < is_dst as i8), v.is_dst());
+            }
+
+            // too short
+            for len in 0..bytes.len() {
+                prop_assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                        UnexpectedEndOfSliceError {
+                            layer: Layer::ControlPayload,
+                            minimum_size: if 0 == len { 1 } else { TimezoneResponse::OK_LEN },
+                            actual_size: len,
+                        }
+                    )),
+                    TimezoneResponse::from_slice(&bytes[..len], is_big_endian)
+                );
+            }
+
+            // trailing bytes
+            let mut long = bytes.clone();
+            long.push(0);
+            prop_assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: TimezoneResponse::OK_LEN,
+                    actual_len: TimezoneResponse::OK_LEN + 1,
+                }),
+                TimezoneResponse::from_slice(&long, is_big_endian)
+            );
+        }
+    }
+
+    #[test]
+    fn from_slice_error_status() {
+        for status in [1, 2] {
+            let v = TimezoneResponse::from_slice(&[status], true).unwrap();
+            assert_eq!(ControlServiceStatus::from_byte(status), v.status());
+            assert_eq!(None, v.utc_offset_secs());
+            assert_eq!(None, v.is_dst());
+        }
+        assert_eq!(
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: 1,
+                actual_len: 2
+            }),
+            TimezoneResponse::from_slice(&[1, 0], true)
+        );
+    }
+}
@@ -2,6 +2,7 @@ use super::*;
 
 ///Extended dlt header (optional header in the dlt header)
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DltExtendedHeader {
     pub message_info: DltMessageInfo,
     pub number_of_arguments: u8,
@@ -38,6 +39,35 @@ impl DltExtendedHeader {
         })
     }
 
+    ///Create a extended header for a verbose message with given message type, number of arguments, application id & context id.
+    pub fn new_verbose(
+        message_type: DltMessageType,
+        number_of_arguments: u8,
+        application_id: [u8; 4],
+        context_id: [u8; 4],
+    ) -> Result<DltExtendedHeader, error::RangeError> {
+        Ok(DltExtendedHeader {
+            message_info: DltMessageInfo(message_type.to_byte()? | EXT_MSIN_VERB_FLAG),
+            number_of_arguments,
+            application_id,
+            context_id,
+        })
+    }
+
+    ///Returns the application id as [`Id4`] (e.g. for map keys or
+    ///printing the id as text).
+    #[inline]
+    pub fn application_id4(&self) -> Id4 {
+        Id4(self.application_id)
+    }
+
+    ///Returns the context id as [`Id4`] (e.g. for map keys or printing
+    ///the id as text).
+    #[inline]
+    pub fn context_id4(&self) -> Id4 {
+        Id4(self.context_id)
+    }
+
     ///Returns true if the extended header flags the message as a verbose message.
     #[inline]
     pub fn is_verbose(&self) -> bool {
@@ -60,6 +90,34 @@ impl DltExtendedHeader {
         self.message_info.into_message_type()
     }
 
+    ///Returns the bits of the message info that are not used by any value
+    ///the DLT standard defines for the encoded message type (independent
+    ///of whether [`DltExtendedHeader::message_type`] can decode it):
+    ///
+    ///* bit 3 (the message types 4-7 are undefined, for these the message
+    ///  type info bits 4-7 are returned as well),
+    ///* bit 7 for log & trace messages (highest defined value 6 & 5),
+    ///* bits 6 & 7 for control messages (highest defined value 2),
+    ///* no bits for network trace messages (values 7-15 are user defined).
+    ///
+    ///Undefined values that do not set one of these bits (e.g. the log
+    ///level 7) are only detectable via `message_type` returning `None`.
+    ///Vendors that store custom data in the message info use these bits
+    ///& values. The message info is always kept unchanged on decoding &
+    ///serializing.
+    #[inline]
+    pub fn reserved_bits(&self) -> u8 {
+        // message type info bits never used by a defined value
+        let mtin_mask = match self.message_info.0 & 0b0000_1110 {
+            EXT_MSIN_MSTP_TYPE_LOG | EXT_MSIN_MSTP_TYPE_TRACE => 0b1000_0000,
+            EXT_MSIN_MSTP_TYPE_NW_TRACE => 0b0000_0000,
+            EXT_MSIN_MSTP_TYPE_CONTROL => 0b1100_0000,
+            // undefined message types (bit 3 set)
+            _ => 0b1111_0000,
+        };
+        self.message_info.0 & (0b0000_1000 | mtin_mask)
+    }
+
     ///Set message type info and based on that the message type.
     #[inline]
     pub fn set_message_type(&mut self, value: DltMessageType) -> Result<(), error::RangeError> {
@@ -82,6 +140,15 @@ mod dlt_extended_header_tests {
     use crate::proptest_generators::*;
     use proptest::prelude::*;
 
+    proptest! {
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trip(ref header in extended_dlt_header_any()) {
+            let json = serde_json::to_string(header).unwrap();
+            prop_assert_eq!(header, &serde_json::from_str::<DltExtendedHeader>(&json).unwrap());
+        }
+    }
+
     #[test]
     fn clone_eq() {
         let header: DltExtendedHeader = Default::default();
@@ -112,6 +179,14 @@ mod dlt_extended_header_tests {
         assert_eq!(header.context_id, [0, 0, 0, 0]);
     }
 
+    #[test]
+    fn id4() {
+        let header = DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX\0");
+        assert_eq!(Id4(*b"APP1"), header.application_id4());
+        assert_eq!(Id4(*b"CTX\0"), header.context_id4());
+        assert_eq!("CTX", format!("{}", header.context_id4()));
+    }
+
     proptest! {
         #[test]
         fn new_non_verbose_log(
@@ -165,6 +240,48 @@ mod dlt_extended_header_tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn new_verbose(
+            message_type in message_type_any(),
+            number_of_arguments in any::<u8>(),
+            application_id in any::<[u8;4]>(),
+            context_id in any::<[u8;4]>(),
+            invalid_user_defined in 0x10..0xffu8
+        ) {
+            // valid data
+            {
+                let header = DltExtendedHeader::new_verbose(
+                    message_type,
+                    number_of_arguments,
+                    application_id,
+                    context_id
+                ).unwrap();
+                assert_eq!(message_type.to_byte().unwrap() | 0b1, header.message_info.0);
+                assert!(header.is_verbose());
+                assert_eq!(Some(message_type), header.message_type());
+                assert_eq!(number_of_arguments, header.number_of_arguments);
+                assert_eq!(application_id, header.application_id);
+                assert_eq!(context_id, header.context_id);
+            }
+
+            // invalid data
+            {
+                use DltMessageType::NetworkTrace;
+                use DltNetworkType::UserDefined;
+                use error::RangeError::NetworkTypekUserDefinedOutsideOfRange;
+
+                let result = DltExtendedHeader::new_verbose(
+                    NetworkTrace(UserDefined(invalid_user_defined)),
+                    number_of_arguments,
+                    application_id,
+                    context_id
+                ).unwrap_err();
+                assert_eq!(NetworkTypekUserDefinedOutsideOfRange(invalid_user_defined), result);
+            }
+        }
+    }
+
     #[test]
     fn set_is_verbose() {
         let mut header: DltExtendedHeader = Default::default();
@@ -201,6 +318,77 @@ mod dlt_extended_header_tests {
         }
     }
 
+    #[test]
+    fn reserved_bits() {
+        // standard values
+        {
+            let header = DltExtendedHeader::new_non_verbose_log(
+                DltLogLevel::Warn,
+                Default::default(),
+                Default::default(),
+            );
+            assert_eq!(0, header.reserved_bits());
+        }
+
+        // decodable message types never have reserved bits set
+        for message_info in 0..=u8::MAX {
+            let ext = DltExtendedHeader {
+                message_info: DltMessageInfo(message_info),
+                ..Default::default()
+            };
+            if ext.message_type().is_some() {
+                assert_eq!(0, ext.reserved_bits());
+            }
+        }
+
+        // reserved values round trip unchanged through serialization
+        for (message_info, expected) in [
+            // log with level 15
+            (0b1111_0000, 0b1000_0000),
+            (0b1111_0001, 0b1000_0000),
+            // log with level 7 (undefined but no reserved bit set)
+            (0b0111_0000, 0b0000_0000),
+            // trace with type 9
+            (0b1001_0010, 0b1000_0000),
+            // control with type 4 & 3
+            (0b0100_0110, 0b0100_0000),
+            (0b0011_0110, 0b0000_0000),
+            // undefined message types 4 & 7
+            (0b0000_1000, 0b0000_1000),
+            (0b0111_1111, 0b0111_1000),
+        ] {
+            let mut ext =
+                DltExtendedHeader::new_non_verbose_log(DltLogLevel::Warn, *b"APP1", *b"CTX1");
+            ext.message_info = DltMessageInfo(message_info);
+            assert_eq!(None, ext.message_type());
+            assert_eq!(expected, ext.reserved_bits());
+
+            // setting the verbose flag keeps the bits
+            let mut verbose = ext.clone();
+            verbose.set_is_verbose(true);
+            assert_eq!(ext.reserved_bits(), verbose.reserved_bits());
+
+            let mut header = DltHeader {
+                is_big_endian: false,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: Some(ext.clone()),
+            };
+            header.length = header.header_len() + 4;
+            let mut buffer = Vec::with_capacity(usize::from(header.length));
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&[1, 2, 3, 4]);
+
+            let slice = DltPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(Some(ext.clone()), slice.extended_header());
+            assert_eq!(expected, slice.extended_header().unwrap().reserved_bits());
+            assert_eq!(&header.to_bytes()[..], &slice.header().to_bytes()[..]);
+        }
+    }
+
     #[test]
     fn message_type() {
         use {
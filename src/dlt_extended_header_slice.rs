@@ -0,0 +1,137 @@
+use super::*;
+use crate::error::{Layer, UnexpectedEndOfSliceError};
+
+///Zero copy view of an extended dlt header.
+///
+///Allows reading single fields (e.g. the application & context id)
+///without decoding the complete [`DltExtendedHeader`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DltExtendedHeaderSlice<'a> {
+    slice: &'a [u8; DltExtendedHeaderSlice::BYTE_LEN],
+}
+
+impl<'a> DltExtendedHeaderSlice<'a> {
+    ///Serialized length of an extended header in bytes.
+    pub const BYTE_LEN: usize = 10;
+
+    ///Creates a view of the extended header at the start of the given slice.
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<DltExtendedHeaderSlice<'a>, UnexpectedEndOfSliceError> {
+        if slice.len() < DltExtendedHeaderSlice::BYTE_LEN {
+            return Err(UnexpectedEndOfSliceError {
+                layer: Layer::DltHeader,
+                minimum_size: DltExtendedHeaderSlice::BYTE_LEN,
+                actual_size: slice.len(),
+            });
+        }
+        Ok(DltExtendedHeaderSlice {
+            // unwrap is safe as the length was checked above
+            slice: slice[..DltExtendedHeaderSlice::BYTE_LEN]
+                .try_into()
+                .unwrap(),
+        })
+    }
+
+    ///Returns the slice containing the extended header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    ///Returns the message info byte.
+    #[inline]
+    pub fn message_info(&self) -> DltMessageInfo {
+        DltMessageInfo(self.slice[0])
+    }
+
+    ///Returns true if the extended header flags the message as a verbose message.
+    #[inline]
+    pub fn is_verbose(&self) -> bool {
+        self.message_info().is_verbose()
+    }
+
+    ///Returns message type info or `Option::None` for reserved values.
+    #[inline]
+    pub fn message_type(&self) -> Option<DltMessageType> {
+        self.message_info().into_message_type()
+    }
+
+    ///Returns the number of arguments field.
+    #[inline]
+    pub fn number_of_arguments(&self) -> u8 {
+        self.slice[1]
+    }
+
+    ///Returns the application id.
+    #[doc(alias = "app_id")]
+    #[inline]
+    pub fn application_id(&self) -> [u8; 4] {
+        [self.slice[2], self.slice[3], self.slice[4], self.slice[5]]
+    }
+
+    ///Returns the context id.
+    #[doc(alias = "ctx_id")]
+    #[inline]
+    pub fn context_id(&self) -> [u8; 4] {
+        [self.slice[6], self.slice[7], self.slice[8], self.slice[9]]
+    }
+
+    ///Decodes all fields into a [`DltExtendedHeader`].
+    #[inline]
+    pub fn to_header(&self) -> DltExtendedHeader {
+        DltExtendedHeader {
+            message_info: self.message_info(),
+            number_of_arguments: self.number_of_arguments(),
+            application_id: self.application_id(),
+            context_id: self.context_id(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod dlt_extended_header_slice_tests {
+    use super::*;
+    use crate::proptest_generators::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            ref header in extended_dlt_header_any(),
+            ref rest in proptest::collection::vec(any::<u8>(), 0..4)
+        ) {
+            let mut buffer = Vec::with_capacity(DltExtendedHeaderSlice::BYTE_LEN + rest.len());
+            buffer.push(header.message_info.0);
+            buffer.push(header.number_of_arguments);
+            buffer.extend_from_slice(&header.application_id[..]);
+            buffer.extend_from_slice(&header.context_id[..]);
+            buffer.extend_from_slice(rest);
+
+            // ok case
+            let slice = DltExtendedHeaderSlice::from_slice(&buffer).unwrap();
+            prop_assert_eq!(slice.slice(), &buffer[..DltExtendedHeaderSlice::BYTE_LEN]);
+            prop_assert_eq!(slice.message_info(), header.message_info);
+            prop_assert_eq!(slice.is_verbose(), header.is_verbose());
+            prop_assert_eq!(slice.message_type(), header.message_type());
+            prop_assert_eq!(slice.number_of_arguments(), header.number_of_arguments);
+            prop_assert_eq!(slice.application_id(), header.application_id);
+            prop_assert_eq!(slice.context_id(), header.context_id);
+            prop_assert_eq!(&slice.to_header(), header);
+            let copy = slice;
+            prop_assert_eq!(slice, copy);
+
+            // length error
+            for len in 0..DltExtendedHeaderSlice::BYTE_LEN {
+                prop_assert_eq!(
+                    DltExtendedHeaderSlice::from_slice(&buffer[..len]),
+                    Err(UnexpectedEndOfSliceError {
+                        layer: Layer::DltHeader,
+                        minimum_size: DltExtendedHeaderSlice::BYTE_LEN,
+                        actual_size: len,
+                    })
+                );
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use super::*;
 
 ///A dlt message header
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DltHeader {
     ///If true the payload is encoded in big endian. This does not influence the fields of the dlt header, which is always encoded in big endian.
     pub is_big_endian: bool,
@@ -57,30 +58,7 @@ impl DltHeader {
         }
 
         // calculate the minimum size based on the header flags
-        // the header size has at least 4 bytes
-        let header_len = if 0 != header_type & ECU_ID_FLAG {
-            4 + 4
-        } else {
-            4
-        };
-
-        let header_len = if 0 != header_type & SESSION_ID_FLAG {
-            header_len + 4
-        } else {
-            header_len
-        };
-
-        let header_len = if 0 != header_type & TIMESTAMP_FLAG {
-            header_len + 4
-        } else {
-            header_len
-        };
-
-        let header_len = if 0 != header_type & EXTDENDED_HEADER_FLAG {
-            header_len + 10
-        } else {
-            header_len
-        };
+        let header_len = DltHeader::header_len_from_type(header_type);
 
         // check that enough data based on the header size is available
         if slice.len() < header_len {
@@ -197,6 +175,35 @@ impl DltHeader {
         })
     }
 
+    /// Decodes a header from the start of the given slice & returns it
+    /// together with the remaining (not consumed) part of the slice.
+    ///
+    /// In contrast to [`DltHeader::from_slice`] the length field of the
+    /// header is also validated & an
+    /// [`error::PacketSliceError::MessageLengthTooSmall`] error is returned
+    /// if it is smaller than the length of the header itself. The returned
+    /// rest is not cut to the length field (use [`crate::DltPacketSlice`]
+    /// if you need the payload limited to the message length).
+    pub fn from_slice_with_rest(
+        slice: &[u8],
+    ) -> Result<(DltHeader, &[u8]), error::PacketSliceError> {
+        use error::{PacketSliceError::*, *};
+
+        let header = DltHeader::from_slice(slice)?;
+        let header_len = usize::from(header.header_len());
+        if usize::from(header.length) < header_len {
+            return Err(MessageLengthTooSmall(DltMessageLengthTooSmallError {
+                required_length: header_len,
+                actual_length: usize::from(header.length),
+            }));
+        }
+
+        // SAFETY: Safe as from_slice verified that the slice has at least
+        // the length of the header.
+        let rest = unsafe { slice.get_unchecked(header_len..) };
+        Ok((header, rest))
+    }
+
     /// Encodes the header to the on the wire format.
     pub fn to_bytes(&self) -> ArrayVec<u8, { DltHeader::MAX_SERIALIZED_SIZE }> {
         // encode values
@@ -475,6 +482,144 @@ impl DltHeader {
             None => 0,
         }
     }
+
+    /// Returns the byte size of each part of the serialized header.
+    ///
+    /// Useful for debugging length errors, as it shows which optional
+    /// header part contributes to the total [`DltHeader::header_len`].
+    pub fn header_len_parts(&self) -> HeaderLenParts {
+        HeaderLenParts {
+            base: 4,
+            ecu_id: if self.ecu_id.is_some() { 4 } else { 0 },
+            session_id: if self.session_id.is_some() { 4 } else { 0 },
+            timestamp: if self.timestamp.is_some() { 4 } else { 0 },
+            extended: if self.extended_header.is_some() {
+                10
+            } else {
+                0
+            },
+        }
+    }
+
+    /// Returns the byte size of the header (including the extended header)
+    /// based on the flags in the given header type byte.
+    pub(crate) fn header_len_from_type(header_type: u8) -> usize {
+        // the header size has at least 4 bytes
+        let header_len = if 0 != header_type & ECU_ID_FLAG {
+            4 + 4
+        } else {
+            4
+        };
+
+        let header_len = if 0 != header_type & SESSION_ID_FLAG {
+            header_len + 4
+        } else {
+            header_len
+        };
+
+        let header_len = if 0 != header_type & TIMESTAMP_FLAG {
+            header_len + 4
+        } else {
+            header_len
+        };
+
+        if 0 != header_type & EXTDENDED_HEADER_FLAG {
+            header_len + 10
+        } else {
+            header_len
+        }
+    }
+}
+
+/// Builder for [`DltHeader`] that calculates the `length` field based
+/// on the optional header parts & the payload length.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::DltHeaderBuilder;
+///
+/// let header = DltHeaderBuilder::new()
+///     .ecu_id(*b"ECU1")
+///     .timestamp(1234)
+///     .finalize(4)
+///     .unwrap();
+///
+/// // 4 bytes base header + 4 bytes ecu id + 4 bytes timestamp + 4 bytes payload
+/// assert_eq!(16, header.length);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DltHeaderBuilder {
+    header: DltHeader,
+}
+
+impl DltHeaderBuilder {
+    /// Creates a builder for a little endian header without any
+    /// optional header parts & a message counter of 0.
+    #[inline]
+    pub fn new() -> DltHeaderBuilder {
+        Default::default()
+    }
+
+    /// Sets if the payload is encoded in big endian.
+    #[inline]
+    pub fn big_endian(mut self, is_big_endian: bool) -> DltHeaderBuilder {
+        self.header.is_big_endian = is_big_endian;
+        self
+    }
+
+    /// Sets the message counter.
+    #[inline]
+    pub fn message_counter(mut self, message_counter: u8) -> DltHeaderBuilder {
+        self.header.message_counter = message_counter;
+        self
+    }
+
+    /// Sets the ECU id.
+    #[inline]
+    pub fn ecu_id(mut self, ecu_id: [u8; 4]) -> DltHeaderBuilder {
+        self.header.ecu_id = Some(ecu_id);
+        self
+    }
+
+    /// Sets the session id.
+    #[inline]
+    pub fn session_id(mut self, session_id: u32) -> DltHeaderBuilder {
+        self.header.session_id = Some(session_id);
+        self
+    }
+
+    /// Sets the timestamp (in 0.1 milliseconds).
+    #[inline]
+    pub fn timestamp(mut self, timestamp: u32) -> DltHeaderBuilder {
+        self.header.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the extended header.
+    #[inline]
+    pub fn extended_header(mut self, extended_header: DltExtendedHeader) -> DltHeaderBuilder {
+        self.header.extended_header = Some(extended_header);
+        self
+    }
+
+    /// Returns the header with the `length` field set to the header
+    /// length plus the given payload length.
+    ///
+    /// An error is returned if the resulting length does not fit into
+    /// the 16 bit length field.
+    pub fn finalize(self, payload_len: usize) -> Result<DltHeader, error::RangeError> {
+        let mut header = self.header;
+        let header_len = header.header_len();
+        header.length = u16::try_from(payload_len)
+            .ok()
+            .and_then(|p| header_len.checked_add(p))
+            .ok_or(error::RangeError::MessageLengthTooBig {
+                header_len,
+                payload_len,
+            })?;
+        Ok(header)
+    }
 }
 
 #[cfg(test)]
@@ -484,6 +629,15 @@ mod dlt_header_tests {
     use crate::proptest_generators::*;
     use proptest::prelude::*;
 
+    proptest! {
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trip(ref header in dlt_header_any()) {
+            let json = serde_json::to_string(header).unwrap();
+            prop_assert_eq!(header, &serde_json::from_str::<DltHeader>(&json).unwrap());
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes_from_slice(
@@ -546,6 +700,120 @@ mod dlt_header_tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_slice_with_rest(
+            ref dlt_header in dlt_header_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..10)
+        ) {
+            use error::PacketSliceError::*;
+
+            let mut header = dlt_header.clone();
+            header.length = header.header_len() + payload.len() as u16;
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.extend_from_slice(&payload);
+
+            // ok case
+            assert_eq!(
+                Ok((header.clone(), &payload[..])),
+                DltHeader::from_slice_with_rest(&bytes)
+            );
+
+            // unexpected end of slice
+            for l in 0..header.header_len() as usize {
+                assert_eq!(
+                    UnexpectedEndOfSlice(
+                        error::UnexpectedEndOfSliceError{
+                            minimum_size: if l < 4 {
+                                4
+                            } else {
+                                header.header_len() as usize
+                            },
+                            actual_size: l,
+                            layer: error::Layer::DltHeader,
+                        }
+                    ),
+                    DltHeader::from_slice_with_rest(&bytes[..l]).unwrap_err()
+                );
+            }
+
+            // length field smaller than the header
+            {
+                let mut bytes = bytes.clone();
+                let length = header.header_len() - 1;
+                bytes[2..4].copy_from_slice(&length.to_be_bytes());
+                assert_eq!(
+                    MessageLengthTooSmall(error::DltMessageLengthTooSmallError{
+                        required_length: header.header_len() as usize,
+                        actual_length: length as usize,
+                    }),
+                    DltHeader::from_slice_with_rest(&bytes).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn builder(
+            ref dlt_header in dlt_header_any(),
+            payload_len in 0..usize::from(u16::MAX - DltHeader::MAX_SERIALIZED_SIZE as u16)
+        ) {
+            // build the header via the builder
+            let mut builder = DltHeaderBuilder::new()
+                .big_endian(dlt_header.is_big_endian)
+                .message_counter(dlt_header.message_counter);
+            if let Some(ecu_id) = dlt_header.ecu_id {
+                builder = builder.ecu_id(ecu_id);
+            }
+            if let Some(session_id) = dlt_header.session_id {
+                builder = builder.session_id(session_id);
+            }
+            if let Some(timestamp) = dlt_header.timestamp {
+                builder = builder.timestamp(timestamp);
+            }
+            if let Some(extended_header) = &dlt_header.extended_header {
+                builder = builder.extended_header(extended_header.clone());
+            }
+            let actual = builder.clone().finalize(payload_len).unwrap();
+
+            // compare with a hand built header
+            let mut expected = dlt_header.clone();
+            expected.length = dlt_header.header_len() + payload_len as u16;
+            assert_eq!(expected, actual);
+            assert_eq!(expected.to_bytes(), actual.to_bytes());
+
+            // maximum length
+            {
+                let max_payload_len = usize::from(u16::MAX - expected.header_len());
+                assert_eq!(u16::MAX, builder.clone().finalize(max_payload_len).unwrap().length);
+            }
+
+            // length overflow
+            for payload_len in [usize::from(u16::MAX - expected.header_len()) + 1, usize::MAX] {
+                assert_eq!(
+                    Err(error::RangeError::MessageLengthTooBig {
+                        header_len: expected.header_len(),
+                        payload_len,
+                    }),
+                    builder.clone().finalize(payload_len)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn builder_default() {
+        assert_eq!(
+            DltHeader {
+                length: 4,
+                ..Default::default()
+            },
+            DltHeaderBuilder::new().finalize(0).unwrap()
+        );
+    }
+
     proptest! {
         #[test]
         #[cfg(feature = "std")]
@@ -663,19 +931,28 @@ mod dlt_header_tests {
         ];
 
         for test in tests {
+            let header = DltHeader {
+                is_big_endian: false,
+                message_counter: 123,
+                length: 123,
+                ecu_id: test.ecu_id,
+                session_id: test.session_id,
+                timestamp: test.timestamp,
+                extended_header: test.extended_header,
+            };
+            assert_eq!(test.expected, header.header_len());
             assert_eq!(
-                test.expected,
-                DltHeader {
-                    is_big_endian: false,
-                    message_counter: 123,
-                    length: 123,
-                    ecu_id: test.ecu_id,
-                    session_id: test.session_id,
-                    timestamp: test.timestamp,
-                    extended_header: test.extended_header,
-                }
-                .header_len()
+                usize::from(test.expected),
+                DltHeader::header_len_from_type(header.to_bytes()[0])
             );
+
+            let parts = header.header_len_parts();
+            assert_eq!(test.expected, parts.total());
+            assert_eq!(4, parts.base);
+            assert_eq!(header.ecu_id.map_or(0, |_| 4), parts.ecu_id);
+            assert_eq!(header.session_id.map_or(0, |_| 4), parts.session_id);
+            assert_eq!(header.timestamp.map_or(0, |_| 4), parts.timestamp);
+            assert_eq!(header.extended_header.map_or(0, |_| 10), parts.extended);
         }
     }
 
@@ -0,0 +1,185 @@
+use crate::error::EncodeError;
+use crate::verbose::VerboseValue;
+use crate::*;
+use alloc::vec::Vec;
+
+/// Builder for complete serialized verbose DLT messages that collects
+/// the values & encodes them into a `Vec` when the message is build.
+///
+/// Header fields are set like in [`VerboseMessageBuilder`], which can
+/// be used if no allocations are wanted.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::{DltHeader, DltExtendedHeader, DltLogLevel, DltMessageBuilder, DltPacketSlice};
+/// use dlt_parse::verbose::{BoolValue, VerboseValue};
+///
+/// let mut builder = DltMessageBuilder::new(
+///     DltHeader { is_big_endian: true, ..Default::default() },
+///     DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1"),
+/// );
+/// builder
+///     .add_value(VerboseValue::Bool(BoolValue { name: None, value: true }))
+///     .add_value(VerboseValue::Bool(BoolValue { name: None, value: false }));
+/// let bytes = builder.build().unwrap();
+///
+/// let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+/// assert_eq!(Some(2), slice.verbose_arg_count());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DltMessageBuilder<'a> {
+    header: DltHeader,
+    extended_header: DltExtendedHeader,
+    values: Vec<VerboseValue<'a>>,
+}
+
+impl<'a> DltMessageBuilder<'a> {
+    /// Creates a new builder without any values.
+    ///
+    /// The `length` & `extended_header` fields of the given header are
+    /// ignored and set when the message is build.
+    pub fn new(header: DltHeader, extended_header: DltExtendedHeader) -> DltMessageBuilder<'a> {
+        DltMessageBuilder {
+            header,
+            extended_header,
+            values: Vec::new(),
+        }
+    }
+
+    /// Adds a value as argument to the message.
+    pub fn add_value(&mut self, value: VerboseValue<'a>) -> &mut DltMessageBuilder<'a> {
+        self.values.push(value);
+        self
+    }
+
+    /// Returns the values that were added to the builder.
+    #[inline]
+    pub fn values(&self) -> &[VerboseValue<'a>] {
+        &self.values
+    }
+
+    /// Serializes the dlt header, extended header & all added values
+    /// into a complete verbose DLT message.
+    ///
+    /// An error is returned if more then 255 values were added or the
+    /// resulting message would be bigger then the maximum DLT message
+    /// length of 65535 bytes.
+    pub fn build(&self) -> Result<Vec<u8>, EncodeError> {
+        let number_of_arguments =
+            u8::try_from(self.values.len()).map_err(|_| EncodeError::CapacityExceeded)?;
+
+        // set the header fields based on the values
+        let mut header = verbose_header(
+            self.header.clone(),
+            self.extended_header.clone(),
+            number_of_arguments,
+        );
+        let payload_len: usize = self.values.iter().map(|v| v.encoded_len()).sum();
+        header.length = message_length_field(usize::from(header.header_len()) + payload_len)?;
+
+        let mut result = Vec::with_capacity(usize::from(header.length));
+        result.extend_from_slice(&header.to_bytes());
+        for value in &self.values {
+            value.add_to_msg(&mut result, header.is_big_endian)?;
+        }
+        debug_assert_eq!(usize::from(header.length), result.len());
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod dlt_message_builder_tests {
+    use super::*;
+    use crate::test_packets::{header, log_extended_header};
+    use crate::verbose::{BoolValue, StringValue, U32Value};
+
+    #[test]
+    fn new() {
+        let builder = DltMessageBuilder::new(header(true), log_extended_header(DltLogLevel::Info));
+        assert!(builder.values().is_empty());
+    }
+
+    #[test]
+    fn build() {
+        // round trip with three arguments
+        for is_big_endian in [false, true] {
+            let values = [
+                VerboseValue::U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 0x1234_5678,
+                }),
+                VerboseValue::Str(StringValue::new(Some("name"), "text")),
+                VerboseValue::Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+            ];
+
+            let mut builder = DltMessageBuilder::new(
+                header(is_big_endian),
+                log_extended_header(DltLogLevel::Warn),
+            );
+            for value in &values {
+                builder.add_value(value.clone());
+            }
+            assert_eq!(builder.values(), &values[..]);
+
+            let bytes = builder.build().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(usize::from(slice.header().length), bytes.len());
+            assert_eq!(Some(3), slice.verbose_arg_count());
+            assert_eq!(
+                Some(DltMessageType::Log(DltLogLevel::Warn)),
+                slice.message_type()
+            );
+            assert_eq!(
+                slice
+                    .verbose_value_iter()
+                    .unwrap()
+                    .map(|v| v.unwrap())
+                    .collect::<Vec<_>>(),
+                values
+            );
+        }
+
+        // no arguments
+        {
+            let builder =
+                DltMessageBuilder::new(header(true), log_extended_header(DltLogLevel::Info));
+            let bytes = builder.build().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert!(slice.is_verbose());
+            assert_eq!(Some(0), slice.verbose_arg_count());
+            assert_eq!(usize::from(slice.header().header_len()), bytes.len());
+        }
+
+        // too many arguments
+        {
+            let mut builder =
+                DltMessageBuilder::new(header(true), log_extended_header(DltLogLevel::Info));
+            for _ in 0..256 {
+                builder.add_value(VerboseValue::Bool(BoolValue {
+                    name: None,
+                    value: false,
+                }));
+            }
+            assert!(builder.build().is_err());
+        }
+
+        // message too big
+        {
+            let text = "a".repeat(usize::from(u16::MAX) - 10);
+            let mut builder =
+                DltMessageBuilder::new(header(true), log_extended_header(DltLogLevel::Info));
+            builder.add_value(VerboseValue::Str(StringValue::new(None, &text)));
+            assert_eq!(
+                Err(EncodeError::MessageTooLong {
+                    len: 22 + 4 + 2 + text.len() + 1
+                }),
+                builder.build()
+            );
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
 
 /// Message info identifying the type of message (e.g. log, trace, network trace & control).
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DltMessageInfo(pub u8);
 
 impl DltMessageInfo {
@@ -43,6 +44,20 @@ impl DltMessageInfo {
     pub fn into_message_type(&self) -> Option<DltMessageType> {
         DltMessageType::from_byte(self.0)
     }
+
+    /// Returns the message type decoded from the type & type info
+    /// bits (`None` if the combination is unknown). The verbose flag
+    /// is ignored.
+    #[inline]
+    pub fn message_type(&self) -> Option<DltMessageType> {
+        DltMessageType::from_byte(self.0)
+    }
+
+    /// Returns the raw message info byte.
+    #[inline]
+    pub fn raw(&self) -> u8 {
+        self.0
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +104,21 @@ mod test {
             assert_eq!(info.into_message_type(), DltMessageType::from_byte(v));
         }
     }
+
+    #[test]
+    fn message_type() {
+        for v in 0..=u8::MAX {
+            let info = DltMessageInfo(v);
+            assert_eq!(info.message_type(), DltMessageType::from_byte(v));
+            // verbose flag has no influence on the message type
+            assert_eq!(info.message_type(), DltMessageInfo(v ^ 0b1).message_type());
+        }
+    }
+
+    #[test]
+    fn raw() {
+        for v in 0..=u8::MAX {
+            assert_eq!(v, DltMessageInfo(v).raw());
+        }
+    }
 }
@@ -0,0 +1,97 @@
+use crate::*;
+use alloc::vec::Vec;
+
+/// Owned version of a DLT packet (header & payload).
+///
+/// In contrast to [`DltPacketSlice`] the packet does not borrow the
+/// data it was decoded from, so it can be kept after the original
+/// buffer has been dropped or reused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DltPacket {
+    /// Decoded DLT header (including the extended header if present).
+    pub header: DltHeader,
+    /// Payload of the packet (all data after the header).
+    pub payload: Vec<u8>,
+}
+
+impl DltPacket {
+    /// Serializes the header & payload.
+    ///
+    /// The header is written as is (the length field is not updated
+    /// if the payload was modified). For a packet created from a
+    /// [`DltPacketSlice`] the result is identical to the original data,
+    /// except for the header version which is always written as
+    /// [`DltHeader::VERSION`] (version 0 packets are written as version 1).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let header = self.header.to_bytes();
+        let mut result = Vec::with_capacity(header.len() + self.payload.len());
+        result.extend_from_slice(&header);
+        result.extend_from_slice(&self.payload);
+        result
+    }
+}
+
+impl<'a> From<&DltPacketSlice<'a>> for DltPacket {
+    fn from(value: &DltPacketSlice<'a>) -> Self {
+        DltPacket {
+            header: value.header(),
+            payload: value.payload().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod dlt_packet_tests {
+    use super::*;
+    use crate::proptest_generators::*;
+    use crate::test_packets::packet;
+    use proptest::prelude::*;
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = packet(1, &[1, 2, 3, 4]);
+        let packet = DltPacket::from(&DltPacketSlice::from_slice(&bytes).unwrap());
+        assert_eq!(packet, packet.clone());
+        assert_eq!(
+            format!(
+                "DltPacket {{ header: {:?}, payload: {:?} }}",
+                packet.header, packet.payload
+            ),
+            format!("{:?}", packet)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let bytes = packet(1, &[1, 2, 3, 4]);
+        let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+        let packet = DltPacket::from(&slice);
+        assert_eq!(slice.header(), packet.header);
+        assert_eq!(&[1, 2, 3, 4], &packet.payload[..]);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(ref packet in dlt_header_with_payload_any()) {
+            let mut bytes = Vec::from(&packet.0.to_bytes()[..]);
+            bytes.extend_from_slice(&packet.1);
+
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            let owned = slice.to_packet();
+            prop_assert_eq!(&owned, &DltPacket::from(&slice));
+            prop_assert_eq!(&packet.0, &owned.header);
+            prop_assert_eq!(&packet.1, &owned.payload);
+            prop_assert_eq!(&bytes, &owned.to_bytes());
+
+            // version 0 packets are written as version 1
+            let mut v0_bytes = bytes.clone();
+            v0_bytes[0] &= 0b0001_1111;
+            let v0_slice = DltPacketSlice::from_slice(&v0_bytes).unwrap();
+            prop_assert_eq!(0, v0_slice.header_version());
+            let v0_owned = v0_slice.to_packet();
+            prop_assert_eq!(&packet.0, &v0_owned.header);
+            prop_assert_eq!(&packet.1, &v0_owned.payload);
+            prop_assert_eq!(&bytes, &v0_owned.to_bytes());
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::verbose::VerboseIter;
+use crate::verbose::{VerboseIter, VerboseScalarBuf};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, collections::BTreeMap, format, string::String};
 
 use self::error::TypedPayloadError;
 
@@ -53,36 +55,7 @@ impl<'a> DltPacketSlice<'a> {
         }
 
         // calculate the minimum size based on the header flags
-        //
-        // SAFETY:
-        // Safe as it is checked beforehand that the slice
-        // has at least 4 bytes.
-        let header_type = unsafe { slice.get_unchecked(0) };
-
-        //the header size has at least 4 bytes
-        let header_len = if 0 != header_type & ECU_ID_FLAG {
-            4 + 4
-        } else {
-            4
-        };
-
-        let header_len = if 0 != header_type & SESSION_ID_FLAG {
-            header_len + 4
-        } else {
-            header_len
-        };
-
-        let header_len = if 0 != header_type & TIMESTAMP_FLAG {
-            header_len + 4
-        } else {
-            header_len
-        };
-
-        let header_len = if 0 != header_type & EXTDENDED_HEADER_FLAG {
-            header_len + 10
-        } else {
-            header_len
-        };
+        let header_len = DltHeader::header_len_from_type(header_type);
 
         // check there is enough data to at least contain the dlt header
         if length < header_len {
@@ -97,11 +70,111 @@ impl<'a> DltPacketSlice<'a> {
             // SAFETY:
             // Safe as it is checked beforehand that the slice
             // has at least length bytes.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), length) },
+            slice: unsafe { sub_slice_unchecked(slice, 0, length) },
             header_len,
         })
     }
 
+    /// Slices a dlt message like [`DltPacketSlice::from_slice`] but
+    /// additionally rejects messages that are not fully well formed.
+    ///
+    /// On top of the checks done by `from_slice` (base & extended header
+    /// present, length field large enough for all flagged header parts)
+    /// the following is verified:
+    ///
+    /// * The header version is 1 (otherwise
+    ///   [`error::PacketSliceError::UnsupportedDltVersion`]).
+    /// * Non verbose messages contain a 4 byte message id (otherwise
+    ///   [`error::PacketSliceError::MissingMessageId`]).
+    pub fn from_slice_strict(
+        slice: &'a [u8],
+    ) -> Result<DltPacketSlice<'a>, error::PacketSliceError> {
+        use error::{PacketSliceError::*, *};
+
+        let result = DltPacketSlice::from_slice(slice)?;
+
+        let version = result.header_version();
+        if 1 != version {
+            return Err(UnsupportedDltVersion(UnsupportedDltVersionError {
+                unsupported_version: version,
+            }));
+        }
+
+        if false == result.is_verbose() && result.header_len + 4 > result.slice.len() {
+            return Err(MissingMessageId(DltMessageLengthTooSmallError {
+                required_length: result.header_len + 4,
+                actual_length: result.slice.len(),
+            }));
+        }
+
+        Ok(result)
+    }
+
+    /// Reads the total length of a dlt message (header + payload) from the
+    /// start of the given slice without requiring the complete message to
+    /// be present.
+    ///
+    /// `Ok(None)` is returned if the slice does not yet contain the
+    /// 4 bytes of the base header. If the base header is present the
+    /// `length` field of the header is returned (also if the rest of the
+    /// message is not yet present in the slice). An error is returned if
+    /// the header contains an unsupported version or a length that is smaller
+    /// then the header itself.
+    pub fn peek_len(slice: &[u8]) -> Result<Option<u16>, error::PacketSliceError> {
+        use error::{PacketSliceError::*, *};
+
+        if slice.len() < 4 {
+            return Ok(None);
+        }
+
+        // SAFETY:
+        // Safe as it is checked beforehand that the slice
+        // has at least 4 bytes.
+        let header_type = unsafe { *slice.get_unchecked(0) };
+
+        // check version
+        let version = (header_type >> 5) & MAX_VERSION;
+        if 0 != version && 1 != version {
+            return Err(UnsupportedDltVersion(UnsupportedDltVersionError {
+                unsupported_version: version,
+            }));
+        }
+
+        let length = u16::from_be_bytes(
+            // SAFETY:
+            // Safe as it is checked beforehand that the slice
+            // has at least 4 bytes.
+            unsafe { [*slice.get_unchecked(2), *slice.get_unchecked(3)] },
+        );
+
+        // calculate the minimum size based on the header flags
+        let header_len = DltHeader::header_len_from_type(header_type);
+
+        // check the length at least contains the dlt header
+        if usize::from(length) < header_len {
+            return Err(MessageLengthTooSmall(DltMessageLengthTooSmallError {
+                required_length: header_len,
+                actual_length: usize::from(length),
+            }));
+        }
+
+        Ok(Some(length))
+    }
+
+    /// Returns the version of the DLT header (bits 5-7 of the header type).
+    ///
+    /// Only packets with a version contained in
+    /// [`DltHeader::SUPPORTED_DECODABLE_VERSIONS`] can be sliced, all other
+    /// versions are rejected by [`DltPacketSlice::from_slice`] with an
+    /// [`error::PacketSliceError::UnsupportedDltVersion`] error.
+    #[inline]
+    pub fn header_version(&self) -> u8 {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        (unsafe { self.slice.get_unchecked(0) } >> 5) & MAX_VERSION
+    }
+
     ///Returns if an extended header is present.
     #[inline]
     pub fn has_extended_header(&self) -> bool {
@@ -111,6 +184,33 @@ impl<'a> DltPacketSlice<'a> {
         0 != unsafe { self.slice.get_unchecked(0) } & 0b1
     }
 
+    ///Returns if an ECU id is present in the standard header.
+    #[inline]
+    pub fn has_ecu_id(&self) -> bool {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        0 != unsafe { self.slice.get_unchecked(0) } & ECU_ID_FLAG
+    }
+
+    ///Returns if a session id is present in the standard header.
+    #[inline]
+    pub fn has_session_id(&self) -> bool {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        0 != unsafe { self.slice.get_unchecked(0) } & SESSION_ID_FLAG
+    }
+
+    ///Returns if a timestamp is present in the standard header.
+    #[inline]
+    pub fn has_timestamp(&self) -> bool {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        0 != unsafe { self.slice.get_unchecked(0) } & TIMESTAMP_FLAG
+    }
+
     ///Returns if the numbers in the payload are encoded in big endian.
     #[inline]
     pub fn is_big_endian(&self) -> bool {
@@ -120,6 +220,121 @@ impl<'a> DltPacketSlice<'a> {
         0 != unsafe { self.slice.get_unchecked(0) } & 0b10
     }
 
+    ///Returns the message counter (second byte of the standard header).
+    ///
+    ///The counter is incremented for each message sent by an application
+    ///and wraps around after 255, which allows detecting lost messages.
+    #[inline]
+    pub fn message_counter(&self) -> u8 {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    ///Returns the ECU id if present in the standard header.
+    #[inline]
+    pub fn ecu_id(&self) -> Option<[u8; 4]> {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        let header_type = unsafe { *self.slice.get_unchecked(0) };
+        if 0 != header_type & ECU_ID_FLAG {
+            // SAFETY:
+            // Safe as it is checked in from_slice that the slice
+            // has the length to contain the standard header based on
+            // the flags contained in the standard header.
+            Some(unsafe { self.read_4_bytes_unchecked(4) })
+        } else {
+            None
+        }
+    }
+
+    ///Returns the session id if present in the standard header.
+    ///
+    ///Note that the fields of the standard header are always encoded in
+    ///big endian (the endianness flag only applies to the payload).
+    #[inline]
+    pub fn session_id(&self) -> Option<u32> {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        let header_type = unsafe { *self.slice.get_unchecked(0) };
+        if 0 != header_type & SESSION_ID_FLAG {
+            let offset = if 0 != header_type & ECU_ID_FLAG { 8 } else { 4 };
+            // SAFETY:
+            // Safe as it is checked in from_slice that the slice
+            // has the length to contain the standard header based on
+            // the flags contained in the standard header.
+            Some(u32::from_be_bytes(unsafe {
+                self.read_4_bytes_unchecked(offset)
+            }))
+        } else {
+            None
+        }
+    }
+
+    ///Returns the timestamp if present in the standard header.
+    ///
+    ///Note that the fields of the standard header are always encoded in
+    ///big endian (the endianness flag only applies to the payload).
+    #[inline]
+    pub fn timestamp(&self) -> Option<u32> {
+        // SAFETY:
+        // Safe as it is checked in from_slice that the slice
+        // has at least a length of 4 bytes.
+        let header_type = unsafe { *self.slice.get_unchecked(0) };
+        if 0 != header_type & TIMESTAMP_FLAG {
+            let mut offset = 4;
+            if 0 != header_type & ECU_ID_FLAG {
+                offset += 4;
+            }
+            if 0 != header_type & SESSION_ID_FLAG {
+                offset += 4;
+            }
+            // SAFETY:
+            // Safe as it is checked in from_slice that the slice
+            // has the length to contain the standard header based on
+            // the flags contained in the standard header.
+            Some(u32::from_be_bytes(unsafe {
+                self.read_4_bytes_unchecked(offset)
+            }))
+        } else {
+            None
+        }
+    }
+
+    ///Returns the timestamp converted to microseconds if present in the
+    ///standard header.
+    ///
+    ///The timestamp field contains the time since the start of the ECU
+    ///in units of 0.1 milliseconds.
+    #[inline]
+    pub fn timestamp_micros(&self) -> Option<u64> {
+        self.timestamp().map(|t| u64::from(t) * 100)
+    }
+
+    /// Reads 4 bytes starting at the given offset without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `offset + 4` must not be bigger then the length of the slice.
+    #[inline]
+    unsafe fn read_4_bytes_unchecked(&self, offset: usize) -> [u8; 4] {
+        debug_assert!(
+            offset <= self.slice.len() && 4 <= self.slice.len() - offset,
+            "DltPacketSlice: 4 bytes at offset {} are out of bounds of the slice with a length of {}",
+            offset,
+            self.slice.len()
+        );
+        [
+            *self.slice.get_unchecked(offset),
+            *self.slice.get_unchecked(offset + 1),
+            *self.slice.get_unchecked(offset + 2),
+            *self.slice.get_unchecked(offset + 3),
+        ]
+    }
+
     ///Returns if the dlt package is verbose or non verbose.
     #[inline]
     pub fn is_verbose(&self) -> bool {
@@ -134,6 +349,50 @@ impl<'a> DltPacketSlice<'a> {
         }
     }
 
+    ///Returns the number of arguments declared in the extended header
+    ///if the message is a verbose message (otherwise None is returned).
+    ///
+    ///Only the header field is read, the payload is not decoded. The
+    ///header field is a single byte, the value is widened to `u16`.
+    #[inline]
+    pub fn verbose_arg_count(&self) -> Option<u16> {
+        if self.is_verbose() {
+            // SAFETY:
+            // Safe as if the extended header is present the
+            // header_len is checked in from_slice to be at least
+            // 10 bytes.
+            Some(u16::from(unsafe {
+                *self.slice.get_unchecked(self.header_len - 9)
+            }))
+        } else {
+            None
+        }
+    }
+
+    ///Returns a zero copy view of the dlt extended header if present.
+    ///
+    ///Cheaper then [`DltPacketSlice::extended_header`] if only single
+    ///fields (e.g. the application & context id) are needed.
+    #[inline]
+    pub fn extended_header_slice(&self) -> Option<DltExtendedHeaderSlice<'a>> {
+        if self.has_extended_header() {
+            // SAFETY:
+            // Safe as if the extended header is present the
+            // header_len is set in from_slice to be at least
+            // 10 bytes and also checked against the slice length.
+            let ext_slice = unsafe {
+                sub_slice_unchecked(
+                    self.slice,
+                    self.header_len - 10,
+                    DltExtendedHeaderSlice::BYTE_LEN,
+                )
+            };
+            DltExtendedHeaderSlice::from_slice(ext_slice).ok()
+        } else {
+            None
+        }
+    }
+
     ///Returns the dlt extended header if present
     #[inline]
     pub fn extended_header(&self) -> Option<DltExtendedHeader> {
@@ -143,7 +402,7 @@ impl<'a> DltPacketSlice<'a> {
             // header_len is set in from_slice to be at least
             // 10 bytes and also checked against the slice length.
             unsafe {
-                let ext_slice = from_raw_parts(self.slice.as_ptr().add(self.header_len - 10), 10);
+                let ext_slice = sub_slice_unchecked(self.slice, self.header_len - 10, 10);
                 Some(DltExtendedHeader {
                     message_info: DltMessageInfo(*ext_slice.get_unchecked(0)),
                     number_of_arguments: *ext_slice.get_unchecked(1),
@@ -166,6 +425,22 @@ impl<'a> DltPacketSlice<'a> {
         }
     }
 
+    ///Returns the message info byte of the extended header (if present).
+    #[inline]
+    pub fn message_info(&self) -> Option<DltMessageInfo> {
+        if self.has_extended_header() {
+            // SAFETY:
+            // Safe as if the extended header is present the
+            // header_len is set in from_slice to be at least
+            // 10 bytes and also checked against the slice length.
+            Some(DltMessageInfo(unsafe {
+                *self.slice.get_unchecked(self.header_len - 10)
+            }))
+        } else {
+            None
+        }
+    }
+
     ///Returns the message type if a parsable message type is present
     #[inline]
     pub fn message_type(&self) -> Option<DltMessageType> {
@@ -182,6 +457,65 @@ impl<'a> DltPacketSlice<'a> {
         }
     }
 
+    ///Returns the log level if the message is a log message (otherwise
+    ///None is returned).
+    ///
+    ///Only the message info byte of the extended header is read, the
+    ///payload is not decoded. This makes it possible to cheaply filter
+    ///messages by their log level:
+    ///
+    ///```
+    ///# use dlt_parse::*;
+    ///# let data = [0u8; 0];
+    ///// keep only messages with a log level of "Warn" or more severe
+    ///let warnings = SliceIterator::new(&data)
+    ///    .filter_map(|packet| packet.ok())
+    ///    .filter(|packet| packet.log_level().map_or(false, |l| l <= DltLogLevel::Warn));
+    ///# assert_eq!(0, warnings.count());
+    ///```
+    #[inline]
+    pub fn log_level(&self) -> Option<DltLogLevel> {
+        match self.message_type() {
+            Some(DltMessageType::Log(level)) => Some(level),
+            _ => None,
+        }
+    }
+
+    ///Returns true if the message type field in the extended header
+    ///marks the message as a control message (no further decoding
+    ///of the message info is done).
+    #[inline]
+    pub fn is_control_message(&self) -> bool {
+        self.extended_header_slice()
+            .is_some_and(|ext| ext.message_info().is_control())
+    }
+
+    ///Returns the service id of a non verbose control message (read
+    ///from the first 4 payload bytes with the payload endianness).
+    ///
+    ///`None` is returned for non control messages, verbose messages & if
+    ///the payload is too short to contain a service id. Together with
+    ///[`control::get_control_command_name`] this can be used to dispatch
+    ///control messages:
+    ///
+    ///```
+    ///# use dlt_parse::*;
+    ///# let data = [0u8; 0];
+    ///for packet in SliceIterator::new(&data).filter_map(|packet| packet.ok()) {
+    ///    if let Some(service_id) = packet.control_service_id() {
+    ///        println!("{:?}", control::get_control_command_name(service_id));
+    ///    }
+    ///}
+    ///```
+    #[inline]
+    pub fn control_service_id(&self) -> Option<u32> {
+        if self.is_control_message() {
+            self.message_id()
+        } else {
+            None
+        }
+    }
+
     /// Returns the message id if the message is a non verbose message
     /// and enough data for a message is present. Otherwise None is returned.
     #[inline]
@@ -214,25 +548,72 @@ impl<'a> DltPacketSlice<'a> {
         self.slice
     }
 
+    /// Returns the number of bytes the dlt message occupies in the slice
+    /// it was read from (equal to the `length` field in the dlt header).
+    ///
+    /// This can be used to advance a cursor to the start of the next
+    /// message without re-reading the length field.
+    #[inline]
+    pub fn consumed_len(&self) -> usize {
+        self.slice.len()
+    }
+
     ///Returns a slice containing the payload of the dlt message
+    ///
+    ///The payload is always within the bounds given by the `length`
+    ///field of the header as [`DltPacketSlice::from_slice`] already
+    ///verifies that the length field is at least as big as the header
+    ///(otherwise [`error::PacketSliceError::MessageLengthTooSmall`] is
+    ///returned) & cuts off any data after the message.
     #[inline]
     pub fn payload(&self) -> &'a [u8] {
         // SAFETY:
-        // Safe as the slice len is checked to be at least
-        // header_len + 4 in from_slice.
+        // Safe as the slice len is set to the length field of the
+        // header in from_slice, which is checked to be at least
+        // header_len.
         unsafe {
-            from_raw_parts(
-                self.slice.as_ptr().add(self.header_len),
+            sub_slice_unchecked(
+                self.slice,
+                self.header_len,
                 self.slice.len() - self.header_len,
             )
         }
     }
 
+    /// Returns the address of the first payload byte modulo 16.
+    ///
+    /// Can be used to decide if the payload can be processed directly by
+    /// SIMD instructions or if it has to be copied to an aligned buffer.
+    #[inline]
+    pub fn payload_alignment(&self) -> usize {
+        (self.payload().as_ptr() as usize) % 16
+    }
+
+    /// Returns true if the address of the first payload byte is a
+    /// multiple of `n` (`false` is returned if `n` is 0).
+    #[inline]
+    pub fn payload_aligned_to(&self, n: usize) -> bool {
+        0 != n && 0 == (self.payload().as_ptr() as usize) % n
+    }
+
+    /// Returns true if the given byte sequence is contained in the raw
+    /// payload (verbose & non verbose). An empty `needle` is always contained.
+    ///
+    /// This allows a fast byte level filtering of messages before
+    /// decoding them.
+    pub fn payload_contains(&self, needle: &[u8]) -> bool {
+        needle.is_empty() || self.payload().windows(needle.len()).any(|w| w == needle)
+    }
+
     /// Returns the message id and a slice containing the payload (after the
     /// message id) if the dlt message is a non verbose message.
     ///
     /// If the message is not a non verbose message or does not have enough
     /// memory for the message id `None` is returned.
+    ///
+    /// The message id is decoded with the endianness of the message
+    /// payload & the returned payload slice borrows the original data.
+    #[doc(alias = "non_verbose_parts")]
     pub fn message_id_and_payload(&self) -> Option<(u32, &'a [u8])> {
         if self.is_verbose() || self.header_len + 4 > self.slice.len() {
             None
@@ -257,8 +638,9 @@ impl<'a> DltPacketSlice<'a> {
             // Safe as the slice len is checked to be at least
             // header_len + 4 in the if check above.
             let non_verbose_payload = unsafe {
-                from_raw_parts(
-                    self.slice.as_ptr().add(self.header_len + 4),
+                sub_slice_unchecked(
+                    self.slice,
+                    self.header_len + 4,
                     self.slice.len() - self.header_len - 4,
                 )
             };
@@ -266,6 +648,21 @@ impl<'a> DltPacketSlice<'a> {
         }
     }
 
+    /// Decodes the payload of a non verbose message via the given
+    /// descriptor (e.g. based on a FIBEX file).
+    ///
+    /// The message id, the payload following it & the payload endianness
+    /// are passed to [`NonVerboseDescriptor::decode`]. `None` is returned
+    /// if the message is not a non verbose message, is too short to contain
+    /// a message id or if the descriptor does not know the message id.
+    pub fn decode_non_verbose<D: NonVerboseDescriptor>(
+        &self,
+        descriptor: &D,
+    ) -> Option<Result<D::Output<'a>, D::Error>> {
+        let (message_id, payload) = self.message_id_and_payload()?;
+        descriptor.decode(message_id, payload, self.is_big_endian())
+    }
+
     /// Returns a slice containing the payload of a non verbose message (after the message id).
     pub fn non_verbose_payload(&self) -> Option<&'a [u8]> {
         if self.is_verbose() || self.header_len + 4 > self.slice.len() {
@@ -275,8 +672,9 @@ impl<'a> DltPacketSlice<'a> {
             // Safe as the slice len is checked to be at least
             // header_len + 4 in the if check above.
             Some(unsafe {
-                from_raw_parts(
-                    self.slice.as_ptr().add(self.header_len + 4),
+                sub_slice_unchecked(
+                    self.slice,
+                    self.header_len + 4,
                     self.slice.len() - self.header_len - 4,
                 )
             })
@@ -284,6 +682,50 @@ impl<'a> DltPacketSlice<'a> {
     }
 
     /// Returns a iterator over the verbose values (if the dlt message is a verbose message).
+    ///
+    /// The iterator is already set up with the endianness & number of
+    /// arguments of the message. For non verbose messages `None` is returned.
+    ///
+    /// Verbose messages with zero arguments (e.g. marker messages) result in
+    /// an iterator that yields no values. Payload bytes that are not part of
+    /// the decoded arguments are ignored & can be accessed via
+    /// [`VerboseIter::raw`] once the iteration ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use dlt_parse::{DltHeader, DltExtendedHeader, DltLogLevel};
+    /// # use dlt_parse::verbose::{U32Value, VerboseValue};
+    /// # let mut header = DltHeader {
+    /// #     is_big_endian: true,
+    /// #     message_counter: 0,
+    /// #     length: 0,
+    /// #     ecu_id: None,
+    /// #     session_id: None,
+    /// #     timestamp: None,
+    /// #     extended_header: Some({
+    /// #         let mut ext = DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1");
+    /// #         ext.set_is_verbose(true);
+    /// #         ext.number_of_arguments = 1;
+    /// #         ext
+    /// #     }),
+    /// # };
+    /// # let mut payload = arrayvec::ArrayVec::<u8, 16>::new();
+    /// # VerboseValue::U32(U32Value { variable_info: None, scaling: None, value: 123 })
+    /// #     .add_to_msg(&mut payload, true)
+    /// #     .unwrap();
+    /// # header.length = header.header_len() + payload.len() as u16;
+    /// # let mut packet = header.to_bytes().to_vec();
+    /// # packet.extend_from_slice(&payload);
+    /// use dlt_parse::DltPacketSlice;
+    ///
+    /// let slice = DltPacketSlice::from_slice(&packet).unwrap();
+    /// if let Some(iter) = slice.verbose_value_iter() {
+    ///     for value in iter {
+    ///         println!("{:?}", value.expect("failed to decode verbose value"));
+    ///     }
+    /// }
+    /// ```
     pub fn verbose_value_iter(&self) -> Option<VerboseIter<'a>> {
         // verbose messages are required to have an extended header
         if self.has_extended_header() {
@@ -291,8 +733,7 @@ impl<'a> DltPacketSlice<'a> {
             // Safe as if the extended header is present the
             // header_len is set in from_slice to be at least
             // 10 bytes and also checked against the slice length.
-            let ext_slice =
-                unsafe { from_raw_parts(self.slice.as_ptr().add(self.header_len - 10), 10) };
+            let ext_slice = unsafe { sub_slice_unchecked(self.slice, self.header_len - 10, 10) };
 
             // check if the verbose flag is set (aka check that this is a verbose dlt message)
             // SAFETY:
@@ -315,6 +756,111 @@ impl<'a> DltPacketSlice<'a> {
         }
     }
 
+    /// Decodes the verbose arguments of the message & stores the scalar
+    /// values (bools, integers & floats) in the given buffer.
+    ///
+    /// The buffer is cleared before decoding. Non scalar arguments are
+    /// skipped (see [`VerboseScalarBuf::num_skipped`]). If the message is not
+    /// a verbose message the buffer stays empty.
+    pub fn decode_scalars_into<const N: usize>(
+        &self,
+        buf: &mut VerboseScalarBuf<N>,
+    ) -> Result<(), error::VerboseDecodeError> {
+        buf.clear();
+        if let Some(iter) = self.verbose_value_iter() {
+            for value in iter {
+                buf.push(&value?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the header & payload into an owned [`DltPacket`] that can
+    /// be kept after the original buffer is dropped.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_packet(&self) -> DltPacket {
+        DltPacket::from(self)
+    }
+
+    /// Returns the header fields & verbose arguments of the message as a flat
+    /// key/value map (e.g. for exporting messages to structured logging sinks).
+    ///
+    /// The following keys are set (if the corresponding value is present
+    /// in the message): `ecu`, `session_id`, `timestamp`, `message_counter`,
+    /// `app`, `ctx` & `level` (only for log messages). The arguments of verbose
+    /// messages are added with their variable name as key or `arg{n}` (`n`
+    /// being the index of the argument) if they have no name or the name is
+    /// already in use. If `arg{n}` is in use as well (e.g. by an argument
+    /// with that name), `_` is appended until the key is unique, so no
+    /// argument overwrites another one.
+    ///
+    /// Arrays & structs are exported as their raw encoded data
+    /// ([`FieldValue::Bytes`]) & 128 bit floating point values as their
+    /// bit pattern ([`FieldValue::UInt`]), see [`FieldValue`].
+    ///
+    /// An error is returned if a verbose argument can not be decoded.
+    #[cfg(feature = "alloc")]
+    pub fn to_fields(
+        &self,
+    ) -> Result<BTreeMap<Cow<'a, str>, FieldValue<'a>>, error::VerboseDecodeError> {
+        fn id_str(id: &[u8; 4]) -> FieldValue<'static> {
+            let len = id.iter().position(|b| 0 == *b).unwrap_or(id.len());
+            FieldValue::Str(Cow::Owned(String::from_utf8_lossy(&id[..len]).into_owned()))
+        }
+
+        let mut result = BTreeMap::new();
+        let header = self.header();
+        if let Some(ecu_id) = &header.ecu_id {
+            result.insert(Cow::Borrowed("ecu"), id_str(ecu_id));
+        }
+        if let Some(session_id) = header.session_id {
+            result.insert(
+                Cow::Borrowed("session_id"),
+                FieldValue::UInt(u128::from(session_id)),
+            );
+        }
+        if let Some(timestamp) = header.timestamp {
+            result.insert(
+                Cow::Borrowed("timestamp"),
+                FieldValue::UInt(u128::from(timestamp)),
+            );
+        }
+        result.insert(
+            Cow::Borrowed("message_counter"),
+            FieldValue::UInt(u128::from(header.message_counter)),
+        );
+        if let Some(ext) = &header.extended_header {
+            result.insert(Cow::Borrowed("app"), id_str(&ext.application_id));
+            result.insert(Cow::Borrowed("ctx"), id_str(&ext.context_id));
+            if let Some(DltMessageType::Log(level)) = ext.message_type() {
+                result.insert(
+                    Cow::Borrowed("level"),
+                    FieldValue::Str(Cow::Borrowed(level.name())),
+                );
+            }
+        }
+
+        if let Some(iter) = self.verbose_value_iter() {
+            for (index, value) in iter.enumerate() {
+                let value = value?;
+                let key = match value.name() {
+                    Some(name) if false == result.contains_key(name) => Cow::Borrowed(name),
+                    _ => {
+                        let mut key = format!("arg{}", index);
+                        while result.contains_key(key.as_str()) {
+                            key.push('_');
+                        }
+                        Cow::Owned(key)
+                    }
+                };
+                result.insert(key, FieldValue::from(&value));
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Returns the verbose or non verbose payload of the given dlt message (if it has one).
     #[inline]
     pub fn typed_payload(&self) -> Result<DltTypedPayload<'a>, TypedPayloadError> {
@@ -324,8 +870,7 @@ impl<'a> DltPacketSlice<'a> {
             // Safe as if the extended header is present the
             // header_len is set in from_slice to be at least
             // 10 bytes and also checked against the slice length.
-            let ext_slice =
-                unsafe { from_raw_parts(self.slice.as_ptr().add(self.header_len - 10), 10) };
+            let ext_slice = unsafe { sub_slice_unchecked(self.slice, self.header_len - 10, 10) };
 
             // check if the verbose flag is set (aka check that this is a verbose dlt message)
             // SAFETY:
@@ -415,8 +960,9 @@ impl<'a> DltPacketSlice<'a> {
             // Safe as the slice len is checked to be at least
             // header_len + 4 in the if check above.
             let non_verbose_payload = unsafe {
-                from_raw_parts(
-                    self.slice.as_ptr().add(self.header_len + 4),
+                sub_slice_unchecked(
+                    self.slice,
+                    self.header_len + 4,
                     self.slice.len() - self.header_len - 4,
                 )
             };
@@ -479,10 +1025,7 @@ impl<'a> DltPacketSlice<'a> {
         let header_type = unsafe { *self.slice.get_unchecked(0) };
         let is_big_endian = 0 != header_type & BIG_ENDIAN_FLAG;
 
-        // SAFETY:
-        // Safe as it is checked in from_slice that the slice
-        // has at least a length of 4 bytes.
-        let message_counter = unsafe { *self.slice.get_unchecked(1) };
+        let message_counter = self.message_counter();
         let length = u16::from_be_bytes(
             // SAFETY:
             // Safe as it is checked in from_slice that the slice
@@ -510,7 +1053,7 @@ impl<'a> DltPacketSlice<'a> {
                 // Safe as it is checked in from_slice that the slice
                 // has the length to contain the standard & extended header
                 // based on the flags contained in the standard header.
-                unsafe { from_raw_parts(self.slice.as_ptr().add(8), self.slice.len() - 8) },
+                unsafe { sub_slice_unchecked(self.slice, 8, self.slice.len() - 8) },
             )
         } else {
             (
@@ -520,7 +1063,7 @@ impl<'a> DltPacketSlice<'a> {
                 // has at least the length of 4 bytes.
                 unsafe {
                     // go after the standard header base
-                    from_raw_parts(self.slice.as_ptr().add(4), self.slice.len() - 4)
+                    sub_slice_unchecked(self.slice, 4, self.slice.len() - 4)
                 },
             )
         };
@@ -545,7 +1088,7 @@ impl<'a> DltPacketSlice<'a> {
                 // Safe as it is checked in from_slice that the slice
                 // has the length to contain the standard & extended header
                 // based on the flags contained in the standard header.
-                unsafe { from_raw_parts(slice.as_ptr().add(4), slice.len() - 4) },
+                unsafe { sub_slice_unchecked(slice, 4, slice.len() - 4) },
             )
         } else {
             (None, slice)
@@ -571,7 +1114,7 @@ impl<'a> DltPacketSlice<'a> {
                 // Safe as it is checked in from_slice that the slice
                 // has the length to contain the standard & extended header
                 // based on the flags contained in the standard header.
-                unsafe { from_raw_parts(slice.as_ptr().add(4), slice.len() - 4) },
+                unsafe { sub_slice_unchecked(slice, 4, slice.len() - 4) },
             )
         } else {
             (None, slice)
@@ -618,6 +1161,29 @@ impl<'a> DltPacketSlice<'a> {
     }
 }
 
+/// Returns the sub slice `slice[start..start + len]` without bounds checks
+/// in release builds.
+///
+/// In debug builds the range is verified against the slice length & a
+/// descriptive panic is triggered if it is out of bounds. This makes
+/// wrong offset calculations visible when fuzzing (instead of silently
+/// reading out of bounds).
+///
+/// # Safety
+///
+/// `start + len` must not be bigger then `slice.len()`.
+#[inline]
+unsafe fn sub_slice_unchecked(slice: &[u8], start: usize, len: usize) -> &[u8] {
+    debug_assert!(
+        start <= slice.len() && len <= slice.len() - start,
+        "DltPacketSlice: range {}..{} is out of bounds of the slice with a length of {}",
+        start,
+        start.saturating_add(len),
+        slice.len()
+    );
+    from_raw_parts(slice.as_ptr().add(start), len)
+}
+
 /// Tests for `DltPacketSlice` methods
 #[cfg(test)]
 mod tests {
@@ -626,6 +1192,29 @@ mod tests {
     use crate::proptest_generators::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn sub_slice_unchecked() {
+        let data = [1, 2, 3, 4];
+        // SAFETY: All ranges are within the data.
+        unsafe {
+            assert_eq!(&data[..], super::sub_slice_unchecked(&data, 0, 4));
+            assert_eq!(&data[1..3], super::sub_slice_unchecked(&data, 1, 2));
+            assert!(super::sub_slice_unchecked(&data, 4, 0).is_empty());
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "range 3..5 is out of bounds of the slice with a length of 4")]
+    fn sub_slice_unchecked_debug_check() {
+        let data = [1, 2, 3, 4];
+        // SAFETY: Not safe, but the debug check panics before the
+        // slice is created.
+        unsafe {
+            super::sub_slice_unchecked(&data, 3, 2);
+        }
+    }
+
     #[test]
     fn debug() {
         let mut header: DltHeader = Default::default();
@@ -686,6 +1275,14 @@ mod tests {
             assert_eq!(slice.is_verbose(), packet.0.is_verbose());
             assert_eq!(slice.payload(), &packet.1[..]);
             assert_eq!(slice.extended_header(), packet.0.extended_header);
+            assert_eq!(
+                slice.extended_header_slice().map(|e| e.to_header()),
+                packet.0.extended_header
+            );
+            assert_eq!(
+                slice.message_info(),
+                packet.0.extended_header.as_ref().map(|e| e.message_info)
+            );
 
             if let Some(packet_ext_header) = packet.0.extended_header.as_ref() {
                 assert_eq!(slice.message_type(), packet_ext_header.message_type());
@@ -714,6 +1311,770 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn standard_header_fields(
+            ref packet in dlt_header_with_payload_any(),
+            ecu_id in proptest::option::of(any::<[u8;4]>()),
+            session_id in proptest::option::of(any::<u32>()),
+            timestamp in proptest::option::of(any::<u32>()),
+        ) {
+            for is_big_endian in [false, true] {
+                let mut header = packet.0.clone();
+                header.is_big_endian = is_big_endian;
+                header.ecu_id = ecu_id;
+                header.session_id = session_id;
+                header.timestamp = timestamp;
+                header.length = header.header_len() + packet.1.len() as u16;
+
+                let mut buffer = Vec::with_capacity(usize::from(header.length));
+                buffer.extend_from_slice(&header.to_bytes());
+                buffer.extend_from_slice(&packet.1);
+
+                let slice = DltPacketSlice::from_slice(&buffer).unwrap();
+                prop_assert_eq!(ecu_id, slice.ecu_id());
+                prop_assert_eq!(session_id, slice.session_id());
+                prop_assert_eq!(timestamp, slice.timestamp());
+                prop_assert_eq!(timestamp.map(|t| u64::from(t) * 100), slice.timestamp_micros());
+                prop_assert_eq!(slice.header().session_id, slice.session_id());
+                prop_assert_eq!(ecu_id.is_some(), slice.has_ecu_id());
+                prop_assert_eq!(session_id.is_some(), slice.has_session_id());
+                prop_assert_eq!(timestamp.is_some(), slice.has_timestamp());
+                prop_assert_eq!(header.extended_header.is_some(), slice.has_extended_header());
+                prop_assert_eq!(header.message_counter, slice.message_counter());
+            }
+        }
+    }
+
+    #[test]
+    fn message_counter() {
+        // header without optional fields & a message counter of 0xab
+        let data = [0b0010_0000, 0xab, 0, 4];
+        let slice = DltPacketSlice::from_slice(&data).unwrap();
+        assert_eq!(0xab, slice.message_counter());
+        assert_eq!(0xab, slice.header().message_counter);
+    }
+
+    proptest! {
+        #[test]
+        fn consumed_len(
+            ref packet0 in dlt_header_with_payload_any(),
+            ref packet1 in dlt_header_with_payload_any(),
+        ) {
+            let mut buffer = Vec::with_capacity(
+                usize::from(packet0.0.length) + usize::from(packet1.0.length)
+            );
+            buffer.extend_from_slice(&packet0.0.to_bytes());
+            buffer.extend_from_slice(&packet0.1);
+            buffer.extend_from_slice(&packet1.0.to_bytes());
+            buffer.extend_from_slice(&packet1.1);
+
+            // first packet
+            let slice0 = DltPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(slice0.consumed_len(), usize::from(packet0.0.length));
+            assert_eq!(slice0.consumed_len(), slice0.slice().len());
+
+            // advancing by the consumed len should land on the next packet
+            let slice1 = DltPacketSlice::from_slice(&buffer[slice0.consumed_len()..]).unwrap();
+            assert_eq!(slice1.header(), packet1.0);
+            assert_eq!(slice1.consumed_len(), usize::from(packet1.0.length));
+            assert_eq!(slice0.consumed_len() + slice1.consumed_len(), buffer.len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn verbose_arg_count(
+            ref packet in dlt_header_with_payload_any(),
+            number_of_arguments in any::<u8>(),
+        ) {
+            // verbose message with known count
+            {
+                let mut header = packet.0.clone();
+                let mut ext = DltExtendedHeader::new_non_verbose(
+                    DltMessageType::Log(DltLogLevel::Info), *b"APP1", *b"CTX1"
+                ).unwrap();
+                ext.set_is_verbose(true);
+                ext.number_of_arguments = number_of_arguments;
+                header.extended_header = Some(ext);
+                header.length = header.header_len();
+
+                let bytes = header.to_bytes();
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert_eq!(Some(u16::from(number_of_arguments)), slice.verbose_arg_count());
+            }
+
+            // non verbose message
+            {
+                let mut header = packet.0.clone();
+                let mut ext = DltExtendedHeader::new_non_verbose(
+                    DltMessageType::Log(DltLogLevel::Info), *b"APP1", *b"CTX1"
+                ).unwrap();
+                ext.number_of_arguments = number_of_arguments;
+                header.extended_header = Some(ext);
+                header.length = header.header_len();
+
+                let bytes = header.to_bytes();
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert_eq!(None, slice.verbose_arg_count());
+            }
+
+            // no extended header
+            {
+                let mut header = packet.0.clone();
+                header.extended_header = None;
+                header.length = header.header_len();
+
+                let bytes = header.to_bytes();
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert_eq!(None, slice.verbose_arg_count());
+            }
+        }
+    }
+
+    #[test]
+    fn decode_non_verbose() {
+        /// Descriptor passing through the payload of the message id 1.
+        struct Descriptor;
+
+        impl NonVerboseDescriptor for Descriptor {
+            type Output<'a> = (&'a [u8], bool);
+            type Error = usize;
+
+            fn decode<'a>(
+                &self,
+                message_id: u32,
+                payload: &'a [u8],
+                is_big_endian: bool,
+            ) -> Option<Result<(&'a [u8], bool), usize>> {
+                match message_id {
+                    1 if payload.len() == 2 => Some(Ok((payload, is_big_endian))),
+                    1 => Some(Err(payload.len())),
+                    _ => None,
+                }
+            }
+        }
+
+        let packet = |is_big_endian: bool, verbose: bool, payload: &[u8]| {
+            let mut header = DltHeader {
+                is_big_endian,
+                ..Default::default()
+            };
+            if verbose {
+                let mut ext =
+                    DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1");
+                ext.set_is_verbose(true);
+                header.extended_header = Some(ext);
+            }
+            header.length = header.header_len() + payload.len() as u16;
+            let mut bytes = header.to_bytes().to_vec();
+            bytes.extend_from_slice(payload);
+            bytes
+        };
+
+        for is_big_endian in [false, true] {
+            let id = |id: u32| {
+                if is_big_endian {
+                    id.to_be_bytes()
+                } else {
+                    id.to_le_bytes()
+                }
+            };
+
+            // known message id
+            let mut payload = id(1).to_vec();
+            payload.extend_from_slice(&[5, 6]);
+            let bytes = packet(is_big_endian, false, &payload);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Some(Ok((&[5u8, 6][..], is_big_endian))),
+                slice.decode_non_verbose(&Descriptor)
+            );
+
+            // descriptor error
+            let bytes = packet(is_big_endian, false, &payload[..5]);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(Some(Err(1)), slice.decode_non_verbose(&Descriptor));
+
+            // unknown message id
+            let bytes = packet(is_big_endian, false, &id(2));
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(None, slice.decode_non_verbose(&Descriptor));
+
+            // too short for a message id
+            let bytes = packet(is_big_endian, false, &id(1)[..3]);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(None, slice.decode_non_verbose(&Descriptor));
+
+            // verbose message
+            let bytes = packet(is_big_endian, true, &payload);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(None, slice.decode_non_verbose(&Descriptor));
+        }
+    }
+
+    #[test]
+    fn verbose_value_iter_zero_arguments() {
+        for is_big_endian in [false, true] {
+            let mut ext =
+                DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1");
+            ext.set_is_verbose(true);
+            ext.number_of_arguments = 0;
+            let mut header = DltHeader {
+                is_big_endian,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: Some(ext),
+            };
+
+            // without payload
+            {
+                header.length = header.header_len();
+                let bytes = header.to_bytes();
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                let mut iter = slice.verbose_value_iter().unwrap();
+                assert_eq!(None, iter.next());
+                assert!(iter.raw().is_empty());
+            }
+
+            // leftover payload bytes are ignored
+            {
+                header.length = header.header_len() + 3;
+                let mut bytes = header.to_bytes().to_vec();
+                bytes.extend_from_slice(&[1, 2, 3]);
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                let mut iter = slice.verbose_value_iter().unwrap();
+                assert_eq!((0, Some(0)), iter.size_hint());
+                assert_eq!(None, iter.next());
+                assert_eq!(None, iter.next());
+                assert_eq!(&[1, 2, 3], iter.raw());
+            }
+        }
+    }
+
+    #[test]
+    fn log_level() {
+        use crate::verbose::{U32Value, VerboseValue};
+
+        for level in [
+            DltLogLevel::Fatal,
+            DltLogLevel::Error,
+            DltLogLevel::Warn,
+            DltLogLevel::Info,
+            DltLogLevel::Debug,
+            DltLogLevel::Verbose,
+        ] {
+            // verbose message with 50 arguments
+            let header = DltHeader {
+                is_big_endian: true,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: None,
+            };
+            let mut builder = VerboseMessageBuilder::<1024>::new(
+                header.clone(),
+                DltExtendedHeader::new_non_verbose_log(level, *b"APP1", *b"CTX1"),
+            )
+            .unwrap();
+            for i in 0..50 {
+                builder
+                    .push(&VerboseValue::U32(U32Value {
+                        variable_info: None,
+                        scaling: None,
+                        value: i,
+                    }))
+                    .unwrap();
+            }
+            let mut bytes = builder.finalize().unwrap();
+            assert_eq!(
+                Some(level),
+                DltPacketSlice::from_slice(&bytes).unwrap().log_level()
+            );
+
+            // overwrite the arguments with undecodable data to verify they
+            // are not touched when determining the log level
+            let header_len = usize::from(header.header_len()) + DltExtendedHeaderSlice::BYTE_LEN;
+            for b in bytes[header_len..].iter_mut() {
+                *b = 0xff;
+            }
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert!(slice.verbose_value_iter().unwrap().next().unwrap().is_err());
+            assert_eq!(Some(level), slice.log_level());
+        }
+
+        // non log messages
+        for message_type in [
+            DltMessageType::Trace(DltTraceType::Variable),
+            DltMessageType::NetworkTrace(DltNetworkType::Ipc),
+            DltMessageType::Control(DltControlMessageType::Request),
+        ] {
+            let mut header = DltHeader {
+                is_big_endian: true,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: Some(
+                    DltExtendedHeader::new_non_verbose(message_type, *b"APP1", *b"CTX1").unwrap(),
+                ),
+            };
+            header.length = header.header_len();
+            let bytes = header.to_bytes();
+            assert_eq!(
+                None,
+                DltPacketSlice::from_slice(&bytes).unwrap().log_level()
+            );
+        }
+
+        // no extended header
+        {
+            let mut header = DltHeader {
+                is_big_endian: true,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: None,
+            };
+            header.length = header.header_len();
+            let bytes = header.to_bytes();
+            assert_eq!(
+                None,
+                DltPacketSlice::from_slice(&bytes).unwrap().log_level()
+            );
+        }
+    }
+
+    #[test]
+    fn control_message() {
+        let header = |is_big_endian, message_type: Option<DltMessageType>| DltHeader {
+            is_big_endian,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: message_type.map(|message_type| {
+                DltExtendedHeader::new_non_verbose(message_type, *b"APP1", *b"CTX1").unwrap()
+            }),
+        };
+        let packet = |mut header: DltHeader, payload: &[u8]| {
+            header.length = header.header_len() + payload.len() as u16;
+            let mut bytes = header.to_bytes().to_vec();
+            bytes.extend_from_slice(payload);
+            bytes
+        };
+
+        // control messages
+        for msg_type in [
+            DltControlMessageType::Request,
+            DltControlMessageType::Response,
+        ] {
+            for is_big_endian in [false, true] {
+                let message_type = Some(DltMessageType::Control(msg_type));
+                let service_id = 0x13u32;
+                let id_bytes = if is_big_endian {
+                    service_id.to_be_bytes()
+                } else {
+                    service_id.to_le_bytes()
+                };
+                let bytes = packet(header(is_big_endian, message_type), &id_bytes);
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert!(slice.is_control_message());
+                assert_eq!(Some(service_id), slice.control_service_id());
+
+                // payload too short for a service id
+                let bytes = packet(header(is_big_endian, message_type), &id_bytes[..3]);
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert!(slice.is_control_message());
+                assert_eq!(None, slice.control_service_id());
+
+                // verbose control message
+                let mut h = header(is_big_endian, message_type);
+                h.extended_header.as_mut().unwrap().set_is_verbose(true);
+                let bytes = packet(h, &id_bytes);
+                let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+                assert!(slice.is_control_message());
+                assert_eq!(None, slice.control_service_id());
+            }
+        }
+
+        // non control messages
+        for message_type in [
+            None,
+            Some(DltMessageType::Log(DltLogLevel::Info)),
+            Some(DltMessageType::Trace(DltTraceType::Variable)),
+            Some(DltMessageType::NetworkTrace(DltNetworkType::Ipc)),
+        ] {
+            let bytes = packet(header(true, message_type), &[0, 0, 0, 0x13]);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert!(false == slice.is_control_message());
+            assert_eq!(None, slice.control_service_id());
+        }
+    }
+
+    #[test]
+    fn payload_bounds() {
+        use error::{DltMessageLengthTooSmallError, PacketSliceError::*};
+
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: Some(*b"ECU1"),
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        header.length = header.header_len() + 4;
+        let mut buffer = header.to_bytes().to_vec();
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        // data after the message
+        buffer.extend_from_slice(&[5, 6]);
+
+        // payload ends at the length given in the header
+        {
+            let slice = DltPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(&[1, 2, 3, 4], slice.payload());
+        }
+
+        // length field smaller than the header
+        for length in 0..header.header_len() {
+            let mut buffer = buffer.clone();
+            buffer[2..4].copy_from_slice(&length.to_be_bytes());
+            assert_eq!(
+                Err(MessageLengthTooSmall(DltMessageLengthTooSmallError {
+                    required_length: usize::from(header.header_len()),
+                    actual_length: usize::from(length),
+                })),
+                DltPacketSlice::from_slice(&buffer)
+            );
+        }
+
+        // length field bigger than the data
+        {
+            let mut buffer = buffer.clone();
+            let length = buffer.len() as u16 + 1;
+            buffer[2..4].copy_from_slice(&length.to_be_bytes());
+            assert_matches!(
+                DltPacketSlice::from_slice(&buffer),
+                Err(UnexpectedEndOfSlice(_))
+            );
+        }
+    }
+
+    #[test]
+    fn payload_alignment() {
+        #[repr(align(16))]
+        struct AlignedBuffer([u8; 32]);
+
+        let header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 4 + 8,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        let mut buffer = AlignedBuffer([0; 32]);
+        for offset in [0usize, 4, 9, 12] {
+            buffer.0[offset..offset + 4].copy_from_slice(&header.to_bytes());
+            let slice = DltPacketSlice::from_slice(&buffer.0[offset..]).unwrap();
+
+            // the payload starts directly after the 4 byte header
+            let expected = (offset + 4) % 16;
+            assert_eq!(expected, slice.payload_alignment());
+            assert_eq!(0 == expected, slice.payload_aligned_to(16));
+            assert_eq!(0 == expected % 4, slice.payload_aligned_to(4));
+            assert!(slice.payload_aligned_to(1));
+            assert!(false == slice.payload_aligned_to(0));
+        }
+    }
+
+    #[test]
+    fn decode_scalars_into() {
+        use crate::verbose::*;
+
+        let values = [
+            VerboseValue::U32(U32Value {
+                variable_info: None,
+                scaling: None,
+                value: 1234,
+            }),
+            VerboseValue::Str(StringValue::new(None, "text")),
+            VerboseValue::I16(I16Value {
+                variable_info: None,
+                scaling: None,
+                value: -12,
+            }),
+            VerboseValue::F64(F64Value {
+                variable_info: None,
+                value: 1.5,
+            }),
+        ];
+        for is_big_endian in [false, true] {
+            let mut header = DltHeader {
+                is_big_endian,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: Some({
+                    let mut ext = DltExtendedHeader::new_non_verbose_log(
+                        DltLogLevel::Info,
+                        *b"APP1",
+                        *b"CTX1",
+                    );
+                    ext.set_is_verbose(true);
+                    ext.number_of_arguments = values.len() as u8;
+                    ext
+                }),
+            };
+            let mut payload = ArrayVec::<u8, 128>::new();
+            for value in &values {
+                value.add_to_msg(&mut payload, is_big_endian).unwrap();
+            }
+            header.length = header.header_len() + payload.len() as u16;
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.extend_from_slice(&payload);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+
+            // decode mix of values
+            let mut buf = VerboseScalarBuf::<4>::new();
+            slice.decode_scalars_into(&mut buf).unwrap();
+            assert_eq!(3, buf.values().len());
+            assert_eq!(1, buf.num_skipped());
+            assert_eq!(0, buf.num_overflowed());
+            assert_eq!(VerboseScalarType::U32, buf.values()[0].scalar_type);
+            assert_eq!(Some(1234), buf.values()[0].as_u128());
+            assert_eq!(VerboseScalarType::I16, buf.values()[1].scalar_type);
+            assert_eq!(Some(-12), buf.values()[1].as_i128());
+            assert_eq!(VerboseScalarType::F64, buf.values()[2].scalar_type);
+            assert_eq!(Some(1.5), buf.values()[2].as_f64());
+
+            // decoding again resets the buffer & too small buffer
+            let mut buf = VerboseScalarBuf::<1>::new();
+            slice.decode_scalars_into(&mut buf).unwrap();
+            slice.decode_scalars_into(&mut buf).unwrap();
+            assert_eq!(1, buf.values().len());
+            assert_eq!(1, buf.num_skipped());
+            assert_eq!(2, buf.num_overflowed());
+
+            // decoding error (missing data)
+            let mut bad_bytes = bytes.clone();
+            bad_bytes.truncate(bytes.len() - 1);
+            bad_bytes[3] -= 1;
+            let bad_slice = DltPacketSlice::from_slice(&bad_bytes).unwrap();
+            let mut buf = VerboseScalarBuf::<4>::new();
+            assert!(bad_slice.decode_scalars_into(&mut buf).is_err());
+        }
+
+        // non verbose message
+        {
+            let mut header = DltHeader {
+                is_big_endian: true,
+                message_counter: 0,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: None,
+            };
+            header.length = header.header_len() + 4;
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.extend_from_slice(&[1, 2, 3, 4]);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+
+            let mut buf = VerboseScalarBuf::<4>::new();
+            slice.decode_scalars_into(&mut buf).unwrap();
+            assert!(buf.values().is_empty());
+            assert_eq!(0, buf.num_skipped());
+        }
+    }
+
+    #[test]
+    fn header_version() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&header.to_bytes());
+        bytes.extend_from_slice(&[1, 2, 3, 4]);
+
+        // supported versions
+        for version in DltHeader::SUPPORTED_DECODABLE_VERSIONS {
+            bytes[0] = (bytes[0] & 0b0001_1111) | (version << 5);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(version, slice.header_version());
+        }
+
+        // unsupported versions are reported by the slice iterator
+        for version in 2..=MAX_VERSION {
+            bytes[0] = (bytes[0] & 0b0001_1111) | (version << 5);
+            let mut iter = SliceIterator::new(&bytes);
+            assert_eq!(
+                Some(Err(error::PacketSliceError::UnsupportedDltVersion(
+                    error::UnsupportedDltVersionError {
+                        unsupported_version: version
+                    }
+                ))),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn payload_contains() {
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: Some(*b"ECU1"),
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        header.length = header.header_len() + 6;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&header.to_bytes());
+        bytes.extend_from_slice(b"abcdef");
+        let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+
+        // present
+        assert!(slice.payload_contains(b""));
+        assert!(slice.payload_contains(b"a"));
+        assert!(slice.payload_contains(b"cde"));
+        assert!(slice.payload_contains(b"abcdef"));
+
+        // absent
+        assert!(false == slice.payload_contains(b"x"));
+        assert!(false == slice.payload_contains(b"ace"));
+        assert!(false == slice.payload_contains(b"abcdefg"));
+        // header data is not part of the payload
+        assert!(false == slice.payload_contains(b"ECU1"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_fields() {
+        use crate::verbose::{BoolValue, StringValue, U32Value, VerboseValue};
+        use alloc::borrow::Cow;
+
+        // verbose log message with named, unnamed & duplicate named arguments
+        {
+            let header = DltHeader {
+                is_big_endian: true,
+                message_counter: 12,
+                length: 0,
+                ecu_id: Some(*b"EC\0\0"),
+                session_id: Some(34),
+                timestamp: Some(5678),
+                extended_header: None,
+            };
+            let mut builder = DltMessageBuilder::new(
+                header,
+                DltExtendedHeader::new_non_verbose_log(DltLogLevel::Warn, *b"APP1", *b"CTX1"),
+            );
+            builder
+                .add_value(VerboseValue::Str(StringValue::new(Some("speed"), "fast")))
+                .add_value(VerboseValue::U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 50,
+                }))
+                .add_value(VerboseValue::Bool(BoolValue {
+                    name: Some("speed"),
+                    value: true,
+                }));
+            let bytes = builder.build().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            let fields = slice.to_fields().unwrap();
+
+            let expected = [
+                ("app", FieldValue::Str(Cow::Borrowed("APP1"))),
+                ("arg1", FieldValue::UInt(50)),
+                ("arg2", FieldValue::Bool(true)),
+                ("ctx", FieldValue::Str(Cow::Borrowed("CTX1"))),
+                ("ecu", FieldValue::Str(Cow::Borrowed("EC"))),
+                ("level", FieldValue::Str(Cow::Borrowed("warn"))),
+                ("message_counter", FieldValue::UInt(12)),
+                ("session_id", FieldValue::UInt(34)),
+                ("speed", FieldValue::Str(Cow::Borrowed("fast"))),
+                ("timestamp", FieldValue::UInt(5678)),
+            ];
+            assert_eq!(fields.len(), expected.len());
+            for ((key, value), (expected_key, expected_value)) in fields.iter().zip(expected.iter())
+            {
+                assert_eq!(key, expected_key);
+                assert_eq!(value, expected_value);
+            }
+        }
+
+        // arguments named like the generated keys are not overwritten
+        {
+            let mut builder = DltMessageBuilder::new(
+                DltHeader {
+                    is_big_endian: true,
+                    ..Default::default()
+                },
+                DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1"),
+            );
+            builder
+                .add_value(VerboseValue::Bool(BoolValue {
+                    name: Some("arg1"),
+                    value: true,
+                }))
+                .add_value(VerboseValue::U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 50,
+                }))
+                .add_value(VerboseValue::Bool(BoolValue {
+                    name: Some("arg1"),
+                    value: false,
+                }));
+            let bytes = builder.build().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            let fields = slice.to_fields().unwrap();
+            assert_eq!(Some(&FieldValue::Bool(true)), fields.get("arg1"));
+            assert_eq!(Some(&FieldValue::UInt(50)), fields.get("arg1_"));
+            assert_eq!(Some(&FieldValue::Bool(false)), fields.get("arg2"));
+        }
+
+        // message without extended header
+        {
+            let mut header = DltHeader {
+                is_big_endian: true,
+                message_counter: 1,
+                length: 0,
+                ecu_id: None,
+                session_id: None,
+                timestamp: None,
+                extended_header: None,
+            };
+            header.length = header.header_len() + 4;
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.extend_from_slice(&[0, 0, 0, 1]);
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            let fields = slice.to_fields().unwrap();
+            assert_eq!(1, fields.len());
+            assert_eq!(Some(&FieldValue::UInt(1)), fields.get("message_counter"));
+        }
+    }
+
     #[test]
     fn from_slice_header_len_eof_errors() {
         use error::{PacketSliceError::*, *};
@@ -744,6 +2105,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_slice_strict() {
+        use error::{PacketSliceError::*, *};
+
+        let header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        let to_bytes = |header: &DltHeader, payload: &[u8]| {
+            let mut header = header.clone();
+            header.length = header.header_len() + payload.len() as u16;
+            let mut bytes = Vec::from(&header.to_bytes()[..]);
+            bytes.extend_from_slice(payload);
+            bytes
+        };
+
+        // ok (non verbose with message id)
+        {
+            let bytes = to_bytes(&header, &[1, 2, 3, 4]);
+            assert_eq!(
+                DltPacketSlice::from_slice(&bytes),
+                DltPacketSlice::from_slice_strict(&bytes)
+            );
+        }
+
+        // ok (verbose without payload)
+        {
+            let mut header = header.clone();
+            header.extended_header = Some(DltExtendedHeader::new_non_verbose_log(
+                DltLogLevel::Info,
+                *b"APP\0",
+                *b"CTX\0",
+            ));
+            header
+                .extended_header
+                .as_mut()
+                .unwrap()
+                .set_is_verbose(true);
+            let bytes = to_bytes(&header, &[]);
+            assert!(DltPacketSlice::from_slice_strict(&bytes).is_ok());
+        }
+
+        // errors of from_slice
+        assert_eq!(
+            DltPacketSlice::from_slice(&[1, 2, 3]),
+            DltPacketSlice::from_slice_strict(&[1, 2, 3])
+        );
+
+        // version 0
+        {
+            let mut bytes = to_bytes(&header, &[1, 2, 3, 4]);
+            bytes[0] &= 0b0001_1111;
+            assert!(DltPacketSlice::from_slice(&bytes).is_ok());
+            assert_eq!(
+                Err(UnsupportedDltVersion(UnsupportedDltVersionError {
+                    unsupported_version: 0
+                })),
+                DltPacketSlice::from_slice_strict(&bytes)
+            );
+        }
+
+        // non verbose message without message id
+        for payload_len in 0..4 {
+            let bytes = to_bytes(&header, &[1, 2, 3, 4][..payload_len]);
+            assert!(DltPacketSlice::from_slice(&bytes).is_ok());
+            assert_eq!(
+                Err(MissingMessageId(DltMessageLengthTooSmallError {
+                    required_length: 8,
+                    actual_length: 4 + payload_len,
+                })),
+                DltPacketSlice::from_slice_strict(&bytes)
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice_version_errors(
@@ -791,6 +2232,68 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn peek_len(
+            ref packet in dlt_header_with_payload_any(),
+            unsupported_version in (0u8..0b111u8).prop_filter(
+                "version must be unknown",
+                |v| !DltHeader::SUPPORTED_DECODABLE_VERSIONS.iter().any(|&x| v == &x)
+            )
+        ) {
+            use error::PacketSliceError::*;
+
+            let header_bytes = packet.0.to_bytes();
+
+            // not enough data for the base header
+            for len in 0..4 {
+                assert_eq!(Ok(None), DltPacketSlice::peek_len(&header_bytes[..len]));
+            }
+
+            // only the header present (payload still missing)
+            for len in 4..=header_bytes.len() {
+                assert_eq!(
+                    Ok(Some(packet.0.length)),
+                    DltPacketSlice::peek_len(&header_bytes[..len])
+                );
+            }
+
+            // complete packet
+            {
+                let mut buffer = Vec::with_capacity(usize::from(packet.0.length));
+                buffer.extend_from_slice(&header_bytes);
+                buffer.extend_from_slice(&packet.1);
+                assert_eq!(
+                    Ok(Some(packet.0.length)),
+                    DltPacketSlice::peek_len(&buffer)
+                );
+            }
+
+            // unsupported version
+            {
+                let mut bytes = header_bytes.clone();
+                bytes[0] = (bytes[0] & 0b0001_1111) | ((unsupported_version << 5) & 0b1110_0000);
+                assert_eq!(
+                    Err(UnsupportedDltVersion(error::UnsupportedDltVersionError{ unsupported_version })),
+                    DltPacketSlice::peek_len(&bytes)
+                );
+            }
+
+            // length smaller then the header
+            {
+                let mut header = packet.0.clone();
+                header.length = header.header_len() - 1;
+                assert_eq!(
+                    Err(MessageLengthTooSmall(error::DltMessageLengthTooSmallError{
+                        required_length: header.header_len().into(),
+                        actual_length: header.header_len() as usize - 1usize,
+                    })),
+                    DltPacketSlice::peek_len(&header.to_bytes()[..4])
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn payload_methods(
@@ -4,18 +4,97 @@ use super::*;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SliceIterator<'a> {
     slice: &'a [u8],
+    remaining: &'a [u8],
+    max_len: u16,
+    strict_version: bool,
 }
 
 impl<'a> SliceIterator<'a> {
     #[inline]
     pub fn new(slice: &'a [u8]) -> SliceIterator<'a> {
-        SliceIterator { slice }
+        SliceIterator {
+            slice,
+            remaining: slice,
+            max_len: u16::MAX,
+            strict_version: false,
+        }
+    }
+
+    /// Sets the maximum accepted message length (value of the `length`
+    /// field in the dlt header).
+    ///
+    /// Messages with a larger length are not sliced & instead a
+    /// [`error::PacketSliceError::MessageLengthTooLarge`] error is
+    /// returned. As with all other errors the iteration ends afterwards &
+    /// [`SliceIterator::remaining`] starts with the rejected message, so
+    /// the data can be resynchronized by the caller. This prevents a
+    /// corrupt length field from consuming the rest of the buffer as a
+    /// single message.
+    #[inline]
+    pub fn with_max_len(mut self, max_len: u16) -> SliceIterator<'a> {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Only accepts messages with the header version 1.
+    ///
+    /// By default messages with the version 0 are also sliced (see
+    /// [`DltPacketSlice::from_slice`]). With the strict version check
+    /// enabled all messages with a version other than 1 are rejected with
+    /// an [`error::PacketSliceError::UnsupportedDltVersion`] error (the
+    /// iteration ends afterwards like for all other errors).
+    #[inline]
+    pub fn with_strict_version(mut self) -> SliceIterator<'a> {
+        self.strict_version = true;
+        self
     }
 
     /// Returns the slice of data still left in the iterator.
     pub fn slice(&self) -> &'a [u8] {
         self.slice
     }
+
+    /// Returns the data that was not consumed by successfully decoded
+    /// packets.
+    ///
+    /// In contrast to [`SliceIterator::slice`] the data is kept after an
+    /// error was encountered (starting with the malformed packet). This
+    /// allows resynchronizing a stream after the iteration ended.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.remaining
+    }
+
+    /// Returns an iterator that additionally yields the byte offset of the
+    /// start of each message.
+    ///
+    /// The offsets are relative to the start of the data still left in the
+    /// iterator (the original buffer if called on a newly created iterator).
+    pub fn with_offsets(
+        self,
+    ) -> impl Iterator<Item = (usize, Result<DltPacketSlice<'a>, error::PacketSliceError>)> + 'a
+    {
+        let total_len = self.slice.len();
+        let mut iter = self;
+        core::iter::from_fn(move || {
+            let offset = total_len - iter.slice.len();
+            iter.next().map(|result| (offset, result))
+        })
+    }
+
+    /// Collects up to `max` packets as owned [`DltPacket`]s.
+    ///
+    /// The collection stops as soon as `max` packets were collected,
+    /// the end of the data is reached or an error is encountered (in
+    /// which case the error is returned).
+    #[cfg(feature = "alloc")]
+    pub fn collect_owned(
+        self,
+        max: usize,
+    ) -> Result<alloc::vec::Vec<DltPacket>, error::PacketSliceError> {
+        self.take(max)
+            .map(|packet| packet.map(|p| DltPacket::from(&p)))
+            .collect()
+    }
 }
 
 impl<'a> Iterator for SliceIterator<'a> {
@@ -25,7 +104,31 @@ impl<'a> Iterator for SliceIterator<'a> {
     fn next(&mut self) -> Option<Result<DltPacketSlice<'a>, error::PacketSliceError>> {
         if !self.slice.is_empty() {
             //parse
-            let result = DltPacketSlice::from_slice(self.slice);
+            let length = match self.slice {
+                [_, _, l0, l1, ..] => u16::from_be_bytes([*l0, *l1]),
+                _ => 0,
+            };
+            let result = if length > self.max_len {
+                Err(error::PacketSliceError::MessageLengthTooLarge(
+                    error::DltMessageLengthTooLargeError {
+                        max_length: usize::from(self.max_len),
+                        actual_length: usize::from(length),
+                    },
+                ))
+            } else {
+                DltPacketSlice::from_slice(self.slice).and_then(|packet| {
+                    let version = packet.header_version();
+                    if self.strict_version && 1 != version {
+                        Err(error::PacketSliceError::UnsupportedDltVersion(
+                            error::UnsupportedDltVersionError {
+                                unsupported_version: version,
+                            },
+                        ))
+                    } else {
+                        Ok(packet)
+                    }
+                })
+            };
 
             //move the slice depending on the result
             match &result {
@@ -37,6 +140,7 @@ impl<'a> Iterator for SliceIterator<'a> {
                 Ok(ref value) => {
                     //by the length just taken by the slice
                     self.slice = &self.slice[value.slice().len()..];
+                    self.remaining = self.slice;
                 }
             }
 
@@ -48,6 +152,10 @@ impl<'a> Iterator for SliceIterator<'a> {
     }
 }
 
+// The iterator is fused as after an error or the end of the data is
+// reached the slice is empty & `None` is returned for all following calls.
+impl<'a> core::iter::FusedIterator for SliceIterator<'a> {}
+
 /// Tests for `SliceIterator`
 #[cfg(test)]
 mod slice_interator_tests {
@@ -58,15 +166,18 @@ mod slice_interator_tests {
 
     #[test]
     fn clone_eq() {
-        let it = SliceIterator { slice: &[] };
+        let it = SliceIterator::new(&[]);
         assert_eq!(it, it.clone());
     }
 
     #[test]
     fn debug() {
-        let it = SliceIterator { slice: &[] };
+        let it = SliceIterator::new(&[]);
         assert_eq!(
-            format!("SliceIterator {{ slice: {:?} }}", it.slice),
+            format!(
+                "SliceIterator {{ slice: {:?}, remaining: {:?}, max_len: {:?}, strict_version: {:?} }}",
+                it.slice, it.remaining, it.max_len, it.strict_version
+            ),
             format!("{:?}", it)
         );
     }
@@ -74,10 +185,265 @@ mod slice_interator_tests {
     #[test]
     fn slice() {
         let buffer: [u8; 4] = [1, 2, 3, 4];
-        let it = SliceIterator { slice: &buffer };
+        let it = SliceIterator::new(&buffer);
         assert_eq!(it.slice(), &buffer);
     }
 
+    #[test]
+    fn remaining() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut buffer = Vec::with_capacity(usize::from(header.length) * 2);
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        let first_len = buffer.len();
+        // second message is truncated
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2]);
+
+        let mut it = SliceIterator::new(&buffer);
+        assert_eq!(it.remaining(), &buffer[..]);
+
+        assert!(it.next().unwrap().is_ok());
+        assert_eq!(it.remaining(), &buffer[first_len..]);
+        assert_eq!(it.slice(), &buffer[first_len..]);
+
+        assert_matches!(
+            it.next(),
+            Some(Err(error::PacketSliceError::UnexpectedEndOfSlice(_)))
+        );
+        assert!(it.slice().is_empty());
+        assert_eq!(it.remaining(), &buffer[first_len..]);
+
+        // stays the same after the end
+        assert_eq!(None, it.next());
+        assert_eq!(it.remaining(), &buffer[first_len..]);
+
+        // nothing remaining after successfully decoding all packets
+        let mut it = SliceIterator::new(&buffer[..first_len]);
+        assert!(it.next().unwrap().is_ok());
+        assert_eq!(None, it.next());
+        assert!(it.remaining().is_empty());
+    }
+
+    #[test]
+    fn with_offsets() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut buffer = Vec::with_capacity(usize::from(header.length) * 3);
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        let first_len = buffer.len();
+        header.length += 2;
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+        let second_end = buffer.len();
+        // third message is truncated
+        buffer.extend_from_slice(&header.to_bytes());
+
+        let mut it = SliceIterator::new(&buffer).with_offsets();
+        {
+            let (offset, packet) = it.next().unwrap();
+            assert_eq!(0, offset);
+            assert_eq!(&buffer[..first_len], packet.unwrap().slice());
+        }
+        {
+            let (offset, packet) = it.next().unwrap();
+            assert_eq!(first_len, offset);
+            assert_eq!(&buffer[first_len..second_end], packet.unwrap().slice());
+        }
+        {
+            let (offset, packet) = it.next().unwrap();
+            assert_eq!(second_end, offset);
+            assert_matches!(
+                packet,
+                Err(error::PacketSliceError::UnexpectedEndOfSlice(_))
+            );
+        }
+        assert!(it.next().is_none());
+
+        // offsets relative to the data left in the iterator
+        let mut it = SliceIterator::new(&buffer);
+        assert!(it.next().unwrap().is_ok());
+        assert_eq!(0, it.with_offsets().next().unwrap().0);
+    }
+
+    #[test]
+    fn with_max_len() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        let first_len = buffer.len();
+        header.length += 4;
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        // all messages within the limit
+        {
+            let it = SliceIterator::new(&buffer).with_max_len(header.length);
+            assert_eq!(2, it.filter(|p| p.is_ok()).count());
+        }
+
+        // second message exceeds the limit
+        {
+            let max_len = header.length - 1;
+            let mut it = SliceIterator::new(&buffer).with_max_len(max_len);
+            assert!(it.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(error::PacketSliceError::MessageLengthTooLarge(
+                    error::DltMessageLengthTooLargeError {
+                        max_length: usize::from(max_len),
+                        actual_length: usize::from(header.length),
+                    }
+                ))),
+                it.next()
+            );
+            assert_eq!(None, it.next());
+            assert_eq!(&buffer[first_len..], it.remaining());
+        }
+
+        // length field is checked before the data length
+        {
+            let buffer = [0, 0, 0xff, 0xff, 1, 2, 3, 4];
+            let mut it = SliceIterator::new(&buffer).with_max_len(100);
+            assert_matches!(
+                it.next(),
+                Some(Err(error::PacketSliceError::MessageLengthTooLarge(_)))
+            );
+        }
+
+        // partial base header is still reported as end of slice
+        {
+            let mut it = SliceIterator::new(&[0, 0, 0xff]).with_max_len(100);
+            assert_matches!(
+                it.next(),
+                Some(Err(error::PacketSliceError::UnexpectedEndOfSlice(_)))
+            );
+        }
+    }
+
+    #[test]
+    fn with_strict_version() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&header.to_bytes());
+        packet.extend_from_slice(&[1, 2, 3, 4]);
+
+        // packet with the given header version
+        let with_version = |version: u8| {
+            let mut result = packet.clone();
+            result[0] = (result[0] & 0b0001_1111) | (version << 5);
+            result
+        };
+
+        // version 1 is accepted
+        {
+            let mut buffer = with_version(1);
+            buffer.extend_from_slice(&with_version(1));
+            let it = SliceIterator::new(&buffer).with_strict_version();
+            assert_eq!(2, it.filter(|p| p.is_ok()).count());
+        }
+
+        // all other versions are rejected
+        for version in (0..=MAX_VERSION).filter(|v| 1 != *v) {
+            let mut buffer = with_version(1);
+            buffer.extend_from_slice(&with_version(version));
+            buffer.extend_from_slice(&with_version(1));
+
+            let mut it = SliceIterator::new(&buffer).with_strict_version();
+            assert!(it.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(error::PacketSliceError::UnsupportedDltVersion(
+                    error::UnsupportedDltVersionError {
+                        unsupported_version: version,
+                    }
+                ))),
+                it.next()
+            );
+            assert_eq!(None, it.next());
+            assert_eq!(&buffer[packet.len()..], it.remaining());
+        }
+
+        // version 0 is accepted without the strict check
+        {
+            let buffer = with_version(0);
+            let mut it = SliceIterator::new(&buffer);
+            assert_eq!(0, it.next().unwrap().unwrap().header_version());
+        }
+    }
+
+    #[test]
+    fn fused() {
+        // after end
+        {
+            let mut header: DltHeader = Default::default();
+            header.length = header.header_len() + 4;
+            let mut buffer = Vec::with_capacity(usize::from(header.length));
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&[1, 2, 3, 4]);
+
+            let mut it = SliceIterator::new(&buffer);
+            assert!(it.next().unwrap().is_ok());
+            for _ in 0..3 {
+                assert_eq!(None, it.next());
+            }
+        }
+        // after error
+        {
+            // length field is too big for the given data
+            let buffer = [0, 0, 0, 10, 1, 2, 3, 4];
+            let mut it = SliceIterator::new(&buffer);
+            assert_matches!(
+                it.next(),
+                Some(Err(error::PacketSliceError::UnexpectedEndOfSlice(_)))
+            );
+            assert!(it.slice().is_empty());
+            for _ in 0..3 {
+                assert_eq!(None, it.next());
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_owned() {
+        let mut header: DltHeader = Default::default();
+        header.length = header.header_len() + 4;
+        let mut buffer = Vec::new();
+        for i in 0..3u8 {
+            header.message_counter = i;
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&[i, 2, 3, 4]);
+        }
+
+        // limit
+        for max in 0..5 {
+            let packets = SliceIterator::new(&buffer).collect_owned(max).unwrap();
+            assert_eq!(core::cmp::min(max, 3), packets.len());
+            for (i, packet) in packets.iter().enumerate() {
+                assert_eq!(i as u8, packet.header.message_counter);
+                assert_eq!(&[i as u8, 2, 3, 4], &packet.payload[..]);
+            }
+        }
+
+        // error in the middle of the stream
+        assert_matches!(
+            SliceIterator::new(&buffer[..buffer.len() - 1]).collect_owned(5),
+            Err(error::PacketSliceError::UnexpectedEndOfSlice(_))
+        );
+        // errors after the limit are not encountered
+        assert_eq!(
+            2,
+            SliceIterator::new(&buffer[..buffer.len() - 1])
+                .collect_owned(2)
+                .unwrap()
+                .len()
+        );
+    }
+
     proptest! {
         #[test]
         fn iterator(ref packets in prop::collection::vec(dlt_header_with_payload_any(), 1..5)) {
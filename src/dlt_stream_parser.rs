@@ -0,0 +1,310 @@
+use super::*;
+use crate::error::StreamParseError;
+
+/// Reassembles DLT packets from a stream of data that is received in
+/// chunks with arbitrary boundaries (e.g. DLT via TCP).
+///
+/// The data is buffered in an internal [`ArrayVec`] with a fixed capacity
+/// of `CAP` bytes (no allocations needed). `CAP` has to be big enough to
+/// hold the biggest expected message, messages that don't fit are
+/// skipped & reported via [`StreamParseError::MessageTooBig`].
+///
+/// `CAP` has to be at least 4 (the size of the base header containing the
+/// message length), otherwise the parser could never make progress. This is
+/// checked at compile time:
+///
+/// ```compile_fail
+/// use dlt_parse::DltStreamParser;
+///
+/// let parser = DltStreamParser::<3>::new();
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::DltStreamParser;
+///
+/// let mut parser = DltStreamParser::<0x1_0000>::new();
+/// # let chunks: [&[u8]; 0] = [];
+/// for chunk in chunks {
+///     let mut rest = chunk;
+///     while false == rest.is_empty() {
+///         // push as much data as fits into the buffer
+///         let pushed = parser.push_bytes(rest);
+///         rest = &rest[pushed..];
+///
+///         // process the complete packets
+///         while let Some(packet) = parser.next_packet() {
+///             match packet {
+///                 Ok(packet) => println!("{:?}", packet.header()),
+///                 Err(err) => println!("Error: {}", err),
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DltStreamParser<const CAP: usize> {
+    /// Buffered stream data.
+    buffer: ArrayVec<u8, CAP>,
+    /// Number of bytes at the start of the buffer that were already
+    /// returned as packets (removed on the next push).
+    consumed: usize,
+    /// Number of bytes that still have to be skipped from the stream
+    /// (remaining part of a message that did not fit into the buffer).
+    skip_len: usize,
+}
+
+impl<const CAP: usize> DltStreamParser<CAP> {
+    /// Creates a parser with an empty buffer.
+    #[inline]
+    pub fn new() -> DltStreamParser<CAP> {
+        const {
+            assert!(
+                CAP >= 4,
+                "DltStreamParser capacity must be at least 4 bytes (size of the DLT base header)"
+            )
+        };
+        DltStreamParser {
+            buffer: ArrayVec::new(),
+            consumed: 0,
+            skip_len: 0,
+        }
+    }
+
+    /// Returns the buffered data that was not yet returned as a packet.
+    #[inline]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[self.consumed..]
+    }
+
+    /// Adds stream data to the internal buffer.
+    ///
+    /// Data of already returned packets is removed from the buffer before
+    /// the new data is added. Returns the number of bytes taken from `data`,
+    /// which can be less than `data.len()` if the buffer is full. In this
+    /// case the remaining data should be pushed again after the packets
+    /// were processed via [`DltStreamParser::next_packet`].
+    pub fn push_bytes(&mut self, data: &[u8]) -> usize {
+        // remove the data of the already returned packets
+        if self.consumed > 0 {
+            self.buffer.drain(..self.consumed);
+            self.consumed = 0;
+        }
+
+        // skip the rest of a message that did not fit into the buffer
+        let skipped = core::cmp::min(self.skip_len, data.len());
+        self.skip_len -= skipped;
+        let data = &data[skipped..];
+
+        let len = core::cmp::min(self.buffer.remaining_capacity(), data.len());
+        // the capacity is checked above so no error can be returned
+        let _ = self.buffer.try_extend_from_slice(&data[..len]);
+        skipped + len
+    }
+
+    /// Returns the next complete packet in the buffer or `None` if more
+    /// data is needed.
+    ///
+    /// If a message is bigger than `CAP` a [`StreamParseError::MessageTooBig`]
+    /// error is returned and the message gets skipped. If the base header
+    /// at the start of the buffered data is malformed a
+    /// [`StreamParseError::Packet`] error is returned and all buffered data
+    /// is discarded (as the start of the next message is unknown).
+    pub fn next_packet(&mut self) -> Option<Result<DltPacketSlice<'_>, StreamParseError>> {
+        let data = &self.buffer[self.consumed..];
+        let len = match DltPacketSlice::peek_len(data) {
+            Ok(Some(len)) => usize::from(len),
+            Ok(None) => return None,
+            Err(err) => {
+                self.buffer.clear();
+                self.consumed = 0;
+                return Some(Err(err.into()));
+            }
+        };
+
+        if len > CAP {
+            // skip the message
+            self.skip_len = len - data.len();
+            self.buffer.clear();
+            self.consumed = 0;
+            return Some(Err(StreamParseError::MessageTooBig {
+                message_len: len,
+                capacity: CAP,
+            }));
+        }
+
+        if data.len() < len {
+            return None;
+        }
+
+        let start = self.consumed;
+        self.consumed += len;
+        Some(DltPacketSlice::from_slice(&self.buffer[start..start + len]).map_err(|err| err.into()))
+    }
+}
+
+impl<const CAP: usize> Default for DltStreamParser<CAP> {
+    #[inline]
+    fn default() -> DltStreamParser<CAP> {
+        DltStreamParser::new()
+    }
+}
+
+#[cfg(test)]
+mod dlt_stream_parser_tests {
+    use super::*;
+    use crate::error::{PacketSliceError, UnsupportedDltVersionError};
+    use crate::proptest_generators::*;
+    use proptest::prelude::*;
+
+    fn serialize(packet: &(DltHeader, Vec<u8>)) -> Vec<u8> {
+        let mut result = Vec::with_capacity(usize::from(packet.0.length));
+        result.extend_from_slice(&packet.0.to_bytes());
+        result.extend_from_slice(&packet.1);
+        result
+    }
+
+    /// Pushes the data in chunks of the given size to the parser and
+    /// collects all returned results.
+    fn parse_chunked<const CAP: usize>(
+        parser: &mut DltStreamParser<CAP>,
+        data: &[u8],
+        chunk_size: usize,
+    ) -> Vec<Result<Vec<u8>, StreamParseError>> {
+        let mut result = Vec::new();
+        for chunk in data.chunks(chunk_size) {
+            let mut rest = chunk;
+            while false == rest.is_empty() {
+                let pushed = parser.push_bytes(rest);
+                rest = &rest[pushed..];
+                while let Some(packet) = parser.next_packet() {
+                    result.push(packet.map(|p| p.slice().to_vec()));
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn new_default() {
+        let parser = DltStreamParser::<16>::new();
+        assert_eq!(parser, DltStreamParser::<16>::default());
+        assert!(parser.buffered().is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn chunked(
+            ref packets in proptest::collection::vec(dlt_header_with_payload_any(), 1..4),
+            chunk_size in 1usize..64
+        ) {
+            let packets: Vec<Vec<u8>> = packets.iter().map(serialize).collect();
+            let data: Vec<u8> = packets.iter().flatten().copied().collect();
+
+            // packets are reassembled independent of the chunk size
+            let mut parser = DltStreamParser::<0x1_0000>::new();
+            let result = parse_chunked(&mut parser, &data, chunk_size);
+            prop_assert_eq!(
+                packets.iter().map(|p| Ok(p.clone())).collect::<Vec<_>>(),
+                result
+            );
+            prop_assert!(parser.buffered().is_empty());
+
+            // incomplete packet stays buffered
+            let mut parser = DltStreamParser::<0x1_0000>::new();
+            let result = parse_chunked(&mut parser, &data[..data.len() - 1], chunk_size);
+            prop_assert_eq!(packets.len() - 1, result.len());
+            prop_assert_eq!(
+                &packets[packets.len() - 1][..packets[packets.len() - 1].len() - 1],
+                parser.buffered()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn message_too_big(
+            ref packet in dlt_header_with_payload_any(),
+            chunk_size in 1usize..64
+        ) {
+            // message bigger then the capacity is skipped & following
+            // packets are still returned
+            let big = {
+                let mut header = packet.0.clone();
+                let payload = vec![0u8; 100];
+                header.length = header.header_len() + 100;
+                serialize(&(header, payload))
+            };
+            let small = {
+                let mut header = packet.0.clone();
+                header.length = header.header_len();
+                serialize(&(header, Vec::new()))
+            };
+            let mut data = big.clone();
+            data.extend_from_slice(&small);
+            data.extend_from_slice(&big);
+            data.extend_from_slice(&small);
+
+            let mut parser = DltStreamParser::<64>::new();
+            let result = parse_chunked(&mut parser, &data, chunk_size);
+            let too_big = Err(StreamParseError::MessageTooBig {
+                message_len: big.len(),
+                capacity: 64,
+            });
+            prop_assert_eq!(
+                vec![too_big.clone(), Ok(small.clone()), too_big, Ok(small.clone())],
+                result
+            );
+            prop_assert!(parser.buffered().is_empty());
+        }
+    }
+
+    #[test]
+    fn malformed_header() {
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        header.length = header.header_len();
+        let valid = header.to_bytes();
+
+        // unsupported version discards the buffered data
+        let mut data = valid.to_vec();
+        data[0] |= 0b1110_0000;
+        data.extend_from_slice(&valid);
+
+        let mut parser = DltStreamParser::<64>::new();
+        assert_eq!(data.len(), parser.push_bytes(&data));
+        assert_eq!(
+            Some(Err(StreamParseError::Packet(
+                PacketSliceError::UnsupportedDltVersion(UnsupportedDltVersionError {
+                    unsupported_version: 7,
+                })
+            ))),
+            parser.next_packet().map(|v| v.map(|p| p.slice().to_vec()))
+        );
+        assert!(parser.buffered().is_empty());
+        assert!(parser.next_packet().is_none());
+
+        // parser continues with newly pushed data
+        assert_eq!(valid.len(), parser.push_bytes(&valid));
+        assert_eq!(
+            Some(Ok(valid.to_vec())),
+            parser.next_packet().map(|v| v.map(|p| p.slice().to_vec()))
+        );
+    }
+
+    #[test]
+    fn push_bytes_capacity() {
+        let mut parser = DltStreamParser::<8>::new();
+        assert_eq!(8, parser.push_bytes(&[0; 10]));
+        assert_eq!(&[0; 8], parser.buffered());
+        assert_eq!(0, parser.push_bytes(&[0; 2]));
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+/// Errors that can occur when decoding a CAN frame from the payload of
+/// a network trace message (see [`crate::nw_trace::CanFrame`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CanFrameError {
+    /// Error if the payload is too small to contain the CAN id & DLC.
+    UnexpectedEndOfSlice(UnexpectedEndOfSliceError),
+
+    /// Error if the length of the frame data does not match the length
+    /// defined by the DLC (or the DLC is bigger then 15).
+    DataLenMismatch {
+        /// Data length code of the frame.
+        dlc: u8,
+        /// Length of the frame data.
+        actual_len: usize,
+    },
+}
+
+impl core::fmt::Display for CanFrameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use CanFrameError::*;
+        match self {
+            UnexpectedEndOfSlice(v) => v.fmt(f),
+            DataLenMismatch { dlc, actual_len } => write!(
+                f,
+                "DLT CAN Frame: Frame data has a length of {actual_len} bytes which does not match the DLC {dlc}."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for CanFrameError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        use CanFrameError::*;
+        match self {
+            UnexpectedEndOfSlice(v) => Some(v),
+            DataLenMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<UnexpectedEndOfSliceError> for CanFrameError {
+    fn from(value: UnexpectedEndOfSliceError) -> Self {
+        CanFrameError::UnexpectedEndOfSlice(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use CanFrameError::*;
+        let v = DataLenMismatch {
+            dlc: 1,
+            actual_len: 2,
+        };
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        use CanFrameError::*;
+        assert_eq!(
+            "DataLenMismatch { dlc: 1, actual_len: 2 }",
+            format!(
+                "{:?}",
+                DataLenMismatch {
+                    dlc: 1,
+                    actual_len: 2
+                }
+            ),
+        );
+    }
+
+    #[test]
+    fn display() {
+        use CanFrameError::*;
+        {
+            let inner = UnexpectedEndOfSliceError {
+                layer: Layer::CanFrame,
+                minimum_size: 5,
+                actual_size: 1,
+            };
+            assert_eq!(
+                format!("{}", inner),
+                format!("{}", UnexpectedEndOfSlice(inner.clone())),
+            );
+        }
+        assert_eq!(
+            "DLT CAN Frame: Frame data has a length of 2 bytes which does not match the DLC 1.",
+            format!(
+                "{}",
+                DataLenMismatch {
+                    dlc: 1,
+                    actual_len: 2
+                }
+            ),
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        use CanFrameError::*;
+        assert!(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+            layer: Layer::CanFrame,
+            minimum_size: 5,
+            actual_size: 1,
+        })
+        .source()
+        .is_some());
+        assert!(DataLenMismatch {
+            dlc: 1,
+            actual_len: 2
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn from() {
+        let inner = UnexpectedEndOfSliceError {
+            layer: Layer::CanFrame,
+            minimum_size: 5,
+            actual_size: 1,
+        };
+        assert_eq!(
+            CanFrameError::UnexpectedEndOfSlice(inner.clone()),
+            inner.into()
+        );
+    }
+}
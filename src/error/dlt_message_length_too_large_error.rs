@@ -0,0 +1,75 @@
+/// Error if the length field in a DLT header is larger then the maximum
+/// message length configured for the decoder (e.g. via
+/// [`crate::SliceIterator::with_max_len`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DltMessageLengthTooLargeError {
+    pub max_length: usize,
+    pub actual_length: usize,
+}
+
+impl core::fmt::Display for DltMessageLengthTooLargeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DLT Header Error: The message length of {} present in the dlt header is larger then the allowed maximum of {} bytes.",
+            self.actual_length,
+            self.max_length
+        )
+    }
+}
+
+impl core::error::Error for DltMessageLengthTooLargeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        let v = DltMessageLengthTooLargeError {
+            max_length: 1,
+            actual_length: 2,
+        };
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        let v = DltMessageLengthTooLargeError {
+            max_length: 1,
+            actual_length: 2,
+        };
+        assert_eq!(
+            "DltMessageLengthTooLargeError { max_length: 1, actual_length: 2 }",
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let v = DltMessageLengthTooLargeError {
+            max_length: 1,
+            actual_length: 2,
+        };
+        assert_eq!(
+            "DLT Header Error: The message length of 2 present in the dlt header is larger then the allowed maximum of 1 bytes.",
+            format!("{}", v)
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        assert!(DltMessageLengthTooLargeError {
+            max_length: 1,
+            actual_length: 2,
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -17,9 +17,8 @@ impl core::fmt::Display for DltMessageLengthTooSmallError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for DltMessageLengthTooSmallError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for DltMessageLengthTooSmallError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -69,10 +68,9 @@ mod dlt_message_length_too_small_error_test {
         );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         assert!(DltMessageLengthTooSmallError {
             required_length: 1,
             actual_length: 2,
@@ -0,0 +1,113 @@
+use arrayvec::CapacityError;
+
+/// Error that can occur when encoding DLT messages (e.g. via the
+/// [`crate::VerboseMessageBuilder`] or [`crate::DltMessageBuilder`]).
+///
+/// [`arrayvec::CapacityError`]s returned by the `add_to_msg` methods of
+/// the verbose values can be converted via `?` into an `EncodeError`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// Error if the encoded message does not fit into the target buffer
+    /// or contains more then 255 verbose arguments.
+    CapacityExceeded,
+
+    /// Error if the encoded message exceeds the maximum DLT message
+    /// length of 65535 bytes (can not be represented in the length
+    /// field of the header).
+    MessageTooLong {
+        /// Length of the complete encoded message in bytes.
+        len: usize,
+    },
+}
+
+impl From<CapacityError> for EncodeError {
+    fn from(_: CapacityError) -> Self {
+        EncodeError::CapacityExceeded
+    }
+}
+
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use EncodeError::*;
+        match self {
+            CapacityExceeded => write!(
+                f,
+                "Not enough capacity to encode the DLT message (buffer too small or more than 255 arguments)."
+            ),
+            MessageTooLong { len } => write!(
+                f,
+                "Encoded DLT message has a length of {len} bytes, which exceeds the maximum DLT message length of 65535 bytes."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        let v = EncodeError::CapacityExceeded;
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "CapacityExceeded",
+            format!("{:?}", EncodeError::CapacityExceeded)
+        );
+        assert_eq!(
+            "MessageTooLong { len: 65536 }",
+            format!("{:?}", EncodeError::MessageTooLong { len: 65536 })
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "Not enough capacity to encode the DLT message (buffer too small or more than 255 arguments).",
+            format!("{}", EncodeError::CapacityExceeded)
+        );
+        assert_eq!(
+            "Encoded DLT message has a length of 65536 bytes, which exceeds the maximum DLT message length of 65535 bytes.",
+            format!("{}", EncodeError::MessageTooLong { len: 65536 })
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        assert!(EncodeError::CapacityExceeded.source().is_none());
+        assert!(EncodeError::MessageTooLong { len: 65536 }
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn from_capacity_error() {
+        fn encode() -> Result<(), EncodeError> {
+            let mut buf = arrayvec::ArrayVec::<u8, 4>::new();
+            crate::verbose::U32Value {
+                variable_info: None,
+                scaling: None,
+                value: 1,
+            }
+            .add_to_msg(&mut buf, true)?;
+            Ok(())
+        }
+        assert_eq!(Err(EncodeError::CapacityExceeded), encode());
+        assert_eq!(
+            EncodeError::CapacityExceeded,
+            EncodeError::from(CapacityError::new(()))
+        );
+    }
+}
@@ -7,6 +7,12 @@ pub enum Layer {
     VerboseTypeInfo,
     /// Error occured while parsing or writing a verbose value.
     VerboseValue,
+    /// Error occured while parsing the payload of a control message.
+    ControlPayload,
+    /// Error occured while parsing a storage header.
+    StorageHeader,
+    /// Error occured while parsing a CAN frame of a network trace message.
+    CanFrame,
 }
 
 #[cfg(test)]
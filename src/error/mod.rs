@@ -1,12 +1,27 @@
+mod can_frame_error;
+pub use can_frame_error::*;
+
+mod dlt_message_length_too_large_error;
+pub use dlt_message_length_too_large_error::*;
+
 mod dlt_message_length_too_small_error;
 pub use dlt_message_length_too_small_error::*;
 
+mod encode_error;
+pub use encode_error::*;
+
 mod layer;
 pub use layer::*;
 
+mod nw_trace_reassembly_error;
+pub use nw_trace_reassembly_error::*;
+
 mod packet_slice_error;
 pub use packet_slice_error::*;
 
+mod parse_log_level_error;
+pub use parse_log_level_error::*;
+
 mod range_error;
 pub use range_error::*;
 
@@ -16,6 +31,12 @@ pub use read_error::*;
 mod storage_header_start_pattern_error;
 pub use storage_header_start_pattern_error::*;
 
+mod storage_slice_error;
+pub use storage_slice_error::*;
+
+mod stream_parse_error;
+pub use stream_parse_error::*;
+
 mod typed_payload_error;
 pub use typed_payload_error::*;
 
@@ -25,5 +46,8 @@ pub use unexpected_end_of_slice_error::*;
 mod unsupported_dlt_version_error;
 pub use unsupported_dlt_version_error::*;
 
+mod verbose_argument_error;
+pub use verbose_argument_error::*;
+
 mod verbose_decode_error;
 pub use verbose_decode_error::*;
@@ -0,0 +1,256 @@
+use super::*;
+
+/// Errors that can occur when reassembling a segmented network trace
+/// message (see [`crate::nw_trace::reassemble_segmented`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NwTraceReassemblyError {
+    /// Error when decoding the verbose arguments of a segmentation message.
+    Decode(VerboseDecodeError),
+
+    /// Error if the arguments of a segmentation message do not match the
+    /// expected layout (the marker of the message is given as argument).
+    InvalidArguments(&'static str),
+
+    /// Error if the packets ended before a start message was encountered.
+    MissingStart,
+
+    /// Error if the packets ended before the end message was encountered.
+    MissingEnd,
+
+    /// Error if a segment was received out of order or a segment is missing.
+    UnexpectedSequenceNumber {
+        /// Expected sequence number.
+        expected: u16,
+        /// Sequence number of the received segment.
+        actual: u16,
+    },
+
+    /// Error if the length of the reassembled payload does not match the
+    /// payload length announced in the start message.
+    PayloadLenMismatch {
+        /// Payload length announced in the start message.
+        expected: u64,
+        /// Length of the reassembled payload.
+        actual: usize,
+    },
+
+    /// Error if the number of received segments does not match the
+    /// segment count announced in the start message.
+    SegmentCountMismatch {
+        /// Segment count announced in the start message.
+        expected: u64,
+        /// Number of received segments.
+        actual: usize,
+    },
+
+    /// Error if the length of a segment does not match the segment
+    /// length announced in the start message (only the last segment is
+    /// allowed to be shorter).
+    SegmentLenMismatch {
+        /// Sequence number of the segment.
+        sequence: u16,
+        /// Segment length announced in the start message.
+        expected: u64,
+        /// Length of the received segment.
+        actual: usize,
+    },
+}
+
+impl core::fmt::Display for NwTraceReassemblyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use NwTraceReassemblyError::*;
+        match self {
+            Decode(v) => v.fmt(f),
+            InvalidArguments(marker) => write!(
+                f,
+                "DLT Network Trace: Arguments of the '{marker}' message do not match the expected layout."
+            ),
+            MissingStart => write!(
+                f,
+                "DLT Network Trace: Packets ended before the start of the segmented message."
+            ),
+            MissingEnd => write!(
+                f,
+                "DLT Network Trace: Packets ended before the end of the segmented message."
+            ),
+            UnexpectedSequenceNumber { expected, actual } => write!(
+                f,
+                "DLT Network Trace: Expected segment with the sequence number {expected} but received {actual}."
+            ),
+            PayloadLenMismatch { expected, actual } => write!(
+                f,
+                "DLT Network Trace: Reassembled payload has a length of {actual} bytes but {expected} bytes were announced."
+            ),
+            SegmentCountMismatch { expected, actual } => write!(
+                f,
+                "DLT Network Trace: Received {actual} segments but {expected} segments were announced."
+            ),
+            SegmentLenMismatch {
+                sequence,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "DLT Network Trace: Segment with the sequence number {sequence} has a length of {actual} bytes but a segment length of {expected} bytes was announced."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for NwTraceReassemblyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        use NwTraceReassemblyError::*;
+        match self {
+            Decode(v) => Some(v),
+            InvalidArguments(_) => None,
+            MissingStart => None,
+            MissingEnd => None,
+            UnexpectedSequenceNumber { .. } => None,
+            PayloadLenMismatch { .. } => None,
+            SegmentCountMismatch { .. } => None,
+            SegmentLenMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<VerboseDecodeError> for NwTraceReassemblyError {
+    fn from(value: VerboseDecodeError) -> Self {
+        NwTraceReassemblyError::Decode(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use NwTraceReassemblyError::*;
+        let v = UnexpectedSequenceNumber {
+            expected: 1,
+            actual: 2,
+        };
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        use NwTraceReassemblyError::*;
+        assert_eq!(
+            "UnexpectedSequenceNumber { expected: 1, actual: 2 }",
+            format!(
+                "{:?}",
+                UnexpectedSequenceNumber {
+                    expected: 1,
+                    actual: 2
+                }
+            ),
+        );
+    }
+
+    #[test]
+    fn display() {
+        use NwTraceReassemblyError::*;
+        {
+            let inner = VerboseDecodeError::InvalidBoolValue(2);
+            assert_eq!(format!("{}", inner), format!("{}", Decode(inner.clone())));
+        }
+        assert_eq!(
+            "DLT Network Trace: Arguments of the 'NWST' message do not match the expected layout.",
+            format!("{}", InvalidArguments("NWST"))
+        );
+        assert_eq!(
+            "DLT Network Trace: Packets ended before the start of the segmented message.",
+            format!("{}", MissingStart)
+        );
+        assert_eq!(
+            "DLT Network Trace: Packets ended before the end of the segmented message.",
+            format!("{}", MissingEnd)
+        );
+        assert_eq!(
+            "DLT Network Trace: Expected segment with the sequence number 1 but received 2.",
+            format!(
+                "{}",
+                UnexpectedSequenceNumber {
+                    expected: 1,
+                    actual: 2
+                }
+            )
+        );
+        assert_eq!(
+            "DLT Network Trace: Reassembled payload has a length of 3 bytes but 4 bytes were announced.",
+            format!(
+                "{}",
+                PayloadLenMismatch {
+                    expected: 4,
+                    actual: 3
+                }
+            )
+        );
+        assert_eq!(
+            "DLT Network Trace: Received 1 segments but 2 segments were announced.",
+            format!(
+                "{}",
+                SegmentCountMismatch {
+                    expected: 2,
+                    actual: 1
+                }
+            )
+        );
+        assert_eq!(
+            "DLT Network Trace: Segment with the sequence number 3 has a length of 2 bytes but a segment length of 4 bytes was announced.",
+            format!(
+                "{}",
+                SegmentLenMismatch {
+                    sequence: 3,
+                    expected: 4,
+                    actual: 2
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        use NwTraceReassemblyError::*;
+        assert!(Decode(VerboseDecodeError::InvalidBoolValue(2))
+            .source()
+            .is_some());
+        assert!(InvalidArguments("NWST").source().is_none());
+        assert!(MissingStart.source().is_none());
+        assert!(MissingEnd.source().is_none());
+        assert!(UnexpectedSequenceNumber {
+            expected: 1,
+            actual: 2
+        }
+        .source()
+        .is_none());
+        assert!(PayloadLenMismatch {
+            expected: 4,
+            actual: 3
+        }
+        .source()
+        .is_none());
+        assert!(SegmentCountMismatch {
+            expected: 2,
+            actual: 1
+        }
+        .source()
+        .is_none());
+        assert!(SegmentLenMismatch {
+            sequence: 3,
+            expected: 4,
+            actual: 2
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn from() {
+        let inner = VerboseDecodeError::InvalidBoolValue(2);
+        assert_eq!(NwTraceReassemblyError::Decode(inner.clone()), inner.into());
+    }
+}
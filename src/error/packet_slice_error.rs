@@ -13,6 +13,14 @@ pub enum PacketSliceError {
 
     /// Error if a slice did not contain enough data to decode a value.
     UnexpectedEndOfSlice(UnexpectedEndOfSliceError),
+
+    /// Error if a non verbose message is too short to contain a message id
+    /// (only returned by [`crate::DltPacketSlice::from_slice_strict`]).
+    MissingMessageId(DltMessageLengthTooSmallError),
+
+    /// Error if the dlt length is larger then the configured maximum
+    /// message length (see [`crate::SliceIterator::with_max_len`]).
+    MessageLengthTooLarge(DltMessageLengthTooLargeError),
 }
 
 impl core::fmt::Display for PacketSliceError {
@@ -21,19 +29,26 @@ impl core::fmt::Display for PacketSliceError {
         match self {
             UnsupportedDltVersion(v) => v.fmt(f),
             MessageLengthTooSmall(v) => v.fmt(f),
+            MessageLengthTooLarge(v) => v.fmt(f),
             UnexpectedEndOfSlice(v) => v.fmt(f),
+            MissingMessageId(v) => write!(
+                f,
+                "DLT Packet Error: The non verbose message with a length of {} is too short to contain a message id (minimum required size of {} bytes).",
+                v.actual_length, v.required_length
+            ),
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PacketSliceError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for PacketSliceError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         use PacketSliceError::*;
         match self {
             UnsupportedDltVersion(v) => Some(v),
             MessageLengthTooSmall(v) => Some(v),
+            MessageLengthTooLarge(v) => Some(v),
             UnexpectedEndOfSlice(v) => Some(v),
+            MissingMessageId(v) => Some(v),
         }
     }
 }
@@ -86,6 +101,16 @@ mod tests {
                 format!("{}", MessageLengthTooSmall(inner.clone())),
             );
         }
+        {
+            let inner = DltMessageLengthTooLargeError {
+                actual_length: 2,
+                max_length: 1,
+            };
+            assert_eq!(
+                format!("{}", inner),
+                format!("{}", MessageLengthTooLarge(inner.clone())),
+            );
+        }
         {
             let inner = UnexpectedEndOfSliceError {
                 actual_size: 1,
@@ -97,12 +122,21 @@ mod tests {
                 format!("{}", UnexpectedEndOfSlice(inner.clone())),
             );
         }
+        {
+            let inner = DltMessageLengthTooSmallError {
+                actual_length: 6,
+                required_length: 8,
+            };
+            assert_eq!(
+                "DLT Packet Error: The non verbose message with a length of 6 is too short to contain a message id (minimum required size of 8 bytes).",
+                format!("{}", MissingMessageId(inner)),
+            );
+        }
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         use PacketSliceError::*;
         assert!(UnsupportedDltVersion(UnsupportedDltVersionError {
             unsupported_version: 123,
@@ -122,5 +156,17 @@ mod tests {
         })
         .source()
         .is_some());
+        assert!(MessageLengthTooLarge(DltMessageLengthTooLargeError {
+            actual_length: 2,
+            max_length: 1,
+        })
+        .source()
+        .is_some());
+        assert!(MissingMessageId(DltMessageLengthTooSmallError {
+            actual_length: 1,
+            required_length: 2,
+        })
+        .source()
+        .is_some());
     }
 }
@@ -0,0 +1,47 @@
+/// Error when parsing a [`crate::DltLogLevel`] from a string fails
+/// (see the `FromStr` implementation of [`crate::DltLogLevel`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLogLevelError;
+
+impl core::fmt::Display for ParseLogLevelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown DLT log level. Expected one of 'fatal', 'error', 'warn', 'info', 'debug', 'verbose' or a number between 1 and 6.")
+    }
+}
+
+impl core::error::Error for ParseLogLevelError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod parse_log_level_error_test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        let v = ParseLogLevelError;
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!("ParseLogLevelError", format!("{:?}", ParseLogLevelError));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "Unknown DLT log level. Expected one of 'fatal', 'error', 'warn', 'info', 'debug', 'verbose' or a number between 1 and 6.",
+            format!("{}", ParseLogLevelError)
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        assert!(ParseLogLevelError.source().is_none());
+    }
+}
@@ -3,11 +3,26 @@
 pub enum RangeError {
     /// Error if the user defined value is outside the range of 7-15
     NetworkTypekUserDefinedOutsideOfRange(u8),
+
+    /// Error if a log level value is outside the range of 1-6.
+    LogLevelOutsideOfRange(u8),
+
+    /// Error if a trace type value is outside the range of 1-5.
+    TraceTypeOutsideOfRange(u8),
+
+    /// Error if the length of a message (header + payload) would exceed
+    /// the maximum value that can be represented by the length field of
+    /// the DLT header (65535).
+    MessageLengthTooBig {
+        /// Length of the DLT header in bytes.
+        header_len: u16,
+        /// Length of the payload in bytes.
+        payload_len: usize,
+    },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for RangeError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for RangeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -20,6 +35,26 @@ impl core::fmt::Display for RangeError {
             NetworkTypekUserDefinedOutsideOfRange(value) => {
                 write!(f, "RangeError: Message type info field user defined value of {} outside of the allowed range of 7-15.", value)
             }
+            LogLevelOutsideOfRange(value) => {
+                write!(
+                    f,
+                    "RangeError: Log level value of {} outside of the allowed range of 1-6.",
+                    value
+                )
+            }
+            TraceTypeOutsideOfRange(value) => {
+                write!(
+                    f,
+                    "RangeError: Trace type value of {} outside of the allowed range of 1-5.",
+                    value
+                )
+            }
+            MessageLengthTooBig {
+                header_len,
+                payload_len,
+            } => {
+                write!(f, "RangeError: Message length of a {} byte header plus a {} byte payload exceeds the maximum DLT message length of 65535 bytes.", header_len, payload_len)
+            }
         }
     }
 }
@@ -49,7 +84,7 @@ mod tests {
 
     proptest! {
         #[test]
-        fn display(value in any::<u8>()) {
+        fn display(value in any::<u8>(), header_len in any::<u16>(), payload_len in any::<usize>()) {
             use RangeError::*;
 
             // NetworkTypekUserDefinedOutsideOfRange
@@ -57,17 +92,42 @@ mod tests {
                 &format!("RangeError: Message type info field user defined value of {} outside of the allowed range of 7-15.", value),
                 &format!("{}", NetworkTypekUserDefinedOutsideOfRange(value))
             );
+
+            // LogLevelOutsideOfRange
+            assert_eq!(
+                &format!("RangeError: Log level value of {} outside of the allowed range of 1-6.", value),
+                &format!("{}", LogLevelOutsideOfRange(value))
+            );
+
+            // TraceTypeOutsideOfRange
+            assert_eq!(
+                &format!("RangeError: Trace type value of {} outside of the allowed range of 1-5.", value),
+                &format!("{}", TraceTypeOutsideOfRange(value))
+            );
+
+            // MessageLengthTooBig
+            assert_eq!(
+                &format!("RangeError: Message length of a {} byte header plus a {} byte payload exceeds the maximum DLT message length of 65535 bytes.", header_len, payload_len),
+                &format!("{}", MessageLengthTooBig{ header_len, payload_len })
+            );
         }
     }
 
     #[test]
-    #[cfg(feature = "std")]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         use RangeError::*;
 
         assert!(NetworkTypekUserDefinedOutsideOfRange(123)
             .source()
             .is_none());
+        assert!(LogLevelOutsideOfRange(0).source().is_none());
+        assert!(TraceTypeOutsideOfRange(0).source().is_none());
+        assert!(MessageLengthTooBig {
+            header_len: 4,
+            payload_len: 0x1_0000
+        }
+        .source()
+        .is_none());
     }
 } // mod tests
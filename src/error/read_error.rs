@@ -14,6 +14,9 @@ pub enum ReadError {
     /// Error if the dlt length is smaller then the header the calculated header size based on the flags (+ minimum payload size of 4 bytes/octetets)
     DltMessageLengthTooSmall(DltMessageLengthTooSmallError),
 
+    /// Error if the dlt length is larger then the configured maximum message length.
+    DltMessageLengthTooLarge(DltMessageLengthTooLargeError),
+
     /// Error if a storage header does not start with the correct pattern.
     StorageHeaderStartPattern(StorageHeaderStartPatternError),
 
@@ -22,13 +25,14 @@ pub enum ReadError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ReadError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         use ReadError::*;
         match self {
             UnexpectedEndOfSlice(ref err) => Some(err),
             UnsupportedDltVersion(ref err) => Some(err),
             DltMessageLengthTooSmall(ref err) => Some(err),
+            DltMessageLengthTooLarge(ref err) => Some(err),
             StorageHeaderStartPattern(ref err) => Some(err),
             IoError(ref err) => Some(err),
         }
@@ -46,6 +50,7 @@ impl core::fmt::Display for ReadError {
             }
             UnsupportedDltVersion(err) => err.fmt(f),
             DltMessageLengthTooSmall(err) => err.fmt(f),
+            DltMessageLengthTooLarge(err) => err.fmt(f),
             StorageHeaderStartPattern(err) => err.fmt(f),
             IoError(err) => err.fmt(f),
         }
@@ -66,6 +71,8 @@ impl From<PacketSliceError> for ReadError {
         match err {
             I::UnsupportedDltVersion(err) => ReadError::UnsupportedDltVersion(err),
             I::MessageLengthTooSmall(err) => ReadError::DltMessageLengthTooSmall(err),
+            I::MissingMessageId(err) => ReadError::DltMessageLengthTooSmall(err),
+            I::MessageLengthTooLarge(err) => ReadError::DltMessageLengthTooLarge(err),
             I::UnexpectedEndOfSlice(err) => ReadError::UnexpectedEndOfSlice(err),
         }
     }
@@ -119,6 +126,16 @@ mod tests {
                 format!("{:?}", DltMessageLengthTooSmall(c))
             );
         }
+        {
+            let c = DltMessageLengthTooLargeError {
+                max_length: 3,
+                actual_length: 4,
+            };
+            assert_eq!(
+                format!("DltMessageLengthTooLarge({:?})", c),
+                format!("{:?}", DltMessageLengthTooLarge(c))
+            );
+        }
         {
             let c = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
             assert_eq!(format!("IoError({:?})", c), format!("{:?}", IoError(c)));
@@ -170,6 +187,18 @@ mod tests {
                 );
             }
 
+            // DltMessageLengthTooLarge
+            {
+                let c = DltMessageLengthTooLargeError{
+                    max_length: usize0,
+                    actual_length: usize1
+                };
+                assert_eq!(
+                    &format!("{}", c),
+                    &format!("{}", DltMessageLengthTooLarge(c))
+                );
+            }
+
             // StorageHeaderStartPattern
             {
                 let c = StorageHeaderStartPatternError{
@@ -194,7 +223,7 @@ mod tests {
 
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         use ReadError::*;
 
         assert!(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
@@ -215,6 +244,12 @@ mod tests {
         })
         .source()
         .is_some());
+        assert!(DltMessageLengthTooLarge(DltMessageLengthTooLargeError {
+            max_length: 3,
+            actual_length: 4
+        })
+        .source()
+        .is_some());
         assert!(StorageHeaderStartPattern(StorageHeaderStartPatternError {
             actual_pattern: [1, 2, 3, 4]
         })
@@ -275,5 +310,25 @@ mod tests {
             .into();
             assert_matches!(r, ReadError::UnexpectedEndOfSlice(_));
         }
+
+        // MessageLengthTooLarge
+        {
+            let r: ReadError = I::MessageLengthTooLarge(DltMessageLengthTooLargeError {
+                max_length: 3,
+                actual_length: 4,
+            })
+            .into();
+            assert_matches!(r, ReadError::DltMessageLengthTooLarge(_));
+        }
+
+        // MissingMessageId
+        {
+            let r: ReadError = I::MissingMessageId(DltMessageLengthTooSmallError {
+                required_length: 8,
+                actual_length: 4,
+            })
+            .into();
+            assert_matches!(r, ReadError::DltMessageLengthTooSmall(_));
+        }
     }
 } // mod tests
@@ -18,9 +18,8 @@ impl core::fmt::Display for StorageHeaderStartPatternError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for StorageHeaderStartPatternError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for StorageHeaderStartPatternError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -67,10 +66,9 @@ mod tests {
         );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         assert!(StorageHeaderStartPatternError {
             actual_pattern: [1, 2, 3, 4]
         }
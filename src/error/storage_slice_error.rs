@@ -0,0 +1,148 @@
+use super::*;
+
+/// Errors that can occur when slicing a storage header & DLT packet
+/// from a slice (e.g. the content of a .dlt file).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageSliceError {
+    /// Error if the slice is too small to contain a storage header.
+    UnexpectedEndOfSlice(UnexpectedEndOfSliceError),
+
+    /// Error if a storage header does not start with the correct pattern.
+    StorageHeaderStartPattern(StorageHeaderStartPatternError),
+
+    /// Error when slicing the DLT packet following the storage header.
+    Packet(PacketSliceError),
+}
+
+impl core::fmt::Display for StorageSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use StorageSliceError::*;
+        match self {
+            UnexpectedEndOfSlice(v) => v.fmt(f),
+            StorageHeaderStartPattern(v) => v.fmt(f),
+            Packet(v) => v.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for StorageSliceError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        use StorageSliceError::*;
+        match self {
+            UnexpectedEndOfSlice(v) => Some(v),
+            StorageHeaderStartPattern(v) => Some(v),
+            Packet(v) => Some(v),
+        }
+    }
+}
+
+impl From<StorageHeaderStartPatternError> for StorageSliceError {
+    fn from(value: StorageHeaderStartPatternError) -> Self {
+        StorageSliceError::StorageHeaderStartPattern(value)
+    }
+}
+
+impl From<PacketSliceError> for StorageSliceError {
+    fn from(value: PacketSliceError) -> Self {
+        StorageSliceError::Packet(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use StorageSliceError::*;
+        let v = StorageHeaderStartPattern(StorageHeaderStartPatternError {
+            actual_pattern: [1, 2, 3, 4],
+        });
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        use StorageSliceError::*;
+        let inner = StorageHeaderStartPatternError {
+            actual_pattern: [1, 2, 3, 4],
+        };
+        assert_eq!(
+            format!("StorageHeaderStartPattern({:?})", inner),
+            format!("{:?}", StorageHeaderStartPattern(inner.clone())),
+        );
+    }
+
+    #[test]
+    fn display() {
+        use StorageSliceError::*;
+        {
+            let inner = UnexpectedEndOfSliceError {
+                actual_size: 1,
+                layer: Layer::StorageHeader,
+                minimum_size: 16,
+            };
+            assert_eq!(
+                format!("{}", inner),
+                format!("{}", UnexpectedEndOfSlice(inner.clone())),
+            );
+        }
+        {
+            let inner = StorageHeaderStartPatternError {
+                actual_pattern: [1, 2, 3, 4],
+            };
+            assert_eq!(
+                format!("{}", inner),
+                format!("{}", StorageHeaderStartPattern(inner.clone())),
+            );
+        }
+        {
+            let inner = PacketSliceError::UnsupportedDltVersion(UnsupportedDltVersionError {
+                unsupported_version: 123,
+            });
+            assert_eq!(format!("{}", inner), format!("{}", Packet(inner.clone())));
+        }
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        use StorageSliceError::*;
+        assert!(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+            actual_size: 1,
+            layer: Layer::StorageHeader,
+            minimum_size: 16,
+        })
+        .source()
+        .is_some());
+        assert!(StorageHeaderStartPattern(StorageHeaderStartPatternError {
+            actual_pattern: [1, 2, 3, 4],
+        })
+        .source()
+        .is_some());
+        assert!(Packet(PacketSliceError::UnsupportedDltVersion(
+            UnsupportedDltVersionError {
+                unsupported_version: 123,
+            }
+        ))
+        .source()
+        .is_some());
+    }
+
+    #[test]
+    fn from() {
+        let inner = StorageHeaderStartPatternError {
+            actual_pattern: [1, 2, 3, 4],
+        };
+        assert_eq!(
+            StorageSliceError::StorageHeaderStartPattern(inner.clone()),
+            inner.into()
+        );
+
+        let inner = PacketSliceError::UnsupportedDltVersion(UnsupportedDltVersionError {
+            unsupported_version: 123,
+        });
+        assert_eq!(StorageSliceError::Packet(inner.clone()), inner.into());
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+/// Errors that can occur when reassembling DLT packets from a stream
+/// (see [`crate::DltStreamParser`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StreamParseError {
+    /// Error when slicing the DLT packet at the start of the buffered data.
+    Packet(PacketSliceError),
+
+    /// Error if a message is bigger then the capacity of the parser buffer.
+    MessageTooBig {
+        /// Length of the message in bytes.
+        message_len: usize,
+        /// Capacity of the parser buffer in bytes.
+        capacity: usize,
+    },
+}
+
+impl core::fmt::Display for StreamParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use StreamParseError::*;
+        match self {
+            Packet(v) => v.fmt(f),
+            MessageTooBig {
+                message_len,
+                capacity,
+            } => write!(
+                f,
+                "DLT Stream: Message with a length of {message_len} bytes does not fit into the buffer with a capacity of {capacity} bytes."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for StreamParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        use StreamParseError::*;
+        match self {
+            Packet(v) => Some(v),
+            MessageTooBig { .. } => None,
+        }
+    }
+}
+
+impl From<PacketSliceError> for StreamParseError {
+    fn from(value: PacketSliceError) -> Self {
+        StreamParseError::Packet(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use StreamParseError::*;
+        let v = MessageTooBig {
+            message_len: 12,
+            capacity: 10,
+        };
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        use StreamParseError::*;
+        assert_eq!(
+            "MessageTooBig { message_len: 12, capacity: 10 }",
+            format!(
+                "{:?}",
+                MessageTooBig {
+                    message_len: 12,
+                    capacity: 10
+                }
+            ),
+        );
+    }
+
+    #[test]
+    fn display() {
+        use StreamParseError::*;
+        {
+            let inner = PacketSliceError::UnsupportedDltVersion(UnsupportedDltVersionError {
+                unsupported_version: 123,
+            });
+            assert_eq!(format!("{}", inner), format!("{}", Packet(inner.clone())));
+        }
+        assert_eq!(
+            "DLT Stream: Message with a length of 12 bytes does not fit into the buffer with a capacity of 10 bytes.",
+            format!(
+                "{}",
+                MessageTooBig {
+                    message_len: 12,
+                    capacity: 10
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        use StreamParseError::*;
+        assert!(Packet(PacketSliceError::UnsupportedDltVersion(
+            UnsupportedDltVersionError {
+                unsupported_version: 123,
+            }
+        ))
+        .source()
+        .is_some());
+        assert!(MessageTooBig {
+            message_len: 12,
+            capacity: 10
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn from() {
+        let inner = PacketSliceError::UnsupportedDltVersion(UnsupportedDltVersionError {
+            unsupported_version: 123,
+        });
+        assert_eq!(StreamParseError::Packet(inner.clone()), inner.into());
+    }
+}
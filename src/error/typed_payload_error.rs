@@ -1,4 +1,5 @@
 use crate::DltMessageInfo;
+use core::str::Utf8Error;
 
 /// Error that can occur when trying to get a [`crate::DltTypedPayload`] from
 /// a [`crate::DltPacketSlice`].
@@ -12,12 +13,23 @@ pub enum TypedPayloadError {
 
     /// Error if the message info
     UnknownMessageInfo(DltMessageInfo),
+
+    /// Error if a verbose value has a different type than the expected one.
+    TypeMismatch {
+        expected: &'static str,
+        actual: &'static str,
+    },
+
+    /// Error if the data of a verbose string value is not valid UTF-8.
+    Utf8(Utf8Error),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TypedPayloadError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+impl core::error::Error for TypedPayloadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TypedPayloadError::Utf8(err) => Some(err),
+            _ => None,
+        }
     }
 }
 
@@ -38,6 +50,13 @@ impl core::fmt::Display for TypedPayloadError {
                     message_info.0
                 )
             }
+            TypeMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "DLT verbose value has the type '{actual}' but the type '{expected}' was expected"
+                )
+            }
+            Utf8(err) => err.fmt(f),
         }
     }
 }
@@ -87,13 +106,26 @@ mod tests {
                 &format!("DLT message info contains the value '{}' that is unknown", value_u8),
                 &format!("{}", UnknownMessageInfo(DltMessageInfo(value_u8)))
             );
+
+            // TypeMismatch
+            assert_eq!(
+                "DLT verbose value has the type 'I16' but the type 'U32' was expected",
+                &format!("{}", TypeMismatch { expected: "U32", actual: "I16" })
+            );
+
+            // Utf8
+            #[allow(invalid_from_utf8)]
+            {
+                let err = core::str::from_utf8(&[0xff]).unwrap_err();
+                assert_eq!(&format!("{}", err), &format!("{}", Utf8(err)));
+            }
         }
     }
 
     #[test]
-    #[cfg(feature = "std")]
+    #[allow(invalid_from_utf8)]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         use TypedPayloadError::*;
 
         assert!(LenSmallerThanMessageId {
@@ -102,5 +134,14 @@ mod tests {
         }
         .source()
         .is_none());
+        assert!(TypeMismatch {
+            expected: "U32",
+            actual: "I16"
+        }
+        .source()
+        .is_none());
+        assert!(Utf8(core::str::from_utf8(&[0xff]).unwrap_err())
+            .source()
+            .is_some());
     }
 } // mod tests
@@ -25,9 +25,8 @@ impl core::fmt::Display for UnexpectedEndOfSliceError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for UnexpectedEndOfSliceError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for UnexpectedEndOfSliceError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -81,10 +80,9 @@ mod tests {
         );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         assert!(UnexpectedEndOfSliceError {
             layer: Layer::DltHeader,
             minimum_size: 2,
@@ -19,9 +19,8 @@ impl core::fmt::Display for UnsupportedDltVersionError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for UnsupportedDltVersionError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for UnsupportedDltVersionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -67,10 +66,9 @@ mod unsupported_dlt_version_error_test {
         );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         assert!(UnsupportedDltVersionError {
             unsupported_version: 123,
         }
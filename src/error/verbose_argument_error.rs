@@ -0,0 +1,95 @@
+use super::*;
+
+/// Error if a verbose argument could not be decoded (contains the position
+/// of the argument in the payload).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerboseArgumentError {
+    /// Index of the argument that could not be decoded.
+    pub index: u16,
+
+    /// Byte offset of the argument relative to the start of the payload.
+    pub offset: usize,
+
+    /// Error that occurred while decoding the argument.
+    pub error: VerboseDecodeError,
+}
+
+impl core::fmt::Display for VerboseArgumentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Failed to decode verbose argument {} at payload offset {}: {}",
+            self.index, self.offset, self.error
+        )
+    }
+}
+
+impl core::error::Error for VerboseArgumentError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<VerboseArgumentError> for VerboseDecodeError {
+    fn from(value: VerboseArgumentError) -> Self {
+        value.error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn value() -> VerboseArgumentError {
+        VerboseArgumentError {
+            index: 2,
+            offset: 12,
+            error: VerboseDecodeError::InvalidBoolValue(3),
+        }
+    }
+
+    #[test]
+    fn clone_eq() {
+        let v = value();
+        assert_eq!(v, v.clone());
+    }
+
+    #[test]
+    fn debug() {
+        let v = value();
+        assert_eq!(
+            format!(
+                "VerboseArgumentError {{ index: 2, offset: 12, error: {:?} }}",
+                v.error
+            ),
+            format!("{:?}", v)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let v = value();
+        assert_eq!(
+            format!(
+                "Failed to decode verbose argument 2 at payload offset 12: {}",
+                v.error
+            ),
+            format!("{}", v)
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+        assert!(value().source().is_some());
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(
+            VerboseDecodeError::InvalidBoolValue(3),
+            VerboseDecodeError::from(value())
+        );
+    }
+}
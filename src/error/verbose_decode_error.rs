@@ -30,8 +30,37 @@ pub enum VerboseDecodeError {
 
     StructDataLengthOverflow,
 
+    /// Error if the entries data of a struct contains less decodable
+    /// values than declared in its number of entries field.
+    StructNumberOfEntriesMismatch {
+        /// Number of entries declared in the struct.
+        declared: u16,
+        /// Number of entries that could be decoded.
+        decoded: u16,
+    },
+
     /// Error when decoding an string (can also occur for variable names or unit names).
     Utf8(Utf8Error),
+
+    /// Error if a payload contains more data than expected.
+    UnexpectedTrailingBytes {
+        /// Expected length of the payload.
+        expected_len: usize,
+        /// Actual length of the payload.
+        actual_len: usize,
+    },
+
+    /// Error if a log level byte in a control message is neither a known
+    /// log level nor one of the sentinel values (see
+    /// [`crate::control::DltLogLevelConfig`]).
+    InvalidLogLevel(u8),
+
+    /// Error if the buffer passed to [`crate::verbose::decode_all`] is too
+    /// small to hold all values of the payload.
+    OutputBufferTooSmall {
+        /// Number of values that fit into the buffer.
+        capacity: usize,
+    },
 }
 
 impl core::fmt::Display for VerboseDecodeError {
@@ -54,13 +83,24 @@ impl core::fmt::Display for VerboseDecodeError {
             Utf8(err) => err.fmt(f),
             ArrayDimensionsOverflow => write!(f, "DLT Verbose Message Field: Array dimension sizes too big. Calculating the overall array size would cause an integer overflow."),
             StructDataLengthOverflow => write!(f, "DLT Verbose Message Field: Struct data length too big. Would cause an integer overflow."),
+            StructNumberOfEntriesMismatch { declared, decoded } => write!(
+                f, "DLT Verbose Message Field: Struct declares {declared} entries but only {decoded} entries could be decoded."
+            ),
+            UnexpectedTrailingBytes { expected_len, actual_len } => write!(
+                f, "DLT Payload: Encountered unexpected trailing bytes (expected a length of {expected_len} bytes but got {actual_len} bytes)."
+            ),
+            InvalidLogLevel(value) => write!(
+                f, "DLT Control Message: Encountered invalid log level value '{}'", value
+            ),
+            OutputBufferTooSmall { capacity } => write!(
+                f, "DLT Verbose Message: Output buffer with a capacity of {capacity} values is too small to hold all values of the payload."
+            ),
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for VerboseDecodeError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for VerboseDecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         use VerboseDecodeError::*;
         match self {
             InvalidTypeInfo(_) => None,
@@ -71,6 +111,10 @@ impl std::error::Error for VerboseDecodeError {
             Utf8(err) => Some(err),
             ArrayDimensionsOverflow => None,
             StructDataLengthOverflow => None,
+            StructNumberOfEntriesMismatch { .. } => None,
+            UnexpectedTrailingBytes { .. } => None,
+            InvalidLogLevel(_) => None,
+            OutputBufferTooSmall { .. } => None,
         }
     }
 }
@@ -138,13 +182,32 @@ mod tests {
             let v = std::str::from_utf8(&[0, 159, 146, 150]).unwrap_err();
             assert_eq!(format!("{}", v), format!("{}", Utf8(v)));
         }
+
+        assert_eq!(
+            "DLT Verbose Message Field: Struct declares 3 entries but only 2 entries could be decoded.",
+            format!("{}", StructNumberOfEntriesMismatch { declared: 3, decoded: 2 })
+        );
+
+        assert_eq!(
+            "DLT Payload: Encountered unexpected trailing bytes (expected a length of 4 bytes but got 5 bytes).",
+            format!("{}", UnexpectedTrailingBytes { expected_len: 4, actual_len: 5 })
+        );
+
+        assert_eq!(
+            "DLT Control Message: Encountered invalid log level value '7'",
+            format!("{}", InvalidLogLevel(7))
+        );
+
+        assert_eq!(
+            "DLT Verbose Message: Output buffer with a capacity of 2 values is too small to hold all values of the payload.",
+            format!("{}", OutputBufferTooSmall { capacity: 2 })
+        );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     #[allow(invalid_from_utf8)]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
         use VerboseDecodeError::*;
         assert!(InvalidTypeInfo([1, 2, 3, 4]).source().is_none());
         assert!(InvalidBoolValue(2).source().is_none());
@@ -160,6 +223,20 @@ mod tests {
         assert!(Utf8(std::str::from_utf8(&[0, 159, 146, 150]).unwrap_err())
             .source()
             .is_some());
+        assert!(StructNumberOfEntriesMismatch {
+            declared: 3,
+            decoded: 2
+        }
+        .source()
+        .is_none());
+        assert!(UnexpectedTrailingBytes {
+            expected_len: 4,
+            actual_len: 5
+        }
+        .source()
+        .is_none());
+        assert!(InvalidLogLevel(7).source().is_none());
+        assert!(OutputBufferTooSmall { capacity: 2 }.source().is_none());
     }
 
     #[test]
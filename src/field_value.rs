@@ -0,0 +1,198 @@
+use crate::verbose::VerboseValue;
+use alloc::borrow::Cow;
+
+/// Value of a field returned by [`crate::DltPacketSlice::to_fields`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue<'a> {
+    /// String value (e.g. ids or verbose string arguments).
+    Str(Cow<'a, str>),
+    /// Bool value.
+    Bool(bool),
+    /// Signed integer value.
+    Int(i128),
+    /// Unsigned integer value (128 bit floating point values are
+    /// also stored as their bit pattern in this variant).
+    UInt(u128),
+    /// Floating point value.
+    Float(f64),
+    /// Raw bytes (raw verbose arguments as well as the encoded data of
+    /// arrays & structs).
+    Bytes(&'a [u8]),
+}
+
+impl<'a> From<&VerboseValue<'a>> for FieldValue<'a> {
+    fn from(value: &VerboseValue<'a>) -> FieldValue<'a> {
+        use FieldValue::*;
+        use VerboseValue as V;
+
+        match value {
+            V::Bool(v) => Bool(v.value),
+            V::Str(v) => Str(v.to_string_lossy()),
+            V::TraceInfo(v) => Str(Cow::Borrowed(v.value)),
+            V::I8(v) => Int(i128::from(v.value)),
+            V::I16(v) => Int(i128::from(v.value)),
+            V::I32(v) => Int(i128::from(v.value)),
+            V::I64(v) => Int(i128::from(v.value)),
+            V::I128(v) => Int(v.value),
+            V::U8(v) => UInt(u128::from(v.value)),
+            V::U16(v) => UInt(u128::from(v.value)),
+            V::U32(v) => UInt(u128::from(v.value)),
+            V::U64(v) => UInt(u128::from(v.value)),
+            V::U128(v) => UInt(v.value),
+            V::F16(v) => Float(f64::from(v.value.to_f32())),
+            V::F32(v) => Float(f64::from(v.value)),
+            V::F64(v) => Float(v.value),
+            V::F128(v) => UInt(v.value.to_bits()),
+            V::ArrBool(v) => Bytes(v.data),
+            V::ArrI8(v) => Bytes(v.data),
+            V::ArrI16(v) => Bytes(v.data),
+            V::ArrI32(v) => Bytes(v.data),
+            V::ArrI64(v) => Bytes(v.data),
+            V::ArrI128(v) => Bytes(v.data),
+            V::ArrU8(v) => Bytes(v.data),
+            V::ArrU16(v) => Bytes(v.data),
+            V::ArrU32(v) => Bytes(v.data),
+            V::ArrU64(v) => Bytes(v.data),
+            V::ArrU128(v) => Bytes(v.data),
+            V::ArrF16(v) => Bytes(v.data),
+            V::ArrF32(v) => Bytes(v.data),
+            V::ArrF64(v) => Bytes(v.data),
+            V::ArrF128(v) => Bytes(v.data),
+            V::Struct(v) => Bytes(v.entries_raw_data()),
+            V::Raw(v) => Bytes(v.data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verbose::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = FieldValue::Str(Cow::Borrowed("a"));
+        assert_eq!(v, v.clone());
+        assert_eq!("Str(\"a\")", format!("{:?}", v));
+    }
+
+    #[test]
+    fn from_verbose_value() {
+        use FieldValue::*;
+        use VerboseValue as V;
+
+        // u8 array with the dimensions [2] & the data [1, 2]
+        let array_data = [0b0100_0001, 0b0000_0001, 0, 0, 0, 1, 0, 2, 1, 2];
+        let (array, _) = V::from_slice(&array_data, true).unwrap();
+
+        let tests = [
+            (
+                V::Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+                Bool(true),
+            ),
+            (
+                V::Str(StringValue::new(None, "abc")),
+                Str(Cow::Borrowed("abc")),
+            ),
+            // invalid UTF-8 gets replaced
+            (
+                V::Str(StringValue::with_encoding(
+                    None,
+                    StringEncoding::Ascii,
+                    &[b'a', 0xff],
+                )),
+                Str(Cow::Borrowed("a\u{FFFD}")),
+            ),
+            (
+                V::TraceInfo(TraceInfoValue { value: "trace" }),
+                Str(Cow::Borrowed("trace")),
+            ),
+            (
+                V::I8(I8Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: -8,
+                }),
+                Int(-8),
+            ),
+            (
+                V::I128(I128Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: i128::MIN,
+                }),
+                Int(i128::MIN),
+            ),
+            (
+                V::U16(U16Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 16,
+                }),
+                UInt(16),
+            ),
+            (
+                V::U128(U128Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u128::MAX,
+                }),
+                UInt(u128::MAX),
+            ),
+            (
+                V::F16(F16Value {
+                    variable_info: None,
+                    value: RawF16::ONE,
+                }),
+                Float(1.0),
+            ),
+            (
+                V::F32(F32Value {
+                    variable_info: None,
+                    value: 1.5,
+                }),
+                Float(1.5),
+            ),
+            (
+                V::F64(F64Value {
+                    variable_info: None,
+                    value: -2.5,
+                }),
+                Float(-2.5),
+            ),
+            // 128 bit floats are exported as their bit pattern
+            (
+                V::F128(F128Value {
+                    variable_info: None,
+                    value: RawF128::from_bits(1234),
+                }),
+                UInt(1234),
+            ),
+            // arrays & structs are exported as raw data
+            (array, Bytes(&[1, 2])),
+            (
+                V::Struct(StructValue {
+                    is_big_endian: true,
+                    number_of_entries: 0,
+                    name: None,
+                    entries_data: &[5, 6],
+                }),
+                Bytes(&[5, 6]),
+            ),
+            (
+                V::Raw(RawValue {
+                    name: None,
+                    data: &[7, 8],
+                }),
+                Bytes(&[7, 8]),
+            ),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(expected, FieldValue::from(&value), "{:?}", value);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Tests feeding random data into the decoding functions to verify
+// that no input leads to a panic.
+
+use crate::storage::{StorageHeader, StorageSlice};
+use crate::verbose::VerboseValue;
+use crate::*;
+use proptest::prelude::*;
+
+/// Calls all accessors of a packet slice that read from the underlying data.
+fn check_packet(packet: &DltPacketSlice) {
+    let header = packet.header();
+    assert!(packet.slice().len() >= usize::from(header.header_len()));
+    assert_eq!(packet.consumed_len(), packet.slice().len());
+    let _ = packet.header_version();
+    let _ = packet.has_extended_header();
+    let _ = packet.is_big_endian();
+    assert_eq!(header.message_counter, packet.message_counter());
+    assert_eq!(header.ecu_id.is_some(), packet.has_ecu_id());
+    assert_eq!(header.session_id.is_some(), packet.has_session_id());
+    assert_eq!(header.timestamp.is_some(), packet.has_timestamp());
+    assert_eq!(header.ecu_id, packet.ecu_id());
+    assert_eq!(header.session_id, packet.session_id());
+    assert_eq!(header.timestamp, packet.timestamp());
+    let _ = packet.is_verbose();
+    let _ = packet.verbose_arg_count();
+    assert_eq!(
+        packet.extended_header(),
+        packet.extended_header_slice().map(|e| e.to_header())
+    );
+    let _ = packet.message_type();
+    let _ = packet.message_info();
+    let _ = packet.log_level();
+    let _ = packet.timestamp_micros();
+    let _ = packet.is_control_message();
+    let _ = packet.control_service_id();
+    let _ = control::ControlMessage::from_packet(packet);
+    let _ = packet.message_id();
+    let _ = packet.payload();
+    let _ = packet.payload_contains(&[0, 1]);
+    let _ = packet.message_id_and_payload();
+    let _ = packet.non_verbose_payload();
+    let _ = packet.typed_payload();
+    #[cfg(feature = "alloc")]
+    let _ = packet.to_fields();
+    #[cfg(feature = "alloc")]
+    let _ = packet.to_packet();
+    if let Some(iter) = packet.verbose_value_iter() {
+        // the iterator has to terminate on its own
+        for value in iter {
+            let _ = value;
+        }
+    }
+}
+
+/// Sets the version & length in the header so the data is more likely
+/// to pass the packet header checks.
+fn patch_header(data: &mut [u8]) {
+    if data.len() >= 4 {
+        data[0] = (data[0] & 0b0001_1111) | 0b0010_0000;
+        let len = u16::try_from(data.len()).unwrap_or(u16::MAX).to_be_bytes();
+        data[2] = len[0];
+        data[3] = len[1];
+    }
+}
+
+proptest! {
+    #[test]
+    fn packet_slice_random_data(
+        ref data in proptest::collection::vec(any::<u8>(), 0..1024)
+    ) {
+        // completely random data
+        if let Ok(packet) = DltPacketSlice::from_slice(data) {
+            check_packet(&packet);
+        }
+
+        // data with a plausible header
+        let mut patched = data.clone();
+        patch_header(&mut patched);
+        if let Ok(packet) = DltPacketSlice::from_slice(&patched) {
+            check_packet(&packet);
+        }
+    }
+
+    #[test]
+    fn slice_iterator_random_data(
+        ref data in proptest::collection::vec(any::<u8>(), 0..1024)
+    ) {
+        let mut patched = data.clone();
+        patch_header(&mut patched);
+        for data in [data, &patched] {
+            // every call consumes at least one packet or ends the iteration
+            let mut iter = SliceIterator::new(data);
+            for _ in 0..=data.len() {
+                match iter.next() {
+                    Some(Ok(packet)) => check_packet(&packet),
+                    Some(Err(_)) => {}
+                    None => break,
+                }
+            }
+            prop_assert!(iter.next().is_none());
+        }
+    }
+
+    #[test]
+    fn storage_slice_random_data(
+        ref data in proptest::collection::vec(any::<u8>(), 0..1024)
+    ) {
+        let _ = StorageSlice::from_slice(data);
+
+        // data with a valid storage header start pattern
+        let mut patched = data.clone();
+        if patched.len() >= StorageHeader::BYTE_LEN {
+            patched[..4].copy_from_slice(&StorageHeader::PATTERN_AT_START);
+            patch_header(&mut patched[StorageHeader::BYTE_LEN..]);
+        }
+        if let Ok(value) = StorageSlice::from_slice(&patched) {
+            prop_assert!(value.consumed_len() <= patched.len());
+            check_packet(&value.packet);
+        }
+    }
+
+    #[test]
+    fn verbose_value_random_data(
+        ref data in proptest::collection::vec(any::<u8>(), 0..1024)
+    ) {
+        for is_big_endian in [false, true] {
+            if let Ok((_, rest)) = VerboseValue::from_slice(data, is_big_endian) {
+                prop_assert!(rest.len() < data.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/// Byte sizes of the individual parts of a DLT header (see
+/// [`crate::DltHeader::header_len_parts`]).
+///
+/// Parts that are not present in the header have a size of 0.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::{DltHeader, HeaderLenParts};
+///
+/// let header = DltHeader {
+///     ecu_id: Some(*b"ECU1"),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     HeaderLenParts {
+///         base: 4,
+///         ecu_id: 4,
+///         session_id: 0,
+///         timestamp: 0,
+///         extended: 0,
+///     },
+///     header.header_len_parts()
+/// );
+/// assert_eq!(header.header_len(), header.header_len_parts().total());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HeaderLenParts {
+    /// Size of the always present part of the header (header type,
+    /// message counter & length).
+    pub base: u16,
+    /// Size of the ECU id (0 if not present).
+    pub ecu_id: u16,
+    /// Size of the session id (0 if not present).
+    pub session_id: u16,
+    /// Size of the timestamp (0 if not present).
+    pub timestamp: u16,
+    /// Size of the extended header (0 if not present).
+    pub extended: u16,
+}
+
+impl HeaderLenParts {
+    /// Returns the summed up size of all parts.
+    #[inline]
+    pub fn total(&self) -> u16 {
+        self.base + self.ecu_id + self.session_id + self.timestamp + self.extended
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let parts = HeaderLenParts {
+            base: 4,
+            ecu_id: 4,
+            session_id: 0,
+            timestamp: 4,
+            extended: 10,
+        };
+        assert_eq!(parts, parts.clone());
+        assert_eq!(
+            "HeaderLenParts { base: 4, ecu_id: 4, session_id: 0, timestamp: 4, extended: 10 }",
+            format!("{:?}", parts)
+        );
+        assert_eq!(0, HeaderLenParts::default().total());
+    }
+
+    #[test]
+    fn total() {
+        let parts = HeaderLenParts {
+            base: 4,
+            ecu_id: 4,
+            session_id: 4,
+            timestamp: 4,
+            extended: 10,
+        };
+        assert_eq!(26, parts.total());
+    }
+}
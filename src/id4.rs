@@ -0,0 +1,144 @@
+/// Four byte identifier as used for the application id & context id
+/// of a DLT message (see [`crate::DltExtendedHeader::application_id4`] &
+/// [`crate::DltExtendedHeader::context_id4`]).
+///
+/// Dereferences to the underlying `[u8; 4]` & can be compared directly
+/// with byte arrays. The [`core::fmt::Display`] implementation prints the
+/// identifier as ASCII text.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::Id4;
+///
+/// let id = Id4::from(*b"APP\0");
+/// assert_eq!(*b"APP\0", id);
+/// assert_eq!("APP", format!("{}", id));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Id4(pub [u8; 4]);
+
+impl Id4 {
+    /// Returns the identifier as byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.0
+    }
+}
+
+impl From<[u8; 4]> for Id4 {
+    #[inline]
+    fn from(value: [u8; 4]) -> Self {
+        Id4(value)
+    }
+}
+
+impl From<Id4> for [u8; 4] {
+    #[inline]
+    fn from(value: Id4) -> Self {
+        value.0
+    }
+}
+
+impl core::ops::Deref for Id4 {
+    type Target = [u8; 4];
+
+    #[inline]
+    fn deref(&self) -> &[u8; 4] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Id4 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u8; 4]> for Id4 {
+    #[inline]
+    fn eq(&self, other: &[u8; 4]) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Id4> for [u8; 4] {
+    #[inline]
+    fn eq(&self, other: &Id4) -> bool {
+        self == &other.0
+    }
+}
+
+/// Prints the identifier as ASCII text. Trailing zero bytes (used to pad
+/// identifiers shorter then 4 characters) are omitted & all other
+/// non printable bytes are escaped (e.g. `\x01`).
+impl core::fmt::Display for Id4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        let len = self.0.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        for b in &self.0[..len] {
+            if b.is_ascii_graphic() || *b == b' ' {
+                f.write_char(char::from(*b))?;
+            } else {
+                write!(f, "\\x{:02x}", b)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn debug_clone_eq_ord() {
+        let v = Id4(*b"APP1");
+        assert_eq!(v, v.clone());
+        assert_eq!("Id4([65, 80, 80, 49])", format!("{:?}", v));
+        assert!(Id4(*b"APP1") < Id4(*b"APP2"));
+        assert_eq!(Id4([0; 4]), Id4::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        set.insert((Id4(*b"APP1"), Id4(*b"CTX1")));
+        assert!(set.contains(&(Id4(*b"APP1"), Id4(*b"CTX1"))));
+        assert!(false == set.contains(&(Id4(*b"APP1"), Id4(*b"CTX2"))));
+    }
+
+    proptest! {
+        #[test]
+        fn conversions(value in any::<[u8; 4]>()) {
+            let id = Id4::from(value);
+            prop_assert_eq!(value, id.to_bytes());
+            prop_assert_eq!(value, <[u8; 4]>::from(id));
+            prop_assert_eq!(&value, &*id);
+            prop_assert_eq!(&value[..], id.as_ref());
+            prop_assert!(id == value);
+            prop_assert!(value == id);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("APP1", format!("{}", Id4(*b"APP1")));
+        assert_eq!("AP", format!("{}", Id4(*b"AP\0\0")));
+        assert_eq!("", format!("{}", Id4([0; 4])));
+        assert_eq!("A B", format!("{}", Id4(*b"A B\0")));
+        assert_eq!("\\x00AB", format!("{}", Id4(*b"\0AB\0")));
+        assert_eq!("A\\x01\\xffB", format!("{}", Id4([b'A', 1, 0xff, b'B'])));
+    }
+}
@@ -17,6 +17,20 @@
 //! dlt_parse = { version = "0.10.0", features = ["serde"] }
 //! ```
 //!
+//! If you want to convert storage header timestamps to [`time::OffsetDateTime`](https://docs.rs/time/latest/time/struct.OffsetDateTime.html) values you can activate the `time` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! dlt_parse = { version = "0.10.0", features = ["time"] }
+//! ```
+//!
+//! If you want to read DLT storage files asynchronously via [`tokio`](https://docs.rs/tokio) (`storage::AsyncDltStorageReader`) you can activate the `tokio` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! dlt_parse = { version = "0.10.0", features = ["tokio"] }
+//! ```
+//!
 //! If you want to use the crate in `no_std` mode you will have to disable the default features:
 //!
 //! ```toml
@@ -24,6 +38,13 @@
 //! dlt_parse = { version = "0.10.0", default-features = false }
 //! ```
 //!
+//! Types that require allocations (e.g. `DltMessageBuilder`) are available in `no_std` mode if the `alloc` feature is activated:
+//!
+//! ```toml
+//! [dependencies]
+//! dlt_parse = { version = "0.10.0", default-features = false, features = ["alloc"] }
+//! ```
+//!
 //! # What is dlt_parse?
 //! dlt_parse is a library that aims to provide serialisation & deserialisation funtions for DLT (Diagnostic Log and Trace) packets.
 //! It should make it possible to anlyse recordings of DLT packets as fast as possible, as well as writing servers
@@ -170,6 +191,27 @@
 //!
 //! An complete example which includes the parsing of the ethernet & udp headers can be found in [examples/print_messages_ids.rs](https://github.com/JulianSchmid/dlt-parse-rs/blob/v0.8.2/examples/print_messages_ids.rs)
 //!
+//! # Breaking Changes (unreleased)
+//!
+//! The minimum supported Rust version is raised from 1.60 to 1.81, as all error types now
+//! implement `core::error::Error` (stabilized in Rust 1.81), also in `no_std` mode.
+//!
+//! [`verbose::StringValue`] no longer has a public `value: &str` field. The string data is
+//! kept as raw bytes together with its string coding (ASCII coded strings are not required
+//! to be valid UTF-8). Code using the field has to be migrated to the following API:
+//!
+//! * `StringValue { name, value }` becomes `StringValue::new(name, value)` (ASCII coded) or
+//!   `StringValue::with_encoding(name, encoding, value.as_bytes())`.
+//! * `v.value` becomes `v.to_str()` (returns an error if the data is not valid UTF-8),
+//!   `v.to_string_lossy()` (requires the `alloc` feature) or `v.as_bytes()`.
+//! * The string coding can be read via `v.encoding()`.
+//!
+//! New variants were added to error enums that are not `#[non_exhaustive]`, exhaustive `match`
+//! statements have to handle them:
+//!
+//! * `error::PacketSliceError::MissingMessageId` & `error::PacketSliceError::MessageLengthTooLarge`
+//! * `error::ReadError::DltMessageLengthTooLarge`
+//!
 //! # References
 //! * [Log and Trace Protocol Specification](https://www.autosar.org/fileadmin/standards/foundation/1-3/AUTOSAR_PRS_LogAndTraceProtocol.pdf)
 
@@ -190,7 +232,7 @@
 #![allow(clippy::bool_comparison)]
 #![no_std]
 
-#[cfg(test)]
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 #[cfg(test)]
 extern crate proptest;
@@ -200,9 +242,15 @@ extern crate std;
 #[macro_use]
 extern crate assert_matches;
 
+mod collect_app_ctx;
+pub use collect_app_ctx::*;
+
 mod dlt_extended_header;
 pub use dlt_extended_header::*;
 
+mod dlt_extended_header_slice;
+pub use dlt_extended_header_slice::*;
+
 mod dlt_header;
 pub use dlt_header::*;
 
@@ -212,15 +260,45 @@ pub use dlt_typed_payload::*;
 mod dlt_message_info;
 pub use dlt_message_info::*;
 
+#[cfg(feature = "alloc")]
+mod dlt_message_builder;
+#[cfg(feature = "alloc")]
+pub use dlt_message_builder::*;
+
+#[cfg(feature = "alloc")]
+mod dlt_packet;
+#[cfg(feature = "alloc")]
+pub use dlt_packet::*;
+
 mod dlt_packet_slice;
 pub use dlt_packet_slice::*;
 
+#[cfg(feature = "alloc")]
+mod field_value;
+#[cfg(feature = "alloc")]
+pub use field_value::*;
+
 mod dlt_slice_iterator;
 pub use dlt_slice_iterator::*;
 
+mod dlt_stream_parser;
+pub use dlt_stream_parser::*;
+
+mod header_len_parts;
+pub use header_len_parts::*;
+
+mod id4;
+pub use id4::*;
+
+mod non_verbose_descriptor;
+pub use non_verbose_descriptor::*;
+
 mod nv_payload;
 pub use nv_payload::*;
 
+mod verbose_message_builder;
+pub use verbose_message_builder::*;
+
 /// Control message related types & functions.
 pub mod control;
 
@@ -230,6 +308,10 @@ pub mod error;
 /// Module containing "verbose DLT" encoding & decoding structs & functions.
 pub mod verbose;
 
+/// Module for decoding network trace payloads & reassembling segmented
+/// network trace messages.
+pub mod nw_trace;
+
 /// Module for decoding .dlt files or other formats that use the DLT storage header.
 pub mod storage;
 
@@ -240,7 +322,11 @@ use core::slice::from_raw_parts;
 #[cfg(feature = "std")]
 use std::io;
 #[cfg(test)]
+mod fuzz_tests;
+#[cfg(test)]
 mod proptest_generators;
+#[cfg(test)]
+mod test_packets;
 
 /// Maximum value that can be encoded in the DLT header version field (has only 3 bits).
 const MAX_VERSION: u8 = 0b111;
@@ -265,6 +351,7 @@ const EXT_MSIN_MSTP_TYPE_CONTROL: u8 = 0x3 << 1;
 
 ///Log level for dlt log messages.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DltLogLevel {
     ///Fatal system error.
     Fatal = 0x1,
@@ -280,9 +367,99 @@ pub enum DltLogLevel {
     Verbose = 0x6,
 }
 
+impl DltLogLevel {
+    /// Parses a log level from its textual name (case insensitive).
+    ///
+    /// Accepted names are "fatal", "error", "warn" or "warning", "info",
+    /// "debug" and "verbose" or "trace". For all other values `None`
+    /// is returned.
+    pub fn from_name(s: &str) -> Option<DltLogLevel> {
+        use DltLogLevel::*;
+
+        const NAMES: [(&str, DltLogLevel); 8] = [
+            ("fatal", Fatal),
+            ("error", Error),
+            ("warn", Warn),
+            ("warning", Warn),
+            ("info", Info),
+            ("debug", Debug),
+            ("verbose", Verbose),
+            ("trace", Verbose),
+        ];
+
+        NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|(_, level)| *level)
+    }
+
+    /// Returns the lower case name of the log level as displayed by
+    /// the DLT Viewer (e.g. "info" or "warn").
+    pub fn name(&self) -> &'static str {
+        use DltLogLevel::*;
+        match self {
+            Fatal => "fatal",
+            Error => "error",
+            Warn => "warn",
+            Info => "info",
+            Debug => "debug",
+            Verbose => "verbose",
+        }
+    }
+}
+
+/// Writes the lower case name of the log level (see [`DltLogLevel::name`]).
+impl core::fmt::Display for DltLogLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a log level from its name (see [`DltLogLevel::from_name`]) or
+/// from its numeric value ("1" for [`DltLogLevel::Fatal`] up to "6" for
+/// [`DltLogLevel::Verbose`]).
+impl core::str::FromStr for DltLogLevel {
+    type Err = error::ParseLogLevelError;
+
+    fn from_str(s: &str) -> Result<DltLogLevel, error::ParseLogLevelError> {
+        use DltLogLevel::*;
+        if let Some(level) = DltLogLevel::from_name(s) {
+            return Ok(level);
+        }
+        match s {
+            "1" => Ok(Fatal),
+            "2" => Ok(Error),
+            "3" => Ok(Warn),
+            "4" => Ok(Info),
+            "5" => Ok(Debug),
+            "6" => Ok(Verbose),
+            _ => Err(error::ParseLogLevelError),
+        }
+    }
+}
+
+/// Converts the raw log level value (`0x1..=0x6`).
+impl TryFrom<u8> for DltLogLevel {
+    type Error = error::RangeError;
+
+    fn try_from(value: u8) -> Result<DltLogLevel, error::RangeError> {
+        use DltLogLevel::*;
+        match value {
+            0x1 => Ok(Fatal),
+            0x2 => Ok(Error),
+            0x3 => Ok(Warn),
+            0x4 => Ok(Info),
+            0x5 => Ok(Debug),
+            0x6 => Ok(Verbose),
+            _ => Err(error::RangeError::LogLevelOutsideOfRange(value)),
+        }
+    }
+}
+
 ///Types of application trace messages that can be sent via dlt if the message type
 ///is specified as "trace".
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DltTraceType {
     ///Value of variable.
     Variable = 0x1,
@@ -296,8 +473,40 @@ pub enum DltTraceType {
     Vfb = 0x5,
 }
 
+/// Writes the trace type as displayed by the DLT Viewer (e.g. "func_in").
+impl core::fmt::Display for DltTraceType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use DltTraceType::*;
+        f.write_str(match self {
+            Variable => "variable",
+            FunctionIn => "func_in",
+            FunctionOut => "func_out",
+            State => "state",
+            Vfb => "vfb",
+        })
+    }
+}
+
+/// Converts the raw trace type value (`0x1..=0x5`).
+impl TryFrom<u8> for DltTraceType {
+    type Error = error::RangeError;
+
+    fn try_from(value: u8) -> Result<DltTraceType, error::RangeError> {
+        use DltTraceType::*;
+        match value {
+            0x1 => Ok(Variable),
+            0x2 => Ok(FunctionIn),
+            0x3 => Ok(FunctionOut),
+            0x4 => Ok(State),
+            0x5 => Ok(Vfb),
+            _ => Err(error::RangeError::TraceTypeOutsideOfRange(value)),
+        }
+    }
+}
+
 ///Network type specified in a network trace dlt message.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DltNetworkType {
     ///Inter-Process-Communication.
     Ipc,
@@ -315,7 +524,73 @@ pub enum DltNetworkType {
     UserDefined(u8),
 }
 
+impl DltNetworkType {
+    /// Converts the 4 bit value of the message type info field into a
+    /// network type.
+    ///
+    /// The values 0x7..=0xf are converted to [`DltNetworkType::UserDefined`],
+    /// for all other unknown values (0 & values bigger then 0xf) `None` is
+    /// returned.
+    pub fn from_value(value: u8) -> Option<DltNetworkType> {
+        use DltNetworkType::*;
+        match value {
+            0x1 => Some(Ipc),
+            0x2 => Some(Can),
+            0x3 => Some(Flexray),
+            0x4 => Some(Most),
+            0x5 => Some(Ethernet),
+            0x6 => Some(SomeIp),
+            0x7..=0xf => Some(UserDefined(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns the 4 bit value of the network type as encoded in the
+    /// message type info field.
+    ///
+    /// An error is returned if the value of a [`DltNetworkType::UserDefined`]
+    /// is outside of the range 0x7..=0xf.
+    pub fn to_value(&self) -> Result<u8, error::RangeError> {
+        use DltNetworkType::*;
+        match *self {
+            Ipc => Ok(0x1),
+            Can => Ok(0x2),
+            Flexray => Ok(0x3),
+            Most => Ok(0x4),
+            Ethernet => Ok(0x5),
+            SomeIp => Ok(0x6),
+            UserDefined(value) => {
+                if (0x7..=0xf).contains(&value) {
+                    Ok(value)
+                } else {
+                    Err(error::RangeError::NetworkTypekUserDefinedOutsideOfRange(
+                        value,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// Writes the network type as displayed by the DLT Viewer (e.g. "can").
+/// User defined values are written as "user_defined(N)".
+impl core::fmt::Display for DltNetworkType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use DltNetworkType::*;
+        match self {
+            Ipc => f.write_str("ipc"),
+            Can => f.write_str("can"),
+            Flexray => f.write_str("flexray"),
+            Most => f.write_str("most"),
+            Ethernet => f.write_str("ethernet"),
+            SomeIp => f.write_str("someip"),
+            UserDefined(value) => write!(f, "user_defined({})", value),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DltControlMessageType {
     ///Request control message.
     Request = 0x1,
@@ -323,8 +598,21 @@ pub enum DltControlMessageType {
     Response = 0x2,
 }
 
+/// Writes the control message type as displayed by the DLT Viewer
+/// ("request" or "response").
+impl core::fmt::Display for DltControlMessageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use DltControlMessageType::*;
+        f.write_str(match self {
+            Request => "request",
+            Response => "response",
+        })
+    }
+}
+
 ///Message type info field (contains the the information of the message type & message type info field)
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DltMessageType {
     ///Dlt log message with a log level
     Log(DltLogLevel),
@@ -336,6 +624,22 @@ pub enum DltMessageType {
     Control(DltControlMessageType),
 }
 
+/// Writes the message type & message type info as displayed in the
+/// "Type" & "Subtype" columns of the DLT Viewer separated by a space
+/// (e.g. "log info", "app_trace func_in", "nw_trace can" or
+/// "control request").
+impl core::fmt::Display for DltMessageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use DltMessageType::*;
+        match self {
+            Log(v) => write!(f, "log {}", v),
+            Trace(v) => write!(f, "app_trace {}", v),
+            NetworkTrace(v) => write!(f, "nw_trace {}", v),
+            Control(v) => write!(f, "control {}", v),
+        }
+    }
+}
+
 impl DltMessageType {
     /// Attempts to read the message type from the first byte of
     /// the dlt extended message header.
@@ -345,31 +649,12 @@ impl DltMessageType {
         const MSIN_MASK: u8 = 0b1111_0000;
 
         match value & 0b0000_1110 {
-            EXT_MSIN_MSTP_TYPE_LOG => {
-                use DltLogLevel::*;
-                match (value & MSIN_MASK) >> 4 {
-                    0x1 => Some(Log(Fatal)),
-                    0x2 => Some(Log(Error)),
-                    0x3 => Some(Log(Warn)),
-                    0x4 => Some(Log(Info)),
-                    0x5 => Some(Log(Debug)),
-                    0x6 => Some(Log(Verbose)),
-                    //undefined values
-                    _ => None,
-                }
-            }
-            EXT_MSIN_MSTP_TYPE_TRACE => {
-                use DltTraceType::*;
-                match (value & MSIN_MASK) >> 4 {
-                    0x1 => Some(Trace(Variable)),
-                    0x2 => Some(Trace(FunctionIn)),
-                    0x3 => Some(Trace(FunctionOut)),
-                    0x4 => Some(Trace(State)),
-                    0x5 => Some(Trace(Vfb)),
-                    //undefined values
-                    _ => None,
-                }
-            }
+            EXT_MSIN_MSTP_TYPE_LOG => DltLogLevel::try_from((value & MSIN_MASK) >> 4)
+                .ok()
+                .map(Log),
+            EXT_MSIN_MSTP_TYPE_TRACE => DltTraceType::try_from((value & MSIN_MASK) >> 4)
+                .ok()
+                .map(Trace),
             EXT_MSIN_MSTP_TYPE_NW_TRACE => {
                 use DltNetworkType::*;
                 match (value & MSIN_MASK) >> 4 {
@@ -398,36 +683,14 @@ impl DltMessageType {
 
     ///Set message type info and based on that the message type.
     pub fn to_byte(&self) -> Result<u8, error::RangeError> {
-        use error::RangeError::NetworkTypekUserDefinedOutsideOfRange;
         use DltMessageType::*;
-        use DltNetworkType::UserDefined;
-
-        //check ranges
-        if let NetworkTrace(UserDefined(user_defined_value)) = *self {
-            if !(7..=0xf).contains(&user_defined_value) {
-                return Err(NetworkTypekUserDefinedOutsideOfRange(user_defined_value));
-            }
-        }
 
         //determine message type & message type info
         let (message_type, message_type_info) = match self {
             Log(ref level) => (EXT_MSIN_MSTP_TYPE_LOG, *level as u8),
             Trace(ref trace_type) => (EXT_MSIN_MSTP_TYPE_TRACE, *trace_type as u8),
             NetworkTrace(ref nw_trace_type) => {
-                use DltNetworkType::*;
-
-                (
-                    EXT_MSIN_MSTP_TYPE_NW_TRACE,
-                    match *nw_trace_type {
-                        Ipc => 0x1,
-                        Can => 0x2,
-                        Flexray => 0x3,
-                        Most => 0x4,
-                        Ethernet => 0x5,
-                        SomeIp => 0x6,
-                        UserDefined(value) => value,
-                    },
-                )
+                (EXT_MSIN_MSTP_TYPE_NW_TRACE, nw_trace_type.to_value()?)
             }
             Control(ref control_msg_type) => (EXT_MSIN_MSTP_TYPE_CONTROL, *control_msg_type as u8),
         };
@@ -480,6 +743,98 @@ mod tests {
                 assert_eq!(v.1, format!("{:?}", v.0));
             }
         }
+
+        #[test]
+        fn try_from() {
+            for value in 0..=u8::MAX {
+                let expected = match value {
+                    1 => Ok(Fatal),
+                    2 => Ok(Error),
+                    3 => Ok(Warn),
+                    4 => Ok(Info),
+                    5 => Ok(Debug),
+                    6 => Ok(Verbose),
+                    _ => Err(error::RangeError::LogLevelOutsideOfRange(value)),
+                };
+                assert_eq!(expected, DltLogLevel::try_from(value));
+            }
+        }
+
+        #[test]
+        fn display() {
+            const VALUES: [(DltLogLevel, &str); 6] = [
+                (Fatal, "fatal"),
+                (Error, "error"),
+                (Warn, "warn"),
+                (Info, "info"),
+                (Debug, "debug"),
+                (Verbose, "verbose"),
+            ];
+            for v in &VALUES {
+                assert_eq!(v.1, format!("{}", v.0));
+                assert_eq!(v.1, v.0.name());
+                assert_eq!(Some(v.0), DltLogLevel::from_name(v.0.name()));
+            }
+        }
+
+        #[test]
+        fn from_name() {
+            const VALUES: [(&str, DltLogLevel); 13] = [
+                ("fatal", Fatal),
+                ("FATAL", Fatal),
+                ("error", Error),
+                ("Error", Error),
+                ("warn", Warn),
+                ("warning", Warn),
+                ("WARNING", Warn),
+                ("info", Info),
+                ("debug", Debug),
+                ("DeBuG", Debug),
+                ("verbose", Verbose),
+                ("trace", Verbose),
+                ("Trace", Verbose),
+            ];
+            for v in &VALUES {
+                assert_eq!(Some(v.1), DltLogLevel::from_name(v.0));
+            }
+
+            // invalid names
+            assert_eq!(None, DltLogLevel::from_name(""));
+            assert_eq!(None, DltLogLevel::from_name("warnings"));
+            assert_eq!(None, DltLogLevel::from_name(" info"));
+            assert_eq!(None, DltLogLevel::from_name("off"));
+        }
+
+        #[test]
+        fn from_str() {
+            const VALUES: [(&str, DltLogLevel); 12] = [
+                ("fatal", Fatal),
+                ("Error", Error),
+                ("WARN", Warn),
+                ("info", Info),
+                ("debug", Debug),
+                ("Verbose", Verbose),
+                ("1", Fatal),
+                ("2", Error),
+                ("3", Warn),
+                ("4", Info),
+                ("5", Debug),
+                ("6", Verbose),
+            ];
+            for v in &VALUES {
+                assert_eq!(Ok(v.1), v.0.parse::<DltLogLevel>());
+            }
+
+            // display output can be parsed back
+            for v in &VALUES {
+                assert_eq!(Ok(v.1), format!("{}", v.1).parse::<DltLogLevel>());
+            }
+
+            // invalid values
+            for s in ["", "0", "7", "01", " 1", "off", "infos"] {
+                assert_eq!(Err(error::ParseLogLevelError), s.parse::<DltLogLevel>());
+            }
+        }
     }
 
     mod dlt_trace_type {
@@ -520,6 +875,35 @@ mod tests {
                 assert_eq!(v.1, format!("{:?}", v.0));
             }
         }
+
+        #[test]
+        fn try_from() {
+            for value in 0..=u8::MAX {
+                let expected = match value {
+                    1 => Ok(Variable),
+                    2 => Ok(FunctionIn),
+                    3 => Ok(FunctionOut),
+                    4 => Ok(State),
+                    5 => Ok(Vfb),
+                    _ => Err(error::RangeError::TraceTypeOutsideOfRange(value)),
+                };
+                assert_eq!(expected, DltTraceType::try_from(value));
+            }
+        }
+
+        #[test]
+        fn display() {
+            const VALUES: [(DltTraceType, &str); 5] = [
+                (Variable, "variable"),
+                (FunctionIn, "func_in"),
+                (FunctionOut, "func_out"),
+                (State, "state"),
+                (Vfb, "vfb"),
+            ];
+            for v in &VALUES {
+                assert_eq!(v.1, format!("{}", v.0));
+            }
+        }
     }
 
     mod dlt_network_type {
@@ -564,6 +948,58 @@ mod tests {
                 assert_eq!(v.1, format!("{:?}", v.0));
             }
         }
+
+        #[test]
+        fn display() {
+            const VALUES: [(DltNetworkType, &str); 8] = [
+                (Ipc, "ipc"),
+                (Can, "can"),
+                (Flexray, "flexray"),
+                (Most, "most"),
+                (Ethernet, "ethernet"),
+                (SomeIp, "someip"),
+                (UserDefined(0x7), "user_defined(7)"),
+                (UserDefined(0xf), "user_defined(15)"),
+            ];
+            for v in &VALUES {
+                assert_eq!(v.1, format!("{}", v.0));
+            }
+        }
+
+        #[test]
+        fn from_value_to_value() {
+            const VALUES: [(DltNetworkType, u8); 6] = [
+                (Ipc, 1),
+                (Can, 2),
+                (Flexray, 3),
+                (Most, 4),
+                (Ethernet, 5),
+                (SomeIp, 6),
+            ];
+            for v in &VALUES {
+                assert_eq!(Some(v.0), DltNetworkType::from_value(v.1));
+                assert_eq!(Ok(v.1), v.0.to_value());
+            }
+
+            // user defined
+            for value in 0x7..=0xf {
+                assert_eq!(Some(UserDefined(value)), DltNetworkType::from_value(value));
+                assert_eq!(Ok(value), UserDefined(value).to_value());
+            }
+
+            // invalid values
+            for value in [0].into_iter().chain(0x10..=u8::MAX) {
+                assert_eq!(None, DltNetworkType::from_value(value));
+            }
+            for value in (0..0x7).chain(0x10..=u8::MAX) {
+                assert_eq!(
+                    Err(error::RangeError::NetworkTypekUserDefinedOutsideOfRange(
+                        value
+                    )),
+                    UserDefined(value).to_value()
+                );
+            }
+        }
     }
 
     mod dlt_control_message_type {
@@ -593,6 +1029,12 @@ mod tests {
                 assert_eq!(v.1, format!("{:?}", v.0));
             }
         }
+
+        #[test]
+        fn display() {
+            assert_eq!("request", format!("{}", Request));
+            assert_eq!("response", format!("{}", Response));
+        }
     }
 
     mod dlt_message_type {
@@ -664,6 +1106,21 @@ mod tests {
             }
         }
 
+        #[test]
+        fn display() {
+            const DISPLAY_VALUES: [(DltMessageType, &str); 6] = [
+                (Log(Info), "log info"),
+                (Log(Warn), "log warn"),
+                (Trace(FunctionIn), "app_trace func_in"),
+                (NetworkTrace(Can), "nw_trace can"),
+                (NetworkTrace(UserDefined(0x7)), "nw_trace user_defined(7)"),
+                (Control(Response), "control response"),
+            ];
+            for v in &DISPLAY_VALUES {
+                assert_eq!(v.1, format!("{}", v.0));
+            }
+        }
+
         #[test]
         fn from_byte() {
             // valid values
@@ -691,6 +1148,16 @@ mod tests {
                 assert!(DltMessageType::from_byte((i << 4) | 0b0011).is_none());
             }
 
+            // network trace type 0 is decoded as user defined
+            assert_eq!(
+                Some(DltMessageType::NetworkTrace(DltNetworkType::UserDefined(0))),
+                DltMessageType::from_byte(0b0000_0100)
+            );
+            assert_eq!(
+                Some(DltMessageType::NetworkTrace(DltNetworkType::UserDefined(0))),
+                DltMessageType::from_byte(0b0000_0101)
+            );
+
             // invalid control
             assert!(DltMessageType::from_byte(0b0000_0110).is_none());
             assert!(DltMessageType::from_byte(0b0000_0111).is_none());
@@ -701,6 +1168,26 @@ mod tests {
             }
         }
 
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trip() {
+            // all valid message types (including all user defined
+            // network types)
+            for byte in 0..=u8::MAX {
+                if let Some(value) = DltMessageType::from_byte(byte) {
+                    let json = serde_json::to_string(&value).unwrap();
+                    assert_eq!(
+                        value,
+                        serde_json::from_str::<DltMessageType>(&json).unwrap()
+                    );
+                }
+            }
+            assert_eq!(
+                r#"{"NetworkTrace":{"UserDefined":15}}"#,
+                serde_json::to_string(&NetworkTrace(UserDefined(15))).unwrap()
+            );
+        }
+
         #[test]
         fn to_byte() {
             // valid values
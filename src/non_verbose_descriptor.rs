@@ -0,0 +1,68 @@
+/// Description source (e.g. a FIBEX file) used to decode the payload of
+/// non verbose messages based on their message id.
+///
+/// The crate does not parse any description formats itself. Implement this
+/// trait for your own lookup structure & use
+/// [`crate::DltPacketSlice::decode_non_verbose`] to decode packets with it.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::{DltHeader, DltPacketSlice, NonVerboseDescriptor};
+///
+/// /// Descriptor that knows a single message (id 1) containing a u16.
+/// struct Descriptor;
+///
+/// impl NonVerboseDescriptor for Descriptor {
+///     type Output<'a> = u16;
+///     type Error = ();
+///
+///     fn decode<'a>(
+///         &self,
+///         message_id: u32,
+///         payload: &'a [u8],
+///         is_big_endian: bool,
+///     ) -> Option<Result<u16, ()>> {
+///         if 1 != message_id {
+///             return None;
+///         }
+///         Some(match payload {
+///             [a, b] if is_big_endian => Ok(u16::from_be_bytes([*a, *b])),
+///             [a, b] => Ok(u16::from_le_bytes([*a, *b])),
+///             _ => Err(()),
+///         })
+///     }
+/// }
+///
+/// let mut header = DltHeader {
+///     is_big_endian: true,
+///     ..Default::default()
+/// };
+/// header.length = header.header_len() + 6;
+/// let mut bytes = header.to_bytes().to_vec();
+/// bytes.extend_from_slice(&[0, 0, 0, 1, 0x12, 0x34]);
+///
+/// let packet = DltPacketSlice::from_slice(&bytes).unwrap();
+/// assert_eq!(Some(Ok(0x1234)), packet.decode_non_verbose(&Descriptor));
+/// ```
+pub trait NonVerboseDescriptor {
+    /// Decoded representation of a non verbose message (can borrow the
+    /// payload data).
+    type Output<'a>;
+
+    /// Error returned if the payload does not match the description.
+    type Error;
+
+    /// Decodes the payload of the non verbose message with the given
+    /// message id.
+    ///
+    /// `payload` contains the data following the 4 byte message id &
+    /// `is_big_endian` the endianness of the message payload. `None`
+    /// should be returned if no description exists for the message id.
+    fn decode<'a>(
+        &self,
+        message_id: u32,
+        payload: &'a [u8],
+        is_big_endian: bool,
+    ) -> Option<Result<Self::Output<'a>, Self::Error>>;
+}
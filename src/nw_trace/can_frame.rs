@@ -0,0 +1,257 @@
+use crate::error::{CanFrameError, Layer, UnexpectedEndOfSliceError};
+use crate::{DltNetworkType, NetworkNvPayload};
+
+/// CAN frame contained in the payload of a non verbose CAN network trace
+/// message (`NetworkTrace(Can)`).
+///
+/// The payload is expected to start with the CAN id (4 bytes, encoded
+/// with the endianness of the DLT message) followed by the DLC (1 byte)
+/// & the frame data. The CAN id uses the SocketCAN layout, bit 31 marks
+/// an extended (29 bit) id. The length of the frame data has to match
+/// the length defined by the DLC (see [`CanFrame::dlc_to_len`]).
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::nw_trace::CanFrame;
+///
+/// let payload = [0x00, 0x00, 0x01, 0x23, 2, 0xab, 0xcd];
+/// let frame = CanFrame::from_slice(&payload, true).unwrap();
+/// assert_eq!(0x123, frame.id());
+/// assert_eq!(false, frame.is_extended_id());
+/// assert_eq!(2, frame.dlc());
+/// assert_eq!(&[0xab, 0xcd], frame.data());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CanFrame<'a> {
+    raw_id: u32,
+    dlc: u8,
+    data: &'a [u8],
+}
+
+impl<'a> CanFrame<'a> {
+    /// Length of the CAN id & DLC at the start of the payload.
+    pub const HEADER_LEN: usize = 5;
+
+    /// Flag in the raw id marking an extended (29 bit) CAN id.
+    pub const EXTENDED_ID_FLAG: u32 = 0x8000_0000;
+
+    /// Returns the data length defined by the given DLC (`0..=8` for
+    /// classic CAN frames & up to 64 for CAN FD frames) or `None` if the
+    /// DLC is bigger then 15.
+    pub fn dlc_to_len(dlc: u8) -> Option<usize> {
+        match dlc {
+            0..=8 => Some(usize::from(dlc)),
+            9 => Some(12),
+            10 => Some(16),
+            11 => Some(20),
+            12 => Some(24),
+            13 => Some(32),
+            14 => Some(48),
+            15 => Some(64),
+            _ => None,
+        }
+    }
+
+    /// Decodes a CAN frame from the given network trace payload.
+    pub fn from_slice(slice: &'a [u8], is_big_endian: bool) -> Result<CanFrame<'a>, CanFrameError> {
+        if slice.len() < Self::HEADER_LEN {
+            return Err(CanFrameError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::CanFrame,
+                    minimum_size: Self::HEADER_LEN,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        let dlc = slice[4];
+        let data = &slice[Self::HEADER_LEN..];
+        if Self::dlc_to_len(dlc) != Some(data.len()) {
+            return Err(CanFrameError::DataLenMismatch {
+                dlc,
+                actual_len: data.len(),
+            });
+        }
+        let id_bytes = [slice[0], slice[1], slice[2], slice[3]];
+        Ok(CanFrame {
+            raw_id: if is_big_endian {
+                u32::from_be_bytes(id_bytes)
+            } else {
+                u32::from_le_bytes(id_bytes)
+            },
+            dlc,
+            data,
+        })
+    }
+
+    /// Decodes a CAN frame from a non verbose network trace payload.
+    ///
+    /// Returns `Ok(None)` if the payload is not a CAN network trace.
+    pub fn from_nv_payload(
+        payload: &NetworkNvPayload<'a>,
+        is_big_endian: bool,
+    ) -> Result<Option<CanFrame<'a>>, CanFrameError> {
+        if payload.net_type == DltNetworkType::Can {
+            Self::from_slice(payload.payload, is_big_endian).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// CAN id (without the extended id flag).
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.raw_id & !Self::EXTENDED_ID_FLAG
+    }
+
+    /// CAN id as encoded in the payload (including the extended id flag).
+    #[inline]
+    pub fn raw_id(&self) -> u32 {
+        self.raw_id
+    }
+
+    /// Returns true if the CAN id is an extended (29 bit) id.
+    #[inline]
+    pub fn is_extended_id(&self) -> bool {
+        0 != self.raw_id & Self::EXTENDED_ID_FLAG
+    }
+
+    /// Data length code of the frame.
+    #[inline]
+    pub fn dlc(&self) -> u8 {
+        self.dlc
+    }
+
+    /// Frame data (all bytes after the DLC).
+    #[inline]
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    fn can_payload(raw_id: u32, dlc: u8, data: &[u8], is_big_endian: bool) -> Vec<u8> {
+        let mut payload = Vec::new();
+        if is_big_endian {
+            payload.extend_from_slice(&raw_id.to_be_bytes());
+        } else {
+            payload.extend_from_slice(&raw_id.to_le_bytes());
+        }
+        payload.push(dlc);
+        payload.extend_from_slice(data);
+        payload
+    }
+
+    #[test]
+    fn dlc_to_len() {
+        for dlc in 0..=8 {
+            assert_eq!(Some(usize::from(dlc)), CanFrame::dlc_to_len(dlc));
+        }
+        for (dlc, len) in [
+            (9, 12),
+            (10, 16),
+            (11, 20),
+            (12, 24),
+            (13, 32),
+            (14, 48),
+            (15, 64),
+        ] {
+            assert_eq!(Some(len), CanFrame::dlc_to_len(dlc));
+        }
+        for dlc in 16..=u8::MAX {
+            assert_eq!(None, CanFrame::dlc_to_len(dlc));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            raw_id in any::<u32>(),
+            dlc in 0..=15u8,
+            data_source in proptest::collection::vec(any::<u8>(), 65),
+            is_big_endian in any::<bool>(),
+        ) {
+            let data = &data_source[..CanFrame::dlc_to_len(dlc).unwrap()];
+            let payload = can_payload(raw_id, dlc, data, is_big_endian);
+
+            // ok
+            {
+                let frame = CanFrame::from_slice(&payload, is_big_endian).unwrap();
+                prop_assert_eq!(raw_id, frame.raw_id());
+                prop_assert_eq!(raw_id & 0x7fff_ffff, frame.id());
+                prop_assert_eq!(0 != raw_id & 0x8000_0000, frame.is_extended_id());
+                prop_assert_eq!(dlc, frame.dlc());
+                prop_assert_eq!(data, frame.data());
+            }
+
+            // length error
+            for len in 0..CanFrame::HEADER_LEN {
+                prop_assert_eq!(
+                    Err(CanFrameError::UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                        layer: Layer::CanFrame,
+                        minimum_size: CanFrame::HEADER_LEN,
+                        actual_size: len,
+                    })),
+                    CanFrame::from_slice(&payload[..len], is_big_endian)
+                );
+            }
+
+            // data length not matching the dlc
+            {
+                let longer = &data_source[..data.len() + 1];
+                let longer_payload = can_payload(raw_id, dlc, longer, is_big_endian);
+                prop_assert_eq!(
+                    Err(CanFrameError::DataLenMismatch {
+                        dlc,
+                        actual_len: longer.len(),
+                    }),
+                    CanFrame::from_slice(&longer_payload, is_big_endian)
+                );
+                if false == data.is_empty() {
+                    prop_assert_eq!(
+                        Err(CanFrameError::DataLenMismatch {
+                            dlc,
+                            actual_len: data.len() - 1,
+                        }),
+                        CanFrame::from_slice(&payload[..payload.len() - 1], is_big_endian)
+                    );
+                }
+            }
+
+            // dlc without a defined length
+            for invalid_dlc in [16, u8::MAX] {
+                let invalid_payload = can_payload(raw_id, invalid_dlc, data, is_big_endian);
+                prop_assert_eq!(
+                    Err(CanFrameError::DataLenMismatch {
+                        dlc: invalid_dlc,
+                        actual_len: data.len(),
+                    }),
+                    CanFrame::from_slice(&invalid_payload, is_big_endian)
+                );
+            }
+
+            // from non verbose payload
+            {
+                let nv = NetworkNvPayload {
+                    net_type: DltNetworkType::Can,
+                    msg_id: 1,
+                    payload: &payload,
+                };
+                prop_assert_eq!(
+                    Ok(Some(CanFrame::from_slice(&payload, is_big_endian).unwrap())),
+                    CanFrame::from_nv_payload(&nv, is_big_endian)
+                );
+                let other = NetworkNvPayload {
+                    net_type: DltNetworkType::Flexray,
+                    ..nv
+                };
+                prop_assert_eq!(Ok(None), CanFrame::from_nv_payload(&other, is_big_endian));
+            }
+        }
+    }
+}
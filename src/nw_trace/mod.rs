@@ -0,0 +1,17 @@
+mod can_frame;
+pub use can_frame::*;
+
+#[cfg(feature = "alloc")]
+mod segmented_nw_trace;
+#[cfg(feature = "alloc")]
+pub use segmented_nw_trace::*;
+
+/// Marker (first argument) of the message starting a segmented network trace.
+pub const NW_TRACE_START: &str = "NWST";
+
+/// Marker (first argument) of a message containing a segment of a
+/// segmented network trace.
+pub const NW_TRACE_SEGMENT: &str = "NWCH";
+
+/// Marker (first argument) of the message ending a segmented network trace.
+pub const NW_TRACE_END: &str = "NWEN";
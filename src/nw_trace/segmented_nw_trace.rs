@@ -0,0 +1,645 @@
+use super::{NW_TRACE_END, NW_TRACE_SEGMENT, NW_TRACE_START};
+use crate::error::NwTraceReassemblyError;
+use crate::verbose::{VerboseIter, VerboseValue};
+use crate::{DltMessageType, DltNetworkType, DltPacketSlice};
+use alloc::vec::Vec;
+
+/// Network trace message reassembled from the messages of a segmented
+/// network trace (see [`reassemble_segmented`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SegmentedNwTrace {
+    /// Network type of the start message.
+    pub network_type: DltNetworkType,
+    /// Stream handle identifying the messages of the segmented trace.
+    pub handle: u32,
+    /// Header of the traced network message (e.g. the SOME/IP header).
+    pub header: Vec<u8>,
+    /// Concatenated data of all segments.
+    pub payload: Vec<u8>,
+}
+
+/// Reassembles a network trace message that was split into multiple DLT
+/// messages because it exceeded the maximum DLT message length.
+///
+/// Segmented network traces consist of verbose network trace messages
+/// with the following arguments:
+///
+/// * Start ([`super::NW_TRACE_START`]): marker, stream handle, header,
+///   payload length, segment count & segment length
+/// * Segment ([`super::NW_TRACE_SEGMENT`]): marker, stream handle,
+///   sequence number & segment data
+/// * End ([`super::NW_TRACE_END`]): marker & stream handle
+///
+/// The packets are expected to be from the same session. Packets before
+/// the start message (including segment & end messages), packets that
+/// are not part of a segmented network trace & segments of other streams
+/// are skipped. This includes malformed segmentation messages as long as
+/// they are not known to belong to the stream. The reassembly stops at
+/// the end message of the stream.
+///
+/// The reassembled payload is checked against the payload length, the
+/// segment count & the segment length announced in the start message
+/// (all segments except the last one have to have the announced segment
+/// length). If the payload length is a multiple of the segment length,
+/// one segment less than announced is accepted (dlt-daemon announces
+/// `payload_len / segment_len + 1` segments but does not send the empty
+/// trailing segment).
+pub fn reassemble_segmented<'a, I>(packets: I) -> Result<SegmentedNwTrace, NwTraceReassemblyError>
+where
+    I: IntoIterator<Item = DltPacketSlice<'a>>,
+{
+    use NwTraceReassemblyError::*;
+
+    let mut packets = packets.into_iter();
+
+    // search the start message
+    let (mut result, payload_len, segment_count, segment_len) = loop {
+        let packet = packets.next().ok_or(MissingStart)?;
+        // malformed messages before the start are skipped
+        if let Ok(Some(Segment::Start {
+            network_type,
+            handle,
+            header,
+            payload_len,
+            segment_count,
+            segment_len,
+        })) = Segment::from_packet(&packet)
+        {
+            break (
+                SegmentedNwTrace {
+                    network_type,
+                    handle,
+                    header: header.to_vec(),
+                    payload: Vec::new(),
+                },
+                payload_len,
+                segment_count,
+                segment_len,
+            );
+        }
+    };
+
+    // collect the segments
+    let mut next_sequence = 0u16;
+    let mut num_segments = 0usize;
+    for packet in packets {
+        let segment = match Segment::from_packet(&packet) {
+            Ok(value) => value,
+            // only malformed messages of the stream are treated as error
+            Err(err) if Segment::handle(&packet) == Some(result.handle) => return Err(err),
+            Err(_) => continue,
+        };
+        match segment {
+            Some(Segment::Chunk {
+                handle,
+                sequence,
+                data,
+            }) if handle == result.handle => {
+                if sequence != next_sequence {
+                    return Err(UnexpectedSequenceNumber {
+                        expected: next_sequence,
+                        actual: sequence,
+                    });
+                }
+                next_sequence = next_sequence.wrapping_add(1);
+                num_segments += 1;
+
+                // only the last segment is allowed to be shorter
+                let is_last = num_segments as u64 >= segment_count;
+                if num_segments as u64 > segment_count {
+                    return Err(SegmentCountMismatch {
+                        expected: segment_count,
+                        actual: num_segments,
+                    });
+                }
+                if data.len() as u64 > segment_len
+                    || (false == is_last && data.len() as u64 != segment_len)
+                {
+                    return Err(SegmentLenMismatch {
+                        sequence,
+                        expected: segment_len,
+                        actual: data.len(),
+                    });
+                }
+                result.payload.extend_from_slice(data);
+            }
+            Some(Segment::End { handle }) if handle == result.handle => {
+                if payload_len != result.payload.len() as u64 {
+                    return Err(PayloadLenMismatch {
+                        expected: payload_len,
+                        actual: result.payload.len(),
+                    });
+                }
+                // the empty trailing segment is allowed to be missing
+                let skipped_empty_segment = segment_len > 0
+                    && 0 == payload_len % segment_len
+                    && segment_count == num_segments as u64 + 1;
+                if segment_count != num_segments as u64 && false == skipped_empty_segment {
+                    return Err(SegmentCountMismatch {
+                        expected: segment_count,
+                        actual: num_segments,
+                    });
+                }
+                return Ok(result);
+            }
+            // packets of other streams
+            _ => {}
+        }
+    }
+    Err(MissingEnd)
+}
+
+/// Decoded message of a segmented network trace.
+enum Segment<'a> {
+    Start {
+        network_type: DltNetworkType,
+        handle: u32,
+        header: &'a [u8],
+        payload_len: u64,
+        segment_count: u64,
+        segment_len: u64,
+    },
+    Chunk {
+        handle: u32,
+        sequence: u16,
+        data: &'a [u8],
+    },
+    End {
+        handle: u32,
+    },
+}
+
+impl<'a> Segment<'a> {
+    /// Decodes the packet if it is a message of a segmented network trace
+    /// (otherwise `None` is returned).
+    fn from_packet(
+        packet: &DltPacketSlice<'a>,
+    ) -> Result<Option<Segment<'a>>, NwTraceReassemblyError> {
+        let Some((network_type, mut iter, marker)) = Segment::marker(packet) else {
+            return Ok(None);
+        };
+
+        if NW_TRACE_START.as_bytes() == marker {
+            let handle = read_handle(&mut iter, NW_TRACE_START)?;
+            let header = read_raw(&mut iter, NW_TRACE_START)?;
+            let payload_len = read_uint(&mut iter, NW_TRACE_START)?;
+            let segment_count = read_uint(&mut iter, NW_TRACE_START)?;
+            let segment_len = read_uint(&mut iter, NW_TRACE_START)?;
+            Ok(Some(Segment::Start {
+                network_type,
+                handle,
+                header,
+                payload_len,
+                segment_count,
+                segment_len,
+            }))
+        } else if NW_TRACE_SEGMENT.as_bytes() == marker {
+            let handle = read_handle(&mut iter, NW_TRACE_SEGMENT)?;
+            let sequence = u16::try_from(read_uint(&mut iter, NW_TRACE_SEGMENT)?)
+                .map_err(|_| NwTraceReassemblyError::InvalidArguments(NW_TRACE_SEGMENT))?;
+            let data = read_raw(&mut iter, NW_TRACE_SEGMENT)?;
+            Ok(Some(Segment::Chunk {
+                handle,
+                sequence,
+                data,
+            }))
+        } else if NW_TRACE_END.as_bytes() == marker {
+            let handle = read_handle(&mut iter, NW_TRACE_END)?;
+            Ok(Some(Segment::End { handle }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the network type, the marker & an iterator over the
+    /// remaining arguments if the packet is a verbose network trace
+    /// message starting with a string argument.
+    fn marker(
+        packet: &DltPacketSlice<'a>,
+    ) -> Option<(DltNetworkType, VerboseIter<'a>, &'a [u8])> {
+        let network_type = match packet.message_type() {
+            Some(DltMessageType::NetworkTrace(value)) => value,
+            _ => return None,
+        };
+        let mut iter = packet.verbose_value_iter()?;
+        match iter.next() {
+            Some(Ok(VerboseValue::Str(value))) => Some((network_type, iter, value.as_bytes())),
+            _ => None,
+        }
+    }
+
+    /// Returns the stream handle of a segmentation message if it can be
+    /// decoded (independent of the validity of the other arguments).
+    fn handle(packet: &DltPacketSlice<'a>) -> Option<u32> {
+        let (_, mut iter, marker) = Segment::marker(packet)?;
+        let marker = [NW_TRACE_START, NW_TRACE_SEGMENT, NW_TRACE_END]
+            .into_iter()
+            .find(|m| m.as_bytes() == marker)?;
+        read_handle(&mut iter, marker).ok()
+    }
+}
+
+/// Reads the next argument of a segmentation message.
+fn read_value<'a>(
+    iter: &mut VerboseIter<'a>,
+    marker: &'static str,
+) -> Result<VerboseValue<'a>, NwTraceReassemblyError> {
+    Ok(iter
+        .next()
+        .ok_or(NwTraceReassemblyError::InvalidArguments(marker))??)
+}
+
+/// Reads the next argument as unsigned integer.
+fn read_uint(
+    iter: &mut VerboseIter<'_>,
+    marker: &'static str,
+) -> Result<u64, NwTraceReassemblyError> {
+    read_value(iter, marker)?
+        .as_u64()
+        .ok_or(NwTraceReassemblyError::InvalidArguments(marker))
+}
+
+/// Reads the next argument as stream handle.
+fn read_handle(
+    iter: &mut VerboseIter<'_>,
+    marker: &'static str,
+) -> Result<u32, NwTraceReassemblyError> {
+    u32::try_from(read_uint(iter, marker)?)
+        .map_err(|_| NwTraceReassemblyError::InvalidArguments(marker))
+}
+
+/// Reads the next argument as raw data.
+fn read_raw<'a>(
+    iter: &mut VerboseIter<'a>,
+    marker: &'static str,
+) -> Result<&'a [u8], NwTraceReassemblyError> {
+    match read_value(iter, marker)? {
+        VerboseValue::Raw(value) => Ok(value.data),
+        _ => Err(NwTraceReassemblyError::InvalidArguments(marker)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verbose::{RawValue, StringValue, U16Value, U32Value};
+    use crate::{DltExtendedHeader, DltHeader, DltLogLevel, DltMessageBuilder};
+    use alloc::vec;
+
+    fn str_value(value: &str) -> VerboseValue<'_> {
+        VerboseValue::Str(StringValue::new(None, value))
+    }
+
+    fn u16_value(value: u16) -> VerboseValue<'static> {
+        VerboseValue::U16(U16Value {
+            variable_info: None,
+            scaling: None,
+            value,
+        })
+    }
+
+    fn u32_value(value: u32) -> VerboseValue<'static> {
+        VerboseValue::U32(U32Value {
+            variable_info: None,
+            scaling: None,
+            value,
+        })
+    }
+
+    fn raw_value(data: &[u8]) -> VerboseValue<'_> {
+        VerboseValue::Raw(RawValue { name: None, data })
+    }
+
+    /// Serializes a message with the given type & arguments.
+    fn message(message_type: DltMessageType, values: &[VerboseValue]) -> Vec<u8> {
+        let header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: Some(1),
+            timestamp: None,
+            extended_header: None,
+        };
+        let extended_header =
+            DltExtendedHeader::new_verbose(message_type, 0, *b"APP1", *b"CTX1").unwrap();
+        let mut builder = DltMessageBuilder::new(header, extended_header);
+        for value in values {
+            builder.add_value(value.clone());
+        }
+        builder.build().unwrap()
+    }
+
+    fn nw_message(values: &[VerboseValue]) -> Vec<u8> {
+        message(DltMessageType::NetworkTrace(DltNetworkType::SomeIp), values)
+    }
+
+    /// Start message announcing a segment length of 4 bytes.
+    fn start(handle: u32, payload_len: u32, segment_count: u16) -> Vec<u8> {
+        nw_message(&[
+            str_value(NW_TRACE_START),
+            u32_value(handle),
+            raw_value(&[1, 2, 3, 4]),
+            u32_value(payload_len),
+            u16_value(segment_count),
+            u16_value(4),
+        ])
+    }
+
+    fn segment(handle: u32, sequence: u16, data: &[u8]) -> Vec<u8> {
+        nw_message(&[
+            str_value(NW_TRACE_SEGMENT),
+            u32_value(handle),
+            u16_value(sequence),
+            raw_value(data),
+        ])
+    }
+
+    fn end(handle: u32) -> Vec<u8> {
+        nw_message(&[str_value(NW_TRACE_END), u32_value(handle)])
+    }
+
+    fn reassemble(messages: &[Vec<u8>]) -> Result<SegmentedNwTrace, NwTraceReassemblyError> {
+        reassemble_segmented(
+            messages
+                .iter()
+                .map(|m| DltPacketSlice::from_slice(m).unwrap()),
+        )
+    }
+
+    #[test]
+    fn reassemble_ok() {
+        let expected = SegmentedNwTrace {
+            network_type: DltNetworkType::SomeIp,
+            handle: 12,
+            header: vec![1, 2, 3, 4],
+            payload: vec![5, 6, 7, 8, 9, 10],
+        };
+
+        // only segmentation messages
+        assert_eq!(
+            Ok(expected.clone()),
+            reassemble(&[
+                start(12, 6, 2),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9, 10]),
+                end(12),
+            ])
+        );
+
+        // other messages & streams in between
+        assert_eq!(
+            Ok(expected),
+            reassemble(&[
+                message(
+                    DltMessageType::Log(DltLogLevel::Info),
+                    &[str_value(NW_TRACE_START)]
+                ),
+                nw_message(&[str_value("other")]),
+                segment(12, 0, &[0, 0, 0, 0]),
+                end(12),
+                start(12, 6, 2),
+                segment(13, 0, &[0, 0]),
+                segment(12, 0, &[5, 6, 7, 8]),
+                nw_message(&[u32_value(1)]),
+                end(13),
+                segment(12, 1, &[9, 10]),
+                end(12),
+                segment(12, 2, &[11]),
+            ])
+        );
+    }
+
+    #[test]
+    fn reassemble_payload_len_multiple_of_segment_len() {
+        let expected = SegmentedNwTrace {
+            network_type: DltNetworkType::SomeIp,
+            handle: 12,
+            header: vec![1, 2, 3, 4],
+            payload: vec![5, 6, 7, 8, 9, 10, 11, 12],
+        };
+
+        // empty trailing segment announced but not sent
+        assert_eq!(
+            Ok(expected.clone()),
+            reassemble(&[
+                start(12, 8, 3),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9, 10, 11, 12]),
+                end(12),
+            ])
+        );
+
+        // empty trailing segment sent
+        assert_eq!(
+            Ok(expected.clone()),
+            reassemble(&[
+                start(12, 8, 3),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9, 10, 11, 12]),
+                segment(12, 2, &[]),
+                end(12),
+            ])
+        );
+
+        // exact segment count
+        assert_eq!(
+            Ok(expected),
+            reassemble(&[
+                start(12, 8, 2),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9, 10, 11, 12]),
+                end(12),
+            ])
+        );
+    }
+
+    #[test]
+    fn reassemble_errors() {
+        use NwTraceReassemblyError::*;
+
+        // missing start
+        assert_eq!(Err(MissingStart), reassemble(&[]));
+        assert_eq!(
+            Err(MissingStart),
+            reassemble(&[segment(12, 0, &[5, 6]), end(12)])
+        );
+        assert_eq!(Err(MissingStart), reassemble(&[end(12)]));
+
+        // missing end
+        assert_eq!(
+            Err(MissingEnd),
+            reassemble(&[start(12, 2, 1), segment(12, 0, &[5, 6])])
+        );
+
+        // segment count mismatch
+        assert_eq!(
+            Err(SegmentCountMismatch {
+                expected: 4,
+                actual: 2
+            }),
+            reassemble(&[
+                start(12, 8, 4),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9, 10, 11, 12]),
+                end(12)
+            ])
+        );
+        assert_eq!(
+            Err(SegmentCountMismatch {
+                expected: 2,
+                actual: 0
+            }),
+            reassemble(&[start(12, 0, 2), end(12)])
+        );
+        assert_eq!(
+            Err(SegmentCountMismatch {
+                expected: 1,
+                actual: 2
+            }),
+            reassemble(&[
+                start(12, 4, 1),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 1, &[9]),
+                end(12)
+            ])
+        );
+
+        // segment length mismatch
+        assert_eq!(
+            Err(SegmentLenMismatch {
+                sequence: 0,
+                expected: 4,
+                actual: 2
+            }),
+            reassemble(&[
+                start(12, 6, 2),
+                segment(12, 0, &[5, 6]),
+                segment(12, 1, &[7, 8, 9, 10]),
+                end(12)
+            ])
+        );
+        assert_eq!(
+            Err(SegmentLenMismatch {
+                sequence: 0,
+                expected: 4,
+                actual: 5
+            }),
+            reassemble(&[start(12, 5, 1), segment(12, 0, &[5, 6, 7, 8, 9]), end(12)])
+        );
+
+        // bad sequence number
+        assert_eq!(
+            Err(UnexpectedSequenceNumber {
+                expected: 1,
+                actual: 2
+            }),
+            reassemble(&[
+                start(12, 8, 2),
+                segment(12, 0, &[5, 6, 7, 8]),
+                segment(12, 2, &[9, 10, 11, 12]),
+                end(12)
+            ])
+        );
+
+        // payload length mismatch
+        assert_eq!(
+            Err(PayloadLenMismatch {
+                expected: 3,
+                actual: 2
+            }),
+            reassemble(&[start(12, 3, 1), segment(12, 0, &[5, 6]), end(12)])
+        );
+
+        // invalid arguments
+        assert_eq!(
+            Err(InvalidArguments(NW_TRACE_SEGMENT)),
+            reassemble(&[
+                start(12, 2, 1),
+                nw_message(&[
+                    str_value(NW_TRACE_SEGMENT),
+                    u32_value(12),
+                    u32_value(0x1_0000),
+                    raw_value(&[5, 6]),
+                ])
+            ])
+        );
+        assert_eq!(
+            Err(InvalidArguments(NW_TRACE_SEGMENT)),
+            reassemble(&[
+                start(12, 2, 1),
+                nw_message(&[
+                    str_value(NW_TRACE_SEGMENT),
+                    u32_value(12),
+                    u16_value(0),
+                    u16_value(0)
+                ])
+            ])
+        );
+
+        // decoding error
+        {
+            let mut bad_segment = segment(12, 0, &[5, 6]);
+            let len = bad_segment.len();
+            // invalid type info of the segment data
+            bad_segment[len - 8] = 0xff;
+            bad_segment[len - 7] = 0xff;
+            assert_matches!(
+                reassemble(&[start(12, 2, 1), bad_segment, end(12)]),
+                Err(Decode(_))
+            );
+        }
+    }
+
+    #[test]
+    fn reassemble_skip_malformed() {
+        // malformed messages before the start
+        let bad_start = nw_message(&[str_value(NW_TRACE_START), u32_value(12)]);
+        let bad_start_handle = nw_message(&[
+            str_value(NW_TRACE_START),
+            raw_value(&[]),
+            raw_value(&[]),
+            u32_value(0),
+        ]);
+        let bad_segment = nw_message(&[
+            str_value(NW_TRACE_SEGMENT),
+            u32_value(12),
+            u32_value(0x1_0000),
+        ]);
+        let mut bad_end = end(12);
+        let len = bad_end.len();
+        // invalid type info of the stream handle
+        bad_end[len - 8] = 0xff;
+        bad_end[len - 7] = 0xff;
+        assert_eq!(
+            Err(NwTraceReassemblyError::MissingStart),
+            reassemble(&[
+                bad_start.clone(),
+                bad_start_handle.clone(),
+                bad_segment.clone(),
+                bad_end.clone(),
+            ])
+        );
+
+        // malformed messages of other streams & without a stream handle
+        assert_eq!(
+            Ok(SegmentedNwTrace {
+                network_type: DltNetworkType::SomeIp,
+                handle: 13,
+                header: vec![1, 2, 3, 4],
+                payload: vec![5, 6],
+            }),
+            reassemble(&[
+                bad_start,
+                bad_segment.clone(),
+                start(13, 2, 1),
+                bad_start_handle,
+                bad_segment,
+                bad_end,
+                nw_message(&[str_value(NW_TRACE_END)]),
+                segment(13, 0, &[5, 6]),
+                end(13),
+            ])
+        );
+    }
+}
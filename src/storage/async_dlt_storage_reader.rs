@@ -0,0 +1,300 @@
+#[cfg(not(test))]
+use std::vec::Vec;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+use crate::error::ReadError;
+use crate::storage::{DecodeStep, StoragePacket, StorageRecordDecoder};
+use crate::*;
+
+/// Asynchronous reader to parse a dlt storage file based on
+/// [`tokio::io::AsyncBufRead`] (requires the `tokio` feature).
+///
+/// Works like [`super::DltStorageReader`] but returns owned packets, so
+/// they can be held across `.await` points.
+///
+/// Note that [`AsyncDltStorageReader::next_packet`] is NOT cancel safe
+/// (see its documentation).
+///
+/// # Example
+/// ```no_run
+/// # async fn example() {
+/// use tokio::{fs::File, io::BufReader};
+/// use dlt_parse::storage::AsyncDltStorageReader;
+///
+/// let dlt_file = File::open("dummy.dlt").await.expect("failed to open file");
+/// let mut reader = AsyncDltStorageReader::new(BufReader::new(dlt_file));
+///
+/// while let Some(msg_result) = reader.next_packet().await {
+///     let msg = msg_result.expect("failed to parse dlt packet");
+///
+///     // the storage header contains the ecu id and the timestamp
+///     println!("{:?}", msg.storage_header);
+///
+///     // the dlt packet
+///     println!("{:?}", msg.packet);
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncDltStorageReader<R: AsyncBufRead + Unpin> {
+    reader: R,
+    decoder: StorageRecordDecoder,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncDltStorageReader<R> {
+    /// Creates a new reader.
+    pub fn new(reader: R) -> AsyncDltStorageReader<R> {
+        AsyncDltStorageReader {
+            reader,
+            decoder: StorageRecordDecoder::new(true),
+        }
+    }
+
+    /// Creates a new reader that does not allow corrupted data
+    /// and does NOT seek to the next storage pattern whenever
+    /// corrupted data is encountered.
+    pub fn new_strict(reader: R) -> AsyncDltStorageReader<R> {
+        AsyncDltStorageReader {
+            reader,
+            decoder: StorageRecordDecoder::new(false),
+        }
+    }
+
+    /// Returns if the reader will seek storage headers if corrupted
+    /// data is present between packets.
+    #[inline]
+    pub fn is_seeking_storage_pattern(&self) -> bool {
+        self.decoder.is_seeking_storage_pattern
+    }
+
+    /// Returns the number of DLT packets read.
+    #[inline]
+    pub fn num_read_packets(&self) -> usize {
+        self.decoder.num_read_packets
+    }
+
+    /// Returns the number of times corrupt data was encountered and the
+    /// next "storage pattern" ([`crate::storage::StorageHeader::PATTERN_AT_START`])
+    /// had to be searched in the data stream.
+    #[inline]
+    pub fn num_pattern_seeks(&self) -> usize {
+        self.decoder.num_pattern_seeks
+    }
+
+    /// Returns the number of bytes consumed from the underlying reader.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.decoder.offset
+    }
+
+    /// Returns the byte offset (relative to the start of the underlying
+    /// reader) of the storage header of the last packet returned by
+    /// [`AsyncDltStorageReader::next_packet`].
+    ///
+    /// If `next_packet` returned an error, the offset of the record that
+    /// failed to decode is returned instead.
+    #[inline]
+    pub fn last_record_offset(&self) -> u64 {
+        self.decoder.record_offset
+    }
+
+    /// Returns the next DLT packet.
+    ///
+    /// The byte offset of a record that failed to decode is available via
+    /// [`AsyncDltStorageReader::last_record_offset`].
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is NOT cancel safe. The data of a partially read record
+    /// is stored in the returned future. If the future is dropped before it
+    /// completes (e.g. when it loses a `tokio::select!` race) the bytes
+    /// already consumed from the underlying reader are lost and the next
+    /// call starts decoding in the middle of a record. A strict reader then
+    /// returns an error, a seeking reader skips data up to the next storage
+    /// header. Only drop the future if the reader is not used afterwards.
+    pub async fn next_packet(&mut self) -> Option<Result<StoragePacket, ReadError>> {
+        let mut data = Vec::new();
+        let mut step = self.decoder.start(&mut data);
+        let storage_header = loop {
+            step = match step {
+                DecodeStep::FillBuf => match self.reader.fill_buf().await {
+                    Ok(slice) => {
+                        let (consumed_len, step) = self.decoder.buffered(slice);
+                        self.reader.consume(consumed_len);
+                        step
+                    }
+                    Err(err) => self.decoder.io_error(err),
+                },
+                DecodeStep::Read(len) => {
+                    let start = data.len();
+                    data.resize(start + len, 0);
+                    match self.reader.read_exact(&mut data[start..]).await {
+                        Ok(_) => self.decoder.read(&mut data),
+                        Err(err) => self.decoder.io_error(err),
+                    }
+                }
+                DecodeStep::Done(result) => match result? {
+                    Ok(storage_header) => break storage_header,
+                    Err(err) => return Some(Err(err)),
+                },
+            };
+        };
+
+        match DltPacketSlice::from_slice(&data) {
+            Ok(packet) => Some(Ok(StoragePacket {
+                storage_header,
+                packet: DltPacket::from(&packet),
+            })),
+            Err(err) => Some(Err(self.decoder.record_error(err.into()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod async_dlt_storage_reader_tests {
+    use super::*;
+    use crate::error::UnsupportedDltVersionError;
+    use crate::storage::StorageHeader;
+    use crate::test_packets::{self, packet};
+    use std::format;
+    use std::vec::Vec;
+
+    fn storage_header(ecu_id: [u8; 4]) -> StorageHeader {
+        StorageHeader {
+            ecu_id,
+            ..test_packets::storage_header(1)
+        }
+    }
+
+    fn expected(storage_header: StorageHeader, packet: &[u8]) -> StoragePacket {
+        StoragePacket {
+            storage_header,
+            packet: DltPacket::from(&DltPacketSlice::from_slice(packet).unwrap()),
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let r = AsyncDltStorageReader::new(&[][..]);
+        assert!(false == format!("{:?}", r).is_empty());
+    }
+
+    #[tokio::test]
+    async fn next_packet() {
+        let packet0 = packet(1, &[1, 2, 3, 4]);
+        let packet1 = packet(2, &[10, 11, 12, 13, 14, 15]);
+
+        let mut v = Vec::new();
+        v.extend_from_slice(&storage_header(*b"ECU0").to_bytes());
+        v.extend_from_slice(&packet0);
+        v.extend_from_slice(&storage_header(*b"ECU1").to_bytes());
+        v.extend_from_slice(&packet1);
+
+        // empty reader
+        for mut r in [
+            AsyncDltStorageReader::new(&[][..]),
+            AsyncDltStorageReader::new_strict(&[][..]),
+        ] {
+            assert!(r.next_packet().await.is_none());
+            assert_eq!(0, r.num_read_packets());
+            assert_eq!(0, r.num_pattern_seeks());
+        }
+
+        // valid packets
+        for mut reader in [
+            AsyncDltStorageReader::new(&v[..]),
+            AsyncDltStorageReader::new_strict(&v[..]),
+        ] {
+            assert_eq!(
+                expected(storage_header(*b"ECU0"), &packet0),
+                reader.next_packet().await.unwrap().unwrap()
+            );
+            assert_eq!(1, reader.num_read_packets());
+            assert_eq!((16 + packet0.len()) as u64, reader.offset());
+            assert_eq!(
+                expected(storage_header(*b"ECU1"), &packet1),
+                reader.next_packet().await.unwrap().unwrap()
+            );
+            assert_eq!(2, reader.num_read_packets());
+            assert_eq!(v.len() as u64, reader.offset());
+            assert!(reader.next_packet().await.is_none());
+            assert_eq!(0, reader.num_pattern_seeks());
+        }
+
+        // seeking reader skips corrupted data
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(&[0, 0, 0]);
+            data.extend_from_slice(&storage_header(*b"ECU0").to_bytes());
+            // packet with unsupported version
+            data.extend_from_slice(&[0b1110_0000, 0, 0, 4]);
+            data.extend_from_slice(&v);
+            data.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+
+            let mut reader = AsyncDltStorageReader::new(&data[..]);
+            assert!(reader.is_seeking_storage_pattern());
+            assert_eq!(
+                expected(storage_header(*b"ECU0"), &packet0),
+                reader.next_packet().await.unwrap().unwrap()
+            );
+            assert_eq!(
+                expected(storage_header(*b"ECU1"), &packet1),
+                reader.next_packet().await.unwrap().unwrap()
+            );
+            assert!(reader.next_packet().await.is_none());
+            assert!(reader.next_packet().await.is_none());
+            assert_eq!(2, reader.num_read_packets());
+            assert!(reader.num_pattern_seeks() > 0);
+        }
+
+        // strict reader errors
+        {
+            // missing storage pattern
+            let mut reader = AsyncDltStorageReader::new_strict(&v[1..]);
+            assert!(false == reader.is_seeking_storage_pattern());
+            assert_matches!(
+                reader.next_packet().await,
+                Some(Err(ReadError::StorageHeaderStartPattern(_)))
+            );
+            assert_eq!(0, reader.last_record_offset());
+            assert!(reader.next_packet().await.is_none());
+
+            // unsupported version
+            let mut data = Vec::from(&storage_header(*b"ECU0").to_bytes()[..]);
+            data.extend_from_slice(&[0b1110_0000, 0, 0, 4]);
+            let mut reader = AsyncDltStorageReader::new_strict(&data[..]);
+            assert_matches!(
+                reader.next_packet().await,
+                Some(Err(ReadError::UnsupportedDltVersion(
+                    UnsupportedDltVersionError {
+                        unsupported_version: 7
+                    }
+                )))
+            );
+            assert!(reader.next_packet().await.is_none());
+
+            // length too small
+            let mut data = Vec::from(&storage_header(*b"ECU0").to_bytes()[..]);
+            data.extend_from_slice(&[0b0010_0000, 0, 0, 3]);
+            let mut reader = AsyncDltStorageReader::new_strict(&data[..]);
+            assert_matches!(
+                reader.next_packet().await,
+                Some(Err(ReadError::DltMessageLengthTooSmall(_)))
+            );
+
+            // packet cut off
+            let mut reader = AsyncDltStorageReader::new_strict(&v[..v.len() - 1]);
+            assert!(reader.next_packet().await.unwrap().is_ok());
+            assert_matches!(reader.next_packet().await, Some(Err(ReadError::IoError(_))));
+            assert_eq!((16 + packet0.len()) as u64, reader.last_record_offset());
+            assert!(reader.next_packet().await.is_none());
+        }
+
+        // seeking reader with cut off packet
+        {
+            let mut reader = AsyncDltStorageReader::new(&v[..v.len() - 1]);
+            assert!(reader.next_packet().await.unwrap().is_ok());
+            assert!(reader.next_packet().await.is_none());
+        }
+    }
+}
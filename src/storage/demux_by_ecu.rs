@@ -0,0 +1,151 @@
+use std::io::{BufReader, Read, Write};
+use std::vec::Vec;
+
+use crate::error::ReadError;
+
+use super::DltStorageReader;
+
+/// Reads all DLT packets from a storage file (.dlt) & writes each of them
+/// (including the storage header) to the writer of its ECU.
+///
+/// The ECU id of the DLT header is used if present. Otherwise the ECU id
+/// of the storage header is used. Writers are created on demand via
+/// `make_writer` when the first packet of an ECU is encountered & are
+/// returned together with their ECU id (in the order they were created).
+///
+/// # Example
+/// ```no_run
+/// use std::{fs::File, io::BufWriter};
+/// use dlt_parse::storage::demux_by_ecu;
+///
+/// let dlt_file = File::open("combined.dlt").expect("failed to open file");
+/// demux_by_ecu(dlt_file, |ecu_id| {
+///     let name = format!("{}.dlt", String::from_utf8_lossy(&ecu_id));
+///     BufWriter::new(File::create(name).expect("failed to create file"))
+/// })
+/// .expect("failed to split dlt file");
+/// ```
+pub fn demux_by_ecu<R, W, F>(reader: R, mut make_writer: F) -> Result<Vec<([u8; 4], W)>, ReadError>
+where
+    R: Read,
+    W: Write,
+    F: FnMut([u8; 4]) -> W,
+{
+    let mut writers: Vec<([u8; 4], W)> = Vec::new();
+    let mut reader = DltStorageReader::new(BufReader::new(reader));
+
+    while let Some(msg) = reader.next_packet() {
+        let msg = msg?;
+        let ecu_id = msg
+            .packet
+            .header()
+            .ecu_id
+            .unwrap_or(msg.storage_header.ecu_id);
+
+        let index = match writers.iter().position(|(id, _)| *id == ecu_id) {
+            Some(index) => index,
+            None => {
+                writers.push((ecu_id, make_writer(ecu_id)));
+                writers.len() - 1
+            }
+        };
+        let writer = &mut writers[index].1;
+        msg.storage_header.write(writer)?;
+        writer.write_all(msg.packet.slice())?;
+    }
+
+    Ok(writers)
+}
+
+#[cfg(test)]
+mod demux_by_ecu_tests {
+    use super::*;
+    use crate::storage::StorageHeader;
+    use crate::test_packets;
+    use crate::*;
+    use std::io::Cursor;
+
+    fn packet(buffer: &mut Vec<u8>, storage_ecu_id: [u8; 4], ecu_id: Option<[u8; 4]>, counter: u8) {
+        let storage_header = StorageHeader {
+            ecu_id: storage_ecu_id,
+            ..test_packets::storage_header(1)
+        };
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: counter,
+            length: 0,
+            ecu_id,
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        header.length = header.header_len() + 4;
+        buffer.extend_from_slice(&storage_header.to_bytes());
+        buffer.extend_from_slice(&header.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn demux_by_ecu() {
+        // two ecus
+        {
+            let mut ecu1 = Vec::new();
+            let mut ecu2 = Vec::new();
+            let mut input = Vec::new();
+            for (storage_ecu_id, ecu_id, counter) in [
+                (*b"ECU1", None, 0),
+                (*b"ECU2", None, 1),
+                (*b"ECU1", None, 2),
+                // dlt header ecu id has priority over the storage header
+                (*b"ECU1", Some(*b"ECU2"), 3),
+            ] {
+                packet(&mut input, storage_ecu_id, ecu_id, counter);
+                if Some(*b"ECU2") == ecu_id || *b"ECU2" == storage_ecu_id {
+                    packet(&mut ecu2, storage_ecu_id, ecu_id, counter);
+                } else {
+                    packet(&mut ecu1, storage_ecu_id, ecu_id, counter);
+                }
+            }
+
+            let mut created = Vec::new();
+            let writers = super::demux_by_ecu(Cursor::new(&input), |ecu_id| {
+                created.push(ecu_id);
+                Vec::new()
+            })
+            .unwrap();
+
+            assert_eq!(created, [*b"ECU1", *b"ECU2"]);
+            assert_eq!(writers, [(*b"ECU1", ecu1), (*b"ECU2", ecu2)]);
+        }
+
+        // empty input
+        {
+            let writers =
+                super::demux_by_ecu(Cursor::new(&[]), |_| -> Vec<u8> { unreachable!() }).unwrap();
+            assert!(writers.is_empty());
+        }
+
+        // write error
+        {
+            #[derive(Debug)]
+            struct FailingWriter {}
+
+            impl Write for FailingWriter {
+                fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                    Err(std::io::Error::new(std::io::ErrorKind::Other, ""))
+                }
+
+                fn flush(&mut self) -> std::io::Result<()> {
+                    Ok(())
+                }
+            }
+
+            let mut input = Vec::new();
+            packet(&mut input, *b"ECU1", None, 0);
+            assert_matches!(
+                super::demux_by_ecu(Cursor::new(&input), |_| FailingWriter {}),
+                Err(ReadError::IoError(_))
+            );
+        }
+    }
+}
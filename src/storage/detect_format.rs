@@ -0,0 +1,130 @@
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+use crate::DltPacketSlice;
+
+use super::StorageHeader;
+
+/// Format of a file containing DLT messages.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DltFileFormat {
+    /// Each DLT message is preceded by a storage header (starting
+    /// with [`StorageHeader::PATTERN_AT_START`]).
+    Storage,
+    /// The data starts directly with a DLT header (no storage headers).
+    RawDlt,
+    /// The data neither starts with a storage header nor with a valid
+    /// DLT header.
+    Unknown,
+}
+
+/// Detects if the data in the reader starts with a storage header or
+/// directly with a DLT header.
+///
+/// Only the first 4 bytes are inspected. The stream position is
+/// restored to the position it had before the call.
+///
+/// # Example
+/// ```no_run
+/// use std::{fs::File, io::BufReader};
+/// use dlt_parse::storage::{detect_format, DltFileFormat, DltStorageReader};
+///
+/// let mut dlt_file = File::open("log.dlt").expect("failed to open file");
+/// match detect_format(&mut dlt_file).expect("failed to read file") {
+///     DltFileFormat::Storage => {
+///         let mut reader = DltStorageReader::new(BufReader::new(dlt_file));
+///         // ...
+///     }
+///     DltFileFormat::RawDlt => {
+///         // read the file & use dlt_parse::SliceIterator
+///     }
+///     DltFileFormat::Unknown => println!("not a dlt file"),
+/// }
+/// ```
+pub fn detect_format<R: Read + Seek>(reader: &mut R) -> Result<DltFileFormat, std::io::Error> {
+    let start = reader.stream_position()?;
+
+    // read the start (the file can also be smaller than 4 bytes)
+    let mut start_bytes = [0u8; 4];
+    let mut len = 0;
+    while len < start_bytes.len() {
+        match reader.read(&mut start_bytes[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    reader.seek(SeekFrom::Start(start))?;
+
+    let start_bytes = &start_bytes[..len];
+    Ok(if start_bytes == StorageHeader::PATTERN_AT_START {
+        DltFileFormat::Storage
+    } else if let Ok(Some(_)) = DltPacketSlice::peek_len(start_bytes) {
+        DltFileFormat::RawDlt
+    } else {
+        DltFileFormat::Unknown
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_packets::{packet, storage_entry, storage_header};
+    use std::io::Cursor;
+    use std::{format, vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = DltFileFormat::Storage;
+        assert_eq!(v, v.clone());
+        assert_eq!("Storage", format!("{:?}", v));
+    }
+
+    #[test]
+    fn detect_format() {
+        // storage file
+        {
+            let data = storage_entry(&storage_header(1), &packet(0, &[1, 2, 3, 4]));
+            let mut cursor = Cursor::new(&data[..]);
+            assert_eq!(
+                DltFileFormat::Storage,
+                super::detect_format(&mut cursor).unwrap()
+            );
+            assert_eq!(0, cursor.position());
+        }
+
+        // raw dlt file
+        {
+            let data = packet(0, &[1, 2, 3, 4]);
+            let mut cursor = Cursor::new(&data[..]);
+            assert_eq!(
+                DltFileFormat::RawDlt,
+                super::detect_format(&mut cursor).unwrap()
+            );
+            assert_eq!(0, cursor.position());
+        }
+
+        // non dlt files
+        for data in [&b"hello world"[..], &[0u8; 8][..], &b"DLT"[..], &[][..]] {
+            let mut cursor = Cursor::new(data);
+            assert_eq!(
+                DltFileFormat::Unknown,
+                super::detect_format(&mut cursor).unwrap()
+            );
+            assert_eq!(0, cursor.position());
+        }
+
+        // position is restored to a non zero start
+        {
+            let mut data = vec![0xff, 0xff];
+            data.extend_from_slice(&packet(0, &[1, 2, 3, 4]));
+            let mut cursor = Cursor::new(&data[..]);
+            cursor.set_position(2);
+            assert_eq!(
+                DltFileFormat::RawDlt,
+                super::detect_format(&mut cursor).unwrap()
+            );
+            assert_eq!(2, cursor.position());
+        }
+    }
+}
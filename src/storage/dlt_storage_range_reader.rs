@@ -0,0 +1,178 @@
+use std::io::{BufRead, Read};
+
+use crate::error::ReadError;
+use crate::storage::DltStorageReader;
+use crate::*;
+
+use super::StorageSlice;
+
+/// Reader that only returns the DLT packets of a [`DltStorageReader`]
+/// that were stored in a given time range (see
+/// [`DltStorageReader::messages_between`]).
+///
+/// Packets before the range are skipped & the iteration stops as soon
+/// as the first packet with a `timestamp_seconds` bigger than the end
+/// of the range is encountered.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct DltStorageRangeReader<'a, R: Read + BufRead> {
+    reader: &'a mut DltStorageReader<R>,
+    start_secs: u32,
+    end_secs: u32,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: Read + BufRead> DltStorageRangeReader<'a, R> {
+    /// Creates a new reader returning only the packets with a storage
+    /// header `timestamp_seconds` in the range `start_secs..=end_secs`.
+    pub fn new(
+        reader: &'a mut DltStorageReader<R>,
+        start_secs: u32,
+        end_secs: u32,
+    ) -> DltStorageRangeReader<'a, R> {
+        DltStorageRangeReader {
+            reader,
+            start_secs,
+            end_secs,
+            done: false,
+        }
+    }
+
+    /// Returns the start of the time range in seconds.
+    #[inline]
+    pub fn start_secs(&self) -> u32 {
+        self.start_secs
+    }
+
+    /// Returns the end of the time range in seconds (inclusive).
+    #[inline]
+    pub fn end_secs(&self) -> u32 {
+        self.end_secs
+    }
+
+    /// Returns the next DLT packet in the time range.
+    pub fn next_packet(&mut self) -> Option<Result<StorageSlice<'_>, ReadError>> {
+        if self.done {
+            return None;
+        }
+
+        // skip all packets before the start of the range (only the
+        // storage header is needed for this, so the packets are not parsed)
+        let storage_header = loop {
+            match self.reader.next_record() {
+                None => {
+                    self.done = true;
+                    return None;
+                }
+                Some(Err(err)) => return Some(Err(err)),
+                Some(Ok(storage_header)) => {
+                    if storage_header.timestamp_seconds >= self.start_secs {
+                        break storage_header;
+                    }
+                }
+            }
+        };
+
+        // stop once the end of the range has been passed
+        if storage_header.timestamp_seconds > self.end_secs {
+            self.done = true;
+            return None;
+        }
+
+        Some(self.reader.last_packet_slice(storage_header))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod dlt_storage_range_reader_tests {
+    use super::*;
+    use crate::storage::StorageHeader;
+    use crate::test_packets::{packet, storage_entry, storage_header};
+    use std::format;
+    use std::io::{BufReader, Cursor};
+    use std::vec::Vec;
+
+    fn storage_data(seconds: &[u32]) -> Vec<u8> {
+        let mut v = Vec::new();
+        for (index, secs) in seconds.iter().enumerate() {
+            v.extend_from_slice(&storage_entry(
+                &storage_header(*secs),
+                &packet(index as u8, &[1, 2, 3, 4]),
+            ));
+        }
+        v
+    }
+
+    #[test]
+    fn debug() {
+        let mut r = DltStorageReader::new(BufReader::new(Cursor::new(&[])));
+        let r = DltStorageRangeReader::new(&mut r, 1, 2);
+        assert!(false == format!("{:?}", r).is_empty());
+    }
+
+    #[test]
+    fn new() {
+        let mut r = DltStorageReader::new(BufReader::new(Cursor::new(&[])));
+        let r = DltStorageRangeReader::new(&mut r, 1, 2);
+        assert_eq!(1, r.start_secs());
+        assert_eq!(2, r.end_secs());
+    }
+
+    #[test]
+    fn next_packet() {
+        // range in the middle of the recording
+        {
+            let v = storage_data(&[1, 1, 2, 3, 3, 4, 5, 6]);
+            let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&v[..])));
+            let mut range = reader.messages_between(2, 4);
+
+            let mut result = Vec::new();
+            while let Some(slice) = range.next_packet() {
+                let slice = slice.unwrap();
+                result.push((
+                    slice.storage_header.timestamp_seconds,
+                    slice.packet.header().message_counter,
+                ));
+            }
+            assert_eq!(result, [(2, 2), (3, 3), (3, 4), (4, 5)]);
+
+            // the iteration stays finished
+            assert!(range.next_packet().is_none());
+
+            // the packet after the end of the range was consumed
+            assert_eq!(7, reader.num_read_packets());
+        }
+
+        // range after the recording
+        {
+            let v = storage_data(&[1, 2, 3]);
+            let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&v[..])));
+            let mut range = reader.messages_between(10, 20);
+            assert!(range.next_packet().is_none());
+            assert!(range.next_packet().is_none());
+        }
+
+        // range before the recording
+        {
+            let v = storage_data(&[5, 6]);
+            let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&v[..])));
+            let mut range = reader.messages_between(1, 4);
+            assert!(range.next_packet().is_none());
+            assert_eq!(1, reader.num_read_packets());
+        }
+
+        // error pass through
+        {
+            let mut v = storage_data(&[1]);
+            v.extend_from_slice(&[0u8; StorageHeader::BYTE_LEN]);
+            let mut reader = DltStorageReader::new_strict(BufReader::new(Cursor::new(&v[..])));
+            let mut range = reader.messages_between(2, 3);
+            assert_matches!(
+                range.next_packet(),
+                Some(Err(ReadError::StorageHeaderStartPattern(_)))
+            );
+        }
+    }
+}
@@ -1,12 +1,12 @@
-use std::io::{BufRead, ErrorKind, Read};
+use std::io::{BufRead, Read, Seek, SeekFrom};
 #[cfg(not(test))]
 use std::vec::Vec;
 
-use crate::error::{DltMessageLengthTooSmallError, ReadError, UnsupportedDltVersionError};
+use crate::error::ReadError;
 use crate::storage::StorageHeader;
 use crate::*;
 
-use super::StorageSlice;
+use super::{DecodeStep, DltStorageRangeReader, StorageRecordDecoder, StorageSlice};
 
 /// Reader to parse a dlt storage file.
 ///
@@ -33,12 +33,8 @@ use super::StorageSlice;
 #[derive(Debug)]
 pub struct DltStorageReader<R: Read + BufRead> {
     reader: R,
-    /// Continue search for next storage header if it is missing.
-    is_seeking_storage_pattern: bool,
+    decoder: StorageRecordDecoder,
     last_packet: Vec<u8>,
-    read_error: bool,
-    num_read_packets: usize,
-    num_pattern_seeks: usize,
 }
 
 #[cfg(feature = "std")]
@@ -47,11 +43,8 @@ impl<R: Read + BufRead> DltStorageReader<R> {
     pub fn new(reader: R) -> DltStorageReader<R> {
         DltStorageReader {
             reader,
-            is_seeking_storage_pattern: true,
+            decoder: StorageRecordDecoder::new(true),
             last_packet: Vec::with_capacity(u16::MAX as usize),
-            read_error: false,
-            num_read_packets: 0,
-            num_pattern_seeks: 0,
         }
     }
 
@@ -61,11 +54,8 @@ impl<R: Read + BufRead> DltStorageReader<R> {
     pub fn new_strict(reader: R) -> DltStorageReader<R> {
         DltStorageReader {
             reader,
-            is_seeking_storage_pattern: false,
+            decoder: StorageRecordDecoder::new(false),
             last_packet: Vec::with_capacity(u16::MAX as usize),
-            read_error: false,
-            num_read_packets: 0,
-            num_pattern_seeks: 0,
         }
     }
 
@@ -73,13 +63,13 @@ impl<R: Read + BufRead> DltStorageReader<R> {
     /// data is present between packets.
     #[inline]
     pub fn is_seeking_storage_pattern(&self) -> bool {
-        self.is_seeking_storage_pattern
+        self.decoder.is_seeking_storage_pattern
     }
 
     /// Returns the number of DLT packets read.
     #[inline]
     pub fn num_read_packets(&self) -> usize {
-        self.num_read_packets
+        self.decoder.num_read_packets
     }
 
     /// Returns the number of times corrupt data was encountered and the
@@ -87,243 +77,237 @@ impl<R: Read + BufRead> DltStorageReader<R> {
     /// had to be searched in the data stream.
     #[inline]
     pub fn num_pattern_seeks(&self) -> usize {
-        self.num_pattern_seeks
+        self.decoder.num_pattern_seeks
     }
 
-    /// Returns the next DLT packet.
-    pub fn next_packet(&mut self) -> Option<Result<StorageSlice<'_>, ReadError>> {
-        // check if iteration is done based as
-        if self.read_error {
-            return None;
-        }
-
-        // goto & read storage header
-        if false == self.is_seeking_storage_pattern {
-            // check if there is data left in the reader
-            match self.reader.fill_buf() {
-                Ok(slice) => {
-                    if slice.is_empty() {
-                        return None;
-                    }
-                }
-                Err(err) => {
-                    self.read_error = true;
-                    return Some(Err(err.into()));
-                }
-            }
-
-            // in the non seeking version a storage header is expected to be directly present
-            let mut storage_header_data = [0u8; StorageHeader::BYTE_LEN];
-            if let Err(err) = self.reader.read_exact(&mut storage_header_data) {
-                self.read_error = true;
-                return Some(Err(err.into()));
-            }
-            let storage_header = match StorageHeader::from_bytes(storage_header_data) {
-                Ok(value) => value,
-                Err(err) => {
-                    self.read_error = true;
-                    return Some(Err(err.into()));
-                }
-            };
-
-            // read the start
-            let mut header_start = [0u8; 4];
-            if let Err(err) = self.reader.read_exact(&mut header_start) {
-                self.read_error = true;
-                return Some(Err(err.into()));
-            }
+    /// Returns the number of bytes consumed from the underlying reader.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.decoder.offset
+    }
 
-            // check version
-            let version = (header_start[0] >> 5) & MAX_VERSION;
-            if 0 != version && 1 != version {
-                self.read_error = true;
-                return Some(Err(ReadError::UnsupportedDltVersion(
-                    UnsupportedDltVersionError {
-                        unsupported_version: version,
-                    },
-                )));
-            }
+    /// Returns the byte offset (relative to the start of the underlying
+    /// reader) of the storage header of the last packet returned by
+    /// [`DltStorageReader::next_packet`].
+    ///
+    /// If `next_packet` returned an error, the offset of the record that
+    /// failed to decode is returned instead. This can be used to locate
+    /// corrupted data in a file.
+    #[inline]
+    pub fn last_record_offset(&self) -> u64 {
+        self.decoder.record_offset
+    }
 
-            // check length to be at least 4
-            let length = u16::from_be_bytes([header_start[2], header_start[3]]) as usize;
-            if length < 4 {
-                self.read_error = true;
-                return Some(Err(ReadError::DltMessageLengthTooSmall(
-                    DltMessageLengthTooSmallError {
-                        required_length: 4,
-                        actual_length: length,
-                    },
-                )));
-            }
+    /// Returns a reader that only returns the DLT packets with a storage
+    /// header `timestamp_seconds` in the range `start_secs..=end_secs`.
+    ///
+    /// Packets before `start_secs` are skipped and the iteration stops at the
+    /// first packet with a `timestamp_seconds` bigger than `end_secs`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let dlt_file = "dummy.dlt";
+    /// use std::{fs::File, io::BufReader};
+    /// use dlt_parse::storage::DltStorageReader;
+    ///
+    /// let dlt_file = File::open(dlt_file).expect("failed to open file");
+    /// let mut reader = DltStorageReader::new(BufReader::new(dlt_file));
+    /// let mut range = reader.messages_between(1_700_000_000, 1_700_000_010);
+    ///
+    /// while let Some(msg_result) = range.next_packet() {
+    ///     let msg = msg_result.expect("failed to parse dlt packet");
+    ///     println!("{:?}", msg.packet);
+    /// }
+    /// ```
+    pub fn messages_between(
+        &mut self,
+        start_secs: u32,
+        end_secs: u32,
+    ) -> DltStorageRangeReader<'_, R> {
+        DltStorageRangeReader::new(self, start_secs, end_secs)
+    }
 
-            // read the complete packet
-            self.last_packet.clear();
-            self.last_packet.reserve(length);
-            self.last_packet.extend_from_slice(&header_start);
-            if length > 4 {
-                self.last_packet.resize(length, 0);
-                if let Err(err) = self.reader.read_exact(&mut self.last_packet[4..]) {
-                    self.read_error = true;
-                    return Some(Err(err.into()));
-                }
-            }
+    /// Reads the next record into the internal packet buffer & returns
+    /// its storage header without parsing the DLT packet (use
+    /// [`DltStorageReader::last_packet_slice`] to parse it).
+    pub(crate) fn next_record(&mut self) -> Option<Result<StorageHeader, ReadError>> {
+        let mut buf = core::mem::take(&mut self.last_packet);
+        let result = self.read_record(&mut buf);
+        self.last_packet = buf;
+        result
+    }
 
-            let packet = match DltPacketSlice::from_slice(&self.last_packet) {
-                Ok(packet) => packet,
-                Err(err) => {
-                    self.read_error = true;
-                    return Some(Err(err.into()));
-                }
-            };
+    /// Parses the packet of the last record read by
+    /// [`DltStorageReader::next_record`].
+    pub(crate) fn last_packet_slice(
+        &mut self,
+        storage_header: StorageHeader,
+    ) -> Result<StorageSlice<'_>, ReadError> {
+        match DltPacketSlice::from_slice(&self.last_packet) {
+            Ok(packet) => Ok(StorageSlice {
+                storage_header,
+                packet,
+            }),
+            Err(err) => Err(self.decoder.record_error(err.into())),
+        }
+    }
 
-            // packet successfully read
-            self.num_read_packets += 1;
+    /// Returns the next DLT packet.
+    ///
+    /// The packet data is read into a buffer owned by the reader (no
+    /// allocation per packet), use [`DltStorageReader::read_into`] if the
+    /// packet should outlive the next call to the reader.
+    ///
+    /// The byte offset of a record that failed to decode is available via
+    /// [`DltStorageReader::last_record_offset`].
+    pub fn next_packet(&mut self) -> Option<Result<StorageSlice<'_>, ReadError>> {
+        match self.next_record()? {
+            Ok(storage_header) => Some(self.last_packet_slice(storage_header)),
+            Err(err) => Some(Err(err)),
+        }
+    }
 
-            Some(Ok(StorageSlice {
+    /// Reads the next DLT packet into the given buffer & returns a
+    /// slice borrowing it.
+    ///
+    /// The buffer is cleared before the packet is read, so the same
+    /// buffer can be reused for all packets of a file. In contrast to
+    /// [`DltStorageReader::next_packet`] the returned slice does not
+    /// borrow the reader.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let dlt_file = "dummy.dlt";
+    /// use std::{fs::File, io::BufReader};
+    /// use dlt_parse::storage::DltStorageReader;
+    ///
+    /// let dlt_file = File::open(dlt_file).expect("failed to open file");
+    /// let mut reader = DltStorageReader::new(BufReader::new(dlt_file));
+    /// let mut buf = Vec::with_capacity(u16::MAX as usize);
+    ///
+    /// while let Some(msg_result) = reader.read_into(&mut buf) {
+    ///     let msg = msg_result.expect("failed to parse dlt packet");
+    ///     println!("{:?} {:?}", msg.storage_header, msg.packet);
+    /// }
+    /// ```
+    pub fn read_into<'b>(
+        &mut self,
+        buf: &'b mut Vec<u8>,
+    ) -> Option<Result<StorageSlice<'b>, ReadError>> {
+        let storage_header = match self.read_record(buf)? {
+            Ok(value) => value,
+            Err(err) => return Some(Err(err)),
+        };
+        let buf: &'b Vec<u8> = buf;
+        match DltPacketSlice::from_slice(buf) {
+            Ok(packet) => Some(Ok(StorageSlice {
                 storage_header,
                 packet,
-            }))
-        } else {
-            loop {
-                // seek the next storage header pattern
-                let mut pattern_elements_found = 0;
-                let mut storage_pattern_error = false;
-                while pattern_elements_found < StorageHeader::PATTERN_AT_START.len() {
-                    // load data
-                    let slice = match self.reader.fill_buf() {
-                        Ok(slice) => {
-                            if slice.is_empty() {
-                                self.read_error = true;
-                                return None;
-                            }
-                            slice
-                        }
-                        Err(err) => {
-                            self.read_error = true;
-                            return Some(Err(err.into()));
-                        }
-                    };
-
-                    // check for the pattern
-                    let mut consumed_len = 0;
-                    for d in slice {
-                        if *d == StorageHeader::PATTERN_AT_START[pattern_elements_found] {
-                            pattern_elements_found += 1;
-                        } else {
-                            storage_pattern_error = true;
-                            pattern_elements_found = 0;
-                        }
-                        consumed_len += 1;
-                        if pattern_elements_found >= StorageHeader::PATTERN_AT_START.len() {
-                            break;
-                        }
-                    }
-                    self.reader.consume(consumed_len);
-                }
-                if storage_pattern_error {
-                    self.num_pattern_seeks += 1;
-                }
+            })),
+            Err(err) => Some(Err(self.decoder.record_error(err.into()))),
+        }
+    }
 
-                // read the rest of the storage header
-                let mut bytes =
-                    [0u8; StorageHeader::BYTE_LEN - StorageHeader::PATTERN_AT_START.len()];
-                if let Err(err) = self.reader.read_exact(&mut bytes) {
-                    self.read_error = true;
-                    if err.kind() == ErrorKind::UnexpectedEof {
-                        return None;
-                    } else {
-                        return Some(Err(err.into()));
+    /// Reads the next record (storage header & DLT packet) & writes the
+    /// packet data into `buf` (the packet itself is not parsed).
+    fn read_record(&mut self, buf: &mut Vec<u8>) -> Option<Result<StorageHeader, ReadError>> {
+        let mut step = self.decoder.start(buf);
+        loop {
+            step = match step {
+                DecodeStep::FillBuf => match self.reader.fill_buf() {
+                    Ok(slice) => {
+                        let (consumed_len, step) = self.decoder.buffered(slice);
+                        self.reader.consume(consumed_len);
+                        step
                     }
-                }
-
-                let storage_header = StorageHeader {
-                    timestamp_seconds: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
-                    timestamp_microseconds: u32::from_le_bytes([
-                        bytes[4], bytes[5], bytes[6], bytes[7],
-                    ]),
-                    ecu_id: [bytes[8], bytes[9], bytes[10], bytes[11]],
-                };
-
-                // read the start
-                let mut header_start = [0u8; 4];
-                if let Err(err) = self.reader.read_exact(&mut header_start) {
-                    self.read_error = true;
-                    if err.kind() == ErrorKind::UnexpectedEof {
-                        return None;
-                    } else {
-                        return Some(Err(err.into()));
+                    Err(err) => self.decoder.io_error(err),
+                },
+                DecodeStep::Read(len) => {
+                    let start = buf.len();
+                    buf.resize(start + len, 0);
+                    match self.reader.read_exact(&mut buf[start..]) {
+                        Ok(()) => self.decoder.read(buf),
+                        Err(err) => self.decoder.io_error(err),
                     }
                 }
+                DecodeStep::Done(result) => return result,
+            };
+        }
+    }
+}
 
-                // check version
-                let version = (header_start[0] >> 5) & MAX_VERSION;
-                if 0 != version && 1 != version {
-                    continue;
-                }
-
-                // calculate the minimum size based on the header flags
-                let header_len = if 0 != header_start[0] & ECU_ID_FLAG {
-                    4 + 4
-                } else {
-                    4
-                };
-
-                let header_len = if 0 != header_start[0] & SESSION_ID_FLAG {
-                    header_len + 4
-                } else {
-                    header_len
-                };
-
-                let header_len = if 0 != header_start[0] & TIMESTAMP_FLAG {
-                    header_len + 4
-                } else {
-                    header_len
-                };
-
-                let header_len = if 0 != header_start[0] & EXTDENDED_HEADER_FLAG {
-                    header_len + 10
-                } else {
-                    header_len
-                };
-
-                // check length to be at least 4
-                let length = u16::from_be_bytes([header_start[2], header_start[3]]) as usize;
-                if length < header_len {
-                    continue;
-                }
-
-                // read the complete packet
-                self.last_packet.clear();
-                self.last_packet.reserve(length);
-                self.last_packet.extend_from_slice(&header_start);
-                if length > 4 {
-                    self.last_packet.resize(length, 0);
-                    if let Err(err) = self.reader.read_exact(&mut self.last_packet[4..]) {
-                        self.read_error = true;
-                        if err.kind() == ErrorKind::UnexpectedEof {
-                            return None;
-                        } else {
-                            return Some(Err(err.into()));
-                        }
-                    }
-                }
+#[cfg(feature = "std")]
+impl<R: Read + BufRead + Seek> DltStorageReader<R> {
+    /// Repositions the underlying reader to the given byte offset (e.g. an
+    /// offset returned by [`DltStorageReader::last_record_offset`] or
+    /// [`DltStorageReader::build_index`]).
+    ///
+    /// The next call to [`DltStorageReader::next_packet`] continues reading
+    /// at the given offset, even if a previous read failed. Offsets are
+    /// only valid as seek positions if the underlying reader was at the
+    /// start of the data when the [`DltStorageReader`] was created.
+    pub fn seek_to_offset(&mut self, offset: u64) -> Result<(), std::io::Error> {
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.decoder.offset = offset;
+        self.decoder.record_offset = offset;
+        self.decoder.read_error = false;
+        Ok(())
+    }
 
-                let packet = match DltPacketSlice::from_slice(&self.last_packet) {
-                    Ok(packet) => packet,
-                    Err(err) => {
-                        self.read_error = true;
-                        return Some(Err(err.into()));
-                    }
-                };
+    /// Reads all packets from the start of the data and returns the byte
+    /// offsets of their storage headers.
+    ///
+    /// Requires a [`Seek`] + [`Read`] source as the reader is rewound to
+    /// the start of the data before indexing. Afterwards the reader is
+    /// repositioned to the offset it had before the call, so the index can
+    /// be used together with [`DltStorageReader::seek_to_offset`] to jump
+    /// to the Nth packet without re-reading all previous packets.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let dlt_file = "dummy.dlt";
+    /// use std::{fs::File, io::BufReader};
+    /// use dlt_parse::storage::DltStorageReader;
+    ///
+    /// let dlt_file = File::open(dlt_file).expect("failed to open file");
+    /// let mut reader = DltStorageReader::new(BufReader::new(dlt_file));
+    /// let index = reader.build_index().expect("failed to index file");
+    ///
+    /// // jump to the 100th packet
+    /// if let Some(offset) = index.get(99) {
+    ///     reader.seek_to_offset(*offset).expect("failed to seek");
+    ///     let msg = reader.next_packet().unwrap().expect("failed to parse dlt packet");
+    ///     println!("{:?}", msg.packet);
+    /// }
+    /// ```
+    pub fn build_index(&mut self) -> Result<Vec<u64>, ReadError> {
+        let prev_offset = self.decoder.offset;
+        let prev_record_offset = self.decoder.record_offset;
+        let prev_read_error = self.decoder.read_error;
+        let prev_num_read_packets = self.decoder.num_read_packets;
+        let prev_num_pattern_seeks = self.decoder.num_pattern_seeks;
+
+        self.seek_to_offset(0)?;
+        let mut result = Vec::new();
+        let mut error = None;
+        while let Some(packet) = self.next_packet() {
+            if let Err(err) = packet {
+                error = Some(err);
+                break;
+            }
+            result.push(self.decoder.record_offset);
+        }
 
-                // packet successfully read
-                self.num_read_packets += 1;
+        // restore the previous state
+        self.seek_to_offset(prev_offset)?;
+        self.decoder.record_offset = prev_record_offset;
+        self.decoder.read_error = prev_read_error;
+        self.decoder.num_read_packets = prev_num_read_packets;
+        self.decoder.num_pattern_seeks = prev_num_pattern_seeks;
 
-                return Some(Ok(StorageSlice {
-                    storage_header,
-                    packet,
-                }));
-            }
+        match error {
+            Some(err) => Err(err),
+            None => Ok(result),
         }
     }
 }
@@ -332,6 +316,7 @@ impl<R: Read + BufRead> DltStorageReader<R> {
 #[cfg(feature = "std")]
 mod dlt_storage_reader_tests {
     use super::*;
+    use crate::test_packets;
     use std::format;
     use std::io::{BufReader, Cursor};
 
@@ -581,7 +566,7 @@ mod dlt_storage_reader_tests {
             buf.read(&mut []).unwrap();
 
             let mut reader = DltStorageReader::new(buf);
-            reader.num_read_packets = 1;
+            reader.decoder.num_read_packets = 1;
             assert_matches!(reader.next_packet(), Some(Err(ReadError::IoError(_))));
             assert!(reader.next_packet().is_none());
         }
@@ -602,7 +587,7 @@ mod dlt_storage_reader_tests {
             bytes[2] = StorageHeader::PATTERN_AT_START[2];
             bytes[3] = StorageHeader::PATTERN_AT_START[3];
             let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&bytes[..])));
-            reader.num_read_packets = 1;
+            reader.decoder.num_read_packets = 1;
             assert!(reader.next_packet().is_none());
             assert!(reader.next_packet().is_none());
         }
@@ -769,4 +754,187 @@ mod dlt_storage_reader_tests {
             assert!(reader.next_packet().is_none());
         }
     }
+
+    #[test]
+    fn offsets() {
+        use std::vec::Vec;
+
+        let packet = test_packets::storage_entry(
+            &test_packets::storage_header(1),
+            &test_packets::packet(1, &[1, 2, 3, 4]),
+        );
+
+        // strict reader with a bad storage header after two packets
+        {
+            let mut v = Vec::new();
+            v.extend_from_slice(&packet);
+            v.extend_from_slice(&packet);
+            let bad_offset = v.len() as u64;
+            let mut bad_packet = packet.clone();
+            bad_packet[0] = 0;
+            v.extend_from_slice(&bad_packet);
+
+            let mut reader = DltStorageReader::new_strict(BufReader::new(Cursor::new(&v[..])));
+            assert_eq!(0, reader.offset());
+            assert_eq!(0, reader.last_record_offset());
+
+            assert!(reader.next_packet().unwrap().is_ok());
+            assert_eq!(0, reader.last_record_offset());
+            assert_eq!(packet.len() as u64, reader.offset());
+
+            assert!(reader.next_packet().unwrap().is_ok());
+            assert_eq!(packet.len() as u64, reader.last_record_offset());
+            assert_eq!(bad_offset, reader.offset());
+
+            assert_matches!(
+                reader.next_packet(),
+                Some(Err(ReadError::StorageHeaderStartPattern(_)))
+            );
+            assert_eq!(bad_offset, reader.last_record_offset());
+            assert!(reader.next_packet().is_none());
+        }
+
+        // seeking reader with corrupted data before a packet
+        {
+            let mut v = Vec::new();
+            v.extend_from_slice(&packet);
+            v.extend_from_slice(&[b'D', b'L', 0, 1, 2]);
+            let offset = v.len() as u64;
+            v.extend_from_slice(&packet);
+
+            let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&v[..])));
+            assert!(reader.next_packet().unwrap().is_ok());
+            assert_eq!(0, reader.last_record_offset());
+            assert!(reader.next_packet().unwrap().is_ok());
+            assert_eq!(offset, reader.last_record_offset());
+            assert_eq!(v.len() as u64, reader.offset());
+            assert!(reader.next_packet().is_none());
+        }
+    }
+
+    #[test]
+    fn read_into() {
+        use std::vec::Vec;
+
+        let packet = |message_counter: u8| {
+            test_packets::storage_entry(
+                &test_packets::storage_header(u32::from(message_counter)),
+                &test_packets::packet(message_counter, &[1, 2, 3, message_counter]),
+            )
+        };
+
+        for strict in [false, true] {
+            let mut v = Vec::new();
+            v.extend_from_slice(&packet(0));
+            v.extend_from_slice(&packet(1));
+            let reader = BufReader::new(Cursor::new(&v[..]));
+            let mut reader = if strict {
+                DltStorageReader::new_strict(reader)
+            } else {
+                DltStorageReader::new(reader)
+            };
+
+            let mut buf = Vec::new();
+            let mut packets = Vec::new();
+            while let Some(result) = reader.read_into(&mut buf) {
+                let slice = result.unwrap();
+                assert_eq!(
+                    &packet(slice.packet.message_counter())[16..],
+                    slice.packet.slice()
+                );
+                packets.push((slice.storage_header, DltPacket::from(&slice.packet)));
+            }
+            assert_eq!(2, packets.len());
+            assert_eq!(2, reader.num_read_packets());
+            for (i, (storage_header, packet)) in packets.iter().enumerate() {
+                assert_eq!(i as u32, storage_header.timestamp_seconds);
+                assert_eq!(i as u8, packet.header.message_counter);
+            }
+            assert!(reader.read_into(&mut buf).is_none());
+        }
+
+        // error
+        {
+            let mut v = packet(0);
+            v[0] = 0;
+            let mut reader = DltStorageReader::new_strict(BufReader::new(Cursor::new(&v[..])));
+            let mut buf = Vec::new();
+            assert_matches!(
+                reader.read_into(&mut buf),
+                Some(Err(ReadError::StorageHeaderStartPattern(_)))
+            );
+            assert!(reader.read_into(&mut buf).is_none());
+        }
+    }
+
+    #[test]
+    fn build_index_seek_to_offset() {
+        use std::vec::Vec;
+
+        let packet = |message_counter: u8| {
+            test_packets::storage_entry(
+                &test_packets::storage_header(1),
+                &test_packets::packet(message_counter, &[1, 2, 3, 4]),
+            )
+        };
+
+        // packets with corrupted data in between
+        let mut v = Vec::new();
+        let mut expected = Vec::new();
+        for i in 0..4u8 {
+            v.extend_from_slice(&[b'D', b'L', 0]);
+            expected.push(v.len() as u64);
+            v.extend_from_slice(&packet(i));
+        }
+
+        // index & random access
+        {
+            let mut reader = DltStorageReader::new(BufReader::new(Cursor::new(&v[..])));
+            assert!(reader.next_packet().unwrap().is_ok());
+            let offset = reader.offset();
+
+            assert_eq!(expected, reader.build_index().unwrap());
+
+            // state is restored after indexing
+            assert_eq!(offset, reader.offset());
+            assert_eq!(expected[0], reader.last_record_offset());
+            assert_eq!(1, reader.num_read_packets());
+            assert_eq!(1, reader.num_pattern_seeks());
+            assert_eq!(
+                1,
+                reader
+                    .next_packet()
+                    .unwrap()
+                    .unwrap()
+                    .packet
+                    .header()
+                    .message_counter
+            );
+
+            for i in [3, 0, 2] {
+                reader.seek_to_offset(expected[i]).unwrap();
+                assert_eq!(expected[i], reader.offset());
+                let msg = reader.next_packet().unwrap().unwrap();
+                assert_eq!(i as u8, msg.packet.header().message_counter);
+                assert_eq!(expected[i], reader.last_record_offset());
+            }
+
+            // iteration can continue after the end was reached
+            reader.seek_to_offset(expected[3]).unwrap();
+            assert!(reader.next_packet().unwrap().is_ok());
+            assert!(reader.next_packet().is_none());
+            reader.seek_to_offset(expected[2]).unwrap();
+            assert!(reader.next_packet().unwrap().is_ok());
+        }
+
+        // error during indexing
+        {
+            let mut reader = DltStorageReader::new_strict(BufReader::new(Cursor::new(&v[..])));
+            assert_matches!(
+                reader.build_index(),
+                Err(ReadError::StorageHeaderStartPattern(_))
+            );
+            assert_eq!(0, reader.offset());
+        }
+    }
 }
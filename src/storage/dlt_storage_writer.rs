@@ -52,13 +52,17 @@ use crate::{storage::StorageHeader, DltPacketSlice};
 #[derive(Debug)]
 pub struct DltStorageWriter<W: Write> {
     writer: W,
+    bytes_written: u64,
 }
 
 #[cfg(feature = "std")]
 impl<W: Write> DltStorageWriter<W> {
     /// Creates a new writer that allows writing dlt packets to a storage file.
     pub fn new(writer: W) -> DltStorageWriter<W> {
-        DltStorageWriter { writer }
+        DltStorageWriter {
+            writer,
+            bytes_written: 0,
+        }
     }
 
     /// Writes a sliced packet into a storage file.
@@ -68,7 +72,24 @@ impl<W: Write> DltStorageWriter<W> {
         dlt_slice: DltPacketSlice<'_>,
     ) -> Result<(), Error> {
         storage_header.write(&mut self.writer)?;
-        self.writer.write_all(dlt_slice.slice())
+        self.bytes_written += StorageHeader::BYTE_LEN as u64;
+        self.writer.write_all(dlt_slice.slice())?;
+        self.bytes_written += dlt_slice.slice().len() as u64;
+        Ok(())
+    }
+
+    /// Number of bytes written by this writer (storage headers & packets).
+    ///
+    /// Useful to split files once they reach a size limit. Data of a
+    /// storage header or packet that failed to be written is not counted.
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()
     }
 }
 
@@ -90,10 +111,31 @@ mod dlt_storage_writer_tests {
     #[test]
     fn new() {
         let mut buffer = Vec::<u8>::new();
-        let _writer = DltStorageWriter::new(&mut buffer);
+        let writer = DltStorageWriter::new(&mut buffer);
+        assert_eq!(0, writer.bytes_written());
         assert_eq!(0, buffer.len());
     }
 
+    #[test]
+    fn flush() {
+        struct FlushCounter(usize);
+        impl Write for FlushCounter {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> Result<(), Error> {
+                self.0 += 1;
+                Ok(())
+            }
+        }
+
+        let mut counter = FlushCounter(0);
+        let mut writer = DltStorageWriter::new(&mut counter);
+        writer.flush().unwrap();
+        writer.flush().unwrap();
+        assert_eq!(2, counter.0);
+    }
+
     #[test]
     fn write_slice() {
         // ok
@@ -128,6 +170,10 @@ mod dlt_storage_writer_tests {
                     DltPacketSlice::from_slice(&packet0).unwrap(),
                 )
                 .unwrap();
+            assert_eq!(
+                (StorageHeader::BYTE_LEN + packet0.len()) as u64,
+                writer.bytes_written()
+            );
 
             // add a secondary packet
             let packet1 = {
@@ -157,6 +203,11 @@ mod dlt_storage_writer_tests {
                     DltPacketSlice::from_slice(&packet1).unwrap(),
                 )
                 .unwrap();
+            assert_eq!(
+                (2 * StorageHeader::BYTE_LEN + packet0.len() + packet1.len()) as u64,
+                writer.bytes_written()
+            );
+            writer.flush().unwrap();
 
             // check contents
             {
@@ -201,6 +252,7 @@ mod dlt_storage_writer_tests {
                 assert!(writer
                     .write_slice(header.clone(), DltPacketSlice::from_slice(&packet).unwrap())
                     .is_err());
+                assert_eq!(0, writer.bytes_written());
             }
             // write with not enough memory for the packet
             {
@@ -210,6 +262,7 @@ mod dlt_storage_writer_tests {
                 assert!(writer
                     .write_slice(header, DltPacketSlice::from_slice(&packet).unwrap())
                     .is_err());
+                assert_eq!(StorageHeader::BYTE_LEN as u64, writer.bytes_written());
             }
         }
     }
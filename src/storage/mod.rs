@@ -1,15 +1,53 @@
+#[cfg(feature = "tokio")]
+mod async_dlt_storage_reader;
+#[cfg(feature = "tokio")]
+pub use async_dlt_storage_reader::*;
+
+#[cfg(feature = "std")]
+mod demux_by_ecu;
+#[cfg(feature = "std")]
+pub use demux_by_ecu::*;
+
+#[cfg(feature = "std")]
+mod detect_format;
+#[cfg(feature = "std")]
+pub use detect_format::*;
+
 #[cfg(feature = "std")]
 mod dlt_storage_reader;
 #[cfg(feature = "std")]
 pub use dlt_storage_reader::*;
 
+#[cfg(feature = "std")]
+mod dlt_storage_range_reader;
+#[cfg(feature = "std")]
+pub use dlt_storage_range_reader::*;
+
 #[cfg(feature = "std")]
 mod dlt_storage_writer;
 #[cfg(feature = "std")]
 pub use dlt_storage_writer::*;
 
+#[cfg(feature = "std")]
+mod normalize_endianness;
+#[cfg(feature = "std")]
+pub use normalize_endianness::*;
+
+#[cfg(feature = "alloc")]
+mod storage_packet;
+#[cfg(feature = "alloc")]
+pub use storage_packet::*;
+
 mod storage_header;
 pub use storage_header::*;
 
+#[cfg(feature = "std")]
+mod storage_record_decoder;
+#[cfg(feature = "std")]
+pub(crate) use storage_record_decoder::*;
+
 mod storage_slice;
 pub use storage_slice::*;
+
+mod storage_slice_iterator;
+pub use storage_slice_iterator::*;
@@ -0,0 +1,291 @@
+use std::io::{self, BufReader, Read, Write};
+use std::vec::Vec;
+
+use super::DltStorageReader;
+use crate::error::ReadError;
+use crate::verbose::{add_converted_to_msg, EndiannessConversionError, VerboseIter};
+
+/// Reads all DLT packets from a storage file (.dlt) & writes them
+/// (including the storage header) to the writer with all little endian
+/// verbose packets re-encoded as big endian packets.
+///
+/// For verbose packets all arguments are re-encoded (including the
+/// elements of arrays & the entries of structs). Non verbose packets
+/// (including control messages) are written unchanged, as their payload
+/// can not be re-encoded without the matching description.
+///
+/// Returns the number of packets that were re-encoded. Packets whose
+/// verbose arguments can not be decoded result in an
+/// [`std::io::ErrorKind::InvalidData`] error.
+///
+/// # Example
+/// ```no_run
+/// use std::{fs::File, io::BufWriter};
+/// use dlt_parse::storage::normalize_endianness;
+///
+/// let input = File::open("input.dlt").expect("failed to open file");
+/// let output = BufWriter::new(File::create("output.dlt").expect("failed to create file"));
+/// let num_converted = normalize_endianness(input, output).expect("failed to convert file");
+/// println!("converted {} packets", num_converted);
+/// ```
+pub fn normalize_endianness<R, W>(reader: R, mut writer: W) -> Result<usize, ReadError>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = DltStorageReader::new(BufReader::new(reader));
+    let mut num_converted = 0;
+    let mut payload = Vec::new();
+
+    while let Some(msg) = reader.next_packet() {
+        let msg = msg?;
+        msg.storage_header.write(&mut writer)?;
+        let iter = match msg.packet.verbose_value_iter() {
+            Some(iter) if false == msg.packet.is_big_endian() => iter,
+            _ => {
+                // big endian & non verbose packets are kept as they are
+                writer.write_all(msg.packet.slice())?;
+                continue;
+            }
+        };
+
+        payload.clear();
+        payload.reserve(usize::from(msg.packet.header().length));
+        payload_to_big_endian(iter, &mut payload).map_err(io::Error::from)?;
+
+        let mut header = msg.packet.header();
+        header.is_big_endian = true;
+        header.length = u16::try_from(usize::from(header.header_len()) + payload.len())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        writer.write_all(&header.to_bytes())?;
+        writer.write_all(&payload)?;
+        num_converted += 1;
+    }
+
+    writer.flush()?;
+    Ok(num_converted)
+}
+
+/// Writes the arguments of a little endian verbose payload re-encoded
+/// as big endian to `buf`.
+fn payload_to_big_endian(
+    mut iter: VerboseIter<'_>,
+    buf: &mut Vec<u8>,
+) -> Result<(), EndiannessConversionError> {
+    for value in iter.by_ref() {
+        add_converted_to_msg(&value?, buf, true)?;
+    }
+    // keep data after the arguments as it is
+    buf.extend_from_slice(iter.raw());
+    Ok(())
+}
+
+#[cfg(test)]
+mod normalize_endianness_tests {
+    use super::*;
+    use crate::storage::StorageHeader;
+    use crate::test_packets::{packet_with_endianness, storage_entry, storage_header};
+    use crate::verbose::{StringValue, U32Value, VerboseValue};
+    use crate::*;
+    use std::io::Cursor;
+    use std::{vec, vec::Vec};
+
+    /// Verbose message with the given endianness (array data encoded
+    /// in the matching endianness).
+    fn verbose_msg(is_big_endian: bool) -> Vec<u8> {
+        // i16 array with one dimension of two elements
+        let mut array = vec![0b0010_0010, 0b0000_0001, 0, 0];
+        for v in [1u16, 2u16, 0x0102, 0xfffd] {
+            if is_big_endian {
+                array.extend_from_slice(&v.to_be_bytes());
+            } else {
+                array.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        let (array, _) = VerboseValue::from_slice(&array, is_big_endian).unwrap();
+
+        let header = DltHeader {
+            is_big_endian,
+            message_counter: 1,
+            length: 0,
+            ecu_id: Some(*b"ECU1"),
+            session_id: Some(0x1234),
+            timestamp: Some(0x5678),
+            extended_header: None,
+        };
+        let mut builder = DltMessageBuilder::new(
+            header,
+            DltExtendedHeader::new_non_verbose_log(DltLogLevel::Info, *b"APP1", *b"CTX1"),
+        );
+        builder
+            .add_value(VerboseValue::U32(U32Value {
+                variable_info: None,
+                scaling: None,
+                value: 0x1234_5678,
+            }))
+            .add_value(VerboseValue::Str(StringValue::new(Some("name"), "text")))
+            .add_value(array);
+        builder.build().unwrap()
+    }
+
+    fn non_verbose_msg(is_big_endian: bool) -> Vec<u8> {
+        let mut payload = Vec::new();
+        if is_big_endian {
+            payload.extend_from_slice(&0x1234_5678u32.to_be_bytes());
+        } else {
+            payload.extend_from_slice(&0x1234_5678u32.to_le_bytes());
+        }
+        payload.extend_from_slice(&[1, 2]);
+        packet_with_endianness(is_big_endian, 2, &payload)
+    }
+
+    /// "Get ECU Software Version" response with the version "1.2.3".
+    fn control_msg(is_big_endian: bool) -> Vec<u8> {
+        let u32_bytes = |value: u32| {
+            if is_big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&u32_bytes(control::CMD_ID_GET_SOFTWARE_VERSION));
+        payload.push(0); // status ok
+        payload.extend_from_slice(&u32_bytes(5));
+        payload.extend_from_slice(b"1.2.3");
+
+        let mut header = DltHeader {
+            is_big_endian,
+            message_counter: 3,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: Some(
+                DltExtendedHeader::new_non_verbose(
+                    DltMessageType::Control(DltControlMessageType::Response),
+                    *b"APP1",
+                    *b"CTX1",
+                )
+                .unwrap(),
+            ),
+        };
+        header.length = header.header_len() + payload.len() as u16;
+        let mut packet = header.to_bytes().to_vec();
+        packet.extend_from_slice(&payload);
+        packet
+    }
+
+    fn storage_file(packets: &[Vec<u8>]) -> Vec<u8> {
+        let mut result = Vec::new();
+        for packet in packets {
+            result.extend_from_slice(&storage_entry(&storage_header(1), packet));
+        }
+        result
+    }
+
+    #[test]
+    fn normalize() {
+        let input = storage_file(&[
+            verbose_msg(false),
+            verbose_msg(true),
+            non_verbose_msg(false),
+            non_verbose_msg(true),
+            control_msg(false),
+        ]);
+        let mut output = Vec::new();
+        assert_eq!(
+            1,
+            normalize_endianness(Cursor::new(&input), &mut output).unwrap()
+        );
+        // non verbose packets are kept unchanged
+        assert_eq!(
+            storage_file(&[
+                verbose_msg(true),
+                verbose_msg(true),
+                non_verbose_msg(false),
+                non_verbose_msg(true),
+                control_msg(false),
+            ]),
+            output
+        );
+
+        // little endian non verbose payloads can still be decoded
+        let mut reader = DltStorageReader::new(Cursor::new(&output));
+        let mut packets = Vec::new();
+        while let Some(msg) = reader.next_packet() {
+            packets.push(msg.unwrap().packet.slice().to_vec());
+        }
+        let user = DltPacketSlice::from_slice(&packets[2]).unwrap();
+        assert_eq!(
+            Some((0x1234_5678, &[1u8, 2][..])),
+            user.message_id_and_payload()
+        );
+        let control = DltPacketSlice::from_slice(&packets[4]).unwrap();
+        assert_matches!(
+            control::ControlMessage::from_packet(&control),
+            Some(Ok(control::ControlMessage::GetSoftwareVersionResponse(r)))
+                if r.version() == Some("1.2.3")
+        );
+
+        // decoded values are identical
+        let le = verbose_msg(false);
+        let be = &output[StorageHeader::BYTE_LEN..StorageHeader::BYTE_LEN + le.len()];
+        let le = DltPacketSlice::from_slice(&le).unwrap();
+        let be = DltPacketSlice::from_slice(be).unwrap();
+        assert!(be.is_big_endian());
+        let le_values = le
+            .verbose_value_iter()
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect::<Vec<_>>();
+        let be_values = be
+            .verbose_value_iter()
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(le_values.len(), be_values.len());
+        assert_eq!(le_values[..2], be_values[..2]);
+        match (&le_values[2], &be_values[2]) {
+            (VerboseValue::ArrI16(l), VerboseValue::ArrI16(b)) => {
+                assert_eq!(l.iter().collect::<Vec<_>>(), b.iter().collect::<Vec<_>>());
+                assert_eq!(
+                    l.dimensions.iter().collect::<Vec<_>>(),
+                    b.dimensions.iter().collect::<Vec<_>>()
+                );
+            }
+            _ => panic!("expected i16 arrays"),
+        }
+    }
+
+    #[test]
+    fn errors() {
+        // verbose argument that can not be decoded
+        {
+            let mut msg = verbose_msg(false);
+            // set an invalid type info for the first argument
+            let header_len = DltPacketSlice::from_slice(&msg)
+                .unwrap()
+                .header()
+                .header_len();
+            msg[usize::from(header_len)] = 0xff;
+            msg[usize::from(header_len) + 1] = 0xff;
+            let input = storage_file(&[msg]);
+            let mut output = Vec::new();
+            assert_matches!(
+                normalize_endianness(Cursor::new(&input), &mut output),
+                Err(ReadError::IoError(err)) if err.kind() == io::ErrorKind::InvalidData
+            );
+        }
+
+        // write error
+        {
+            let input = storage_file(&[verbose_msg(false)]);
+            let mut buffer = [0u8; 4];
+            assert_matches!(
+                normalize_endianness(Cursor::new(&input), Cursor::new(&mut buffer[..])),
+                Err(ReadError::IoError(_))
+            );
+        }
+    }
+}
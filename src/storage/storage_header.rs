@@ -4,6 +4,7 @@ use core::str::Utf8Error;
 /// Header present before a `DltHeader` if a DLT packet is
 /// stored in .dlt file or database.
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StorageHeader {
     pub timestamp_seconds: u32,
     pub timestamp_microseconds: u32,
@@ -41,24 +42,48 @@ impl StorageHeader {
         ]
     }
 
+    /// Checks that the given slice starts with the storage header pattern
+    /// ([`StorageHeader::PATTERN_AT_START`]).
+    ///
+    /// Slices with less than 4 bytes result in an
+    /// [`error::StorageSliceError::UnexpectedEndOfSlice`] error, a
+    /// different pattern in an
+    /// [`error::StorageSliceError::StorageHeaderStartPattern`] error.
+    pub fn check_start_pattern(slice: &[u8]) -> Result<(), error::StorageSliceError> {
+        use error::{StorageSliceError::*, *};
+
+        match slice {
+            [p0, p1, p2, p3, ..] => {
+                let actual_pattern = [*p0, *p1, *p2, *p3];
+                if StorageHeader::PATTERN_AT_START == actual_pattern {
+                    Ok(())
+                } else {
+                    Err(StorageHeaderStartPattern(StorageHeaderStartPatternError {
+                        actual_pattern,
+                    }))
+                }
+            }
+            _ => Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                layer: Layer::StorageHeader,
+                minimum_size: StorageHeader::PATTERN_AT_START.len(),
+                actual_size: slice.len(),
+            })),
+        }
+    }
+
     /// Tries to decode a storage header.
     pub fn from_bytes(
         bytes: [u8; 16],
     ) -> Result<StorageHeader, error::StorageHeaderStartPatternError> {
-        let start_pattern = [bytes[0], bytes[1], bytes[2], bytes[3]];
-        if start_pattern != StorageHeader::PATTERN_AT_START {
-            Err(error::StorageHeaderStartPatternError {
-                actual_pattern: start_pattern,
-            })
-        } else {
-            Ok(StorageHeader {
-                timestamp_seconds: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
-                timestamp_microseconds: u32::from_le_bytes([
-                    bytes[8], bytes[9], bytes[10], bytes[11],
-                ]),
-                ecu_id: [bytes[12], bytes[13], bytes[14], bytes[15]],
-            })
+        let actual_pattern = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        if StorageHeader::PATTERN_AT_START != actual_pattern {
+            return Err(error::StorageHeaderStartPatternError { actual_pattern });
         }
+        Ok(StorageHeader {
+            timestamp_seconds: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            timestamp_microseconds: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            ecu_id: [bytes[12], bytes[13], bytes[14], bytes[15]],
+        })
     }
 
     ///Deserialize a DltHeader & TpHeader from the given reader.
@@ -81,6 +106,40 @@ impl StorageHeader {
     pub fn ecu_id_str(&self) -> Result<&str, Utf8Error> {
         core::str::from_utf8(&self.ecu_id)
     }
+
+    /// Returns true if `timestamp_microseconds` is smaller then one second
+    /// (`< 1_000_000`).
+    #[inline]
+    pub fn is_timestamp_valid(&self) -> bool {
+        self.timestamp_microseconds < 1_000_000
+    }
+
+    /// Returns the timestamp of the storage header as microseconds since
+    /// the unix epoch.
+    ///
+    /// Microsecond values of one second or more are added as they are (see
+    /// [`StorageHeader::is_timestamp_valid`] to detect these).
+    #[inline]
+    pub fn to_unix_micros(&self) -> u64 {
+        u64::from(self.timestamp_seconds) * 1_000_000 + u64::from(self.timestamp_microseconds)
+    }
+
+    /// Returns the timestamp of the storage header as [`std::time::SystemTime`]
+    /// (`timestamp_seconds` are interpreted as seconds since the unix epoch).
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> std::time::SystemTime {
+        std::time::UNIX_EPOCH + std::time::Duration::from_micros(self.to_unix_micros())
+    }
+
+    /// Returns the timestamp of the storage header as an UTC
+    /// [`time::OffsetDateTime`] (`timestamp_seconds` are interpreted as
+    /// seconds since the unix epoch).
+    #[cfg(feature = "time")]
+    pub fn to_offset_datetime(&self) -> time::OffsetDateTime {
+        time::OffsetDateTime::UNIX_EPOCH
+            + time::Duration::seconds(i64::from(self.timestamp_seconds))
+            + time::Duration::microseconds(i64::from(self.timestamp_microseconds))
+    }
 }
 
 #[cfg(test)]
@@ -91,6 +150,15 @@ mod storage_header_tests {
     use proptest::prelude::*;
     use std::format;
 
+    proptest! {
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trip(ref header in storage_header_any()) {
+            let json = serde_json::to_string(header).unwrap();
+            prop_assert_eq!(header, &serde_json::from_str::<StorageHeader>(&json).unwrap());
+        }
+    }
+
     proptest! {
         #[test]
         fn debug(
@@ -128,6 +196,57 @@ mod storage_header_tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn check_start_pattern(
+            ref rest in proptest::collection::vec(any::<u8>(), 0..8),
+            bad_pattern in any::<[u8;4]>().prop_filter(
+                "pattern must not match the expected pattern",
+                |v| *v != StorageHeader::PATTERN_AT_START
+            )
+        ) {
+            // ok case
+            {
+                let mut data = StorageHeader::PATTERN_AT_START.to_vec();
+                data.extend_from_slice(rest);
+                prop_assert_eq!(Ok(()), StorageHeader::check_start_pattern(&data));
+            }
+
+            // bad pattern
+            {
+                let mut data = bad_pattern.to_vec();
+                data.extend_from_slice(rest);
+                prop_assert_eq!(
+                    Err(error::StorageSliceError::StorageHeaderStartPattern(
+                        error::StorageHeaderStartPatternError {
+                            actual_pattern: bad_pattern,
+                        }
+                    )),
+                    StorageHeader::check_start_pattern(&data)
+                );
+            }
+
+            // slice too short (also if the start of the pattern matches)
+            for len in 0..StorageHeader::PATTERN_AT_START.len() {
+                let expected = Err(error::StorageSliceError::UnexpectedEndOfSlice(
+                    error::UnexpectedEndOfSliceError {
+                        layer: error::Layer::StorageHeader,
+                        minimum_size: 4,
+                        actual_size: len,
+                    }
+                ));
+                prop_assert_eq!(
+                    &expected,
+                    &StorageHeader::check_start_pattern(&StorageHeader::PATTERN_AT_START[..len])
+                );
+                prop_assert_eq!(
+                    &expected,
+                    &StorageHeader::check_start_pattern(&bad_pattern[..len])
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn from_bytes(
@@ -235,4 +354,104 @@ mod storage_header_tests {
             );
         }
     }
+
+    #[test]
+    fn is_timestamp_valid() {
+        let mut header = StorageHeader {
+            timestamp_seconds: 1,
+            timestamp_microseconds: 0,
+            ecu_id: [0; 4],
+        };
+        assert!(header.is_timestamp_valid());
+        header.timestamp_microseconds = 999_999;
+        assert!(header.is_timestamp_valid());
+        header.timestamp_microseconds = 1_000_000;
+        assert!(false == header.is_timestamp_valid());
+        header.timestamp_microseconds = u32::MAX;
+        assert!(false == header.is_timestamp_valid());
+    }
+
+    proptest! {
+        #[test]
+        fn to_unix_micros(
+            header in storage_header_any()
+        ) {
+            prop_assert_eq!(
+                header.to_unix_micros(),
+                u64::from(header.timestamp_seconds) * 1_000_000
+                    + u64::from(header.timestamp_microseconds)
+            );
+        }
+    }
+
+    #[test]
+    fn to_unix_micros_max() {
+        let header = StorageHeader {
+            timestamp_seconds: u32::MAX,
+            timestamp_microseconds: u32::MAX,
+            ecu_id: [0; 4],
+        };
+        assert_eq!(
+            4_294_967_295_000_000 + 4_294_967_295,
+            header.to_unix_micros()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_system_time() {
+        let header = StorageHeader {
+            timestamp_seconds: 1_700_000_000,
+            timestamp_microseconds: 123_456,
+            ecu_id: [0; 4],
+        };
+        assert_eq!(
+            header.to_system_time(),
+            std::time::UNIX_EPOCH + std::time::Duration::new(1_700_000_000, 123_456_000)
+        );
+        assert_eq!(
+            header
+                .to_system_time()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_micros(),
+            u128::from(header.to_unix_micros())
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn to_offset_datetime() {
+        use time::{Date, Month};
+
+        // known epoch value
+        {
+            let header = StorageHeader {
+                timestamp_seconds: 1_700_000_000,
+                timestamp_microseconds: 123_456,
+                ecu_id: [0; 4],
+            };
+            assert_eq!(
+                header.to_offset_datetime(),
+                Date::from_calendar_date(2023, Month::November, 14)
+                    .unwrap()
+                    .with_hms_micro(22, 13, 20, 123_456)
+                    .unwrap()
+                    .assume_utc()
+            );
+        }
+
+        // microseconds bigger than one second
+        {
+            let header = StorageHeader {
+                timestamp_seconds: 0,
+                timestamp_microseconds: 1_500_000,
+                ecu_id: [0; 4],
+            };
+            assert_eq!(
+                header.to_offset_datetime(),
+                time::OffsetDateTime::UNIX_EPOCH + time::Duration::microseconds(1_500_000)
+            );
+        }
+    }
 }
@@ -0,0 +1,61 @@
+use super::{StorageHeader, StorageSlice};
+use crate::DltPacket;
+
+/// Owned version of a [`StorageSlice`] (storage header & DLT packet).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StoragePacket {
+    pub storage_header: StorageHeader,
+    pub packet: DltPacket,
+}
+
+impl<'a> From<&StorageSlice<'a>> for StoragePacket {
+    fn from(value: &StorageSlice<'a>) -> Self {
+        StoragePacket {
+            storage_header: value.storage_header.clone(),
+            packet: DltPacket::from(&value.packet),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DltHeader, DltPacketSlice};
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq_from() {
+        let mut header = DltHeader {
+            is_big_endian: true,
+            message_counter: 0,
+            length: 0,
+            ecu_id: None,
+            session_id: None,
+            timestamp: None,
+            extended_header: None,
+        };
+        header.length = header.header_len() + 4;
+        let mut bytes = Vec::from(&header.to_bytes()[..]);
+        bytes.extend_from_slice(&[1, 2, 3, 4]);
+
+        let slice = StorageSlice {
+            storage_header: StorageHeader {
+                timestamp_seconds: 1,
+                timestamp_microseconds: 2,
+                ecu_id: *b"ECU1",
+            },
+            packet: DltPacketSlice::from_slice(&bytes).unwrap(),
+        };
+        let packet = StoragePacket::from(&slice);
+        assert_eq!(slice.storage_header, packet.storage_header);
+        assert_eq!(DltPacket::from(&slice.packet), packet.packet);
+        assert_eq!(packet, packet.clone());
+        assert_eq!(
+            format!(
+                "StoragePacket {{ storage_header: {:?}, packet: {:?} }}",
+                packet.storage_header, packet.packet
+            ),
+            format!("{:?}", packet)
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use std::io::ErrorKind;
+#[cfg(not(test))]
+use std::vec::Vec;
+
+use crate::error::{DltMessageLengthTooSmallError, ReadError, UnsupportedDltVersionError};
+use crate::storage::StorageHeader;
+use crate::*;
+
+/// I/O independent part of the storage readers ([`super::DltStorageReader`]
+/// & `AsyncDltStorageReader`).
+///
+/// The decoder contains the storage header & DLT header decoding, the
+/// resynchronization on corrupted data & the read statistics. The readers
+/// only perform the I/O requested via [`DecodeStep`]:
+///
+/// * [`DecodeStep::FillBuf`]: fill the read buffer, pass the buffered data to
+///   [`StorageRecordDecoder::buffered`] & consume the returned number of bytes.
+/// * [`DecodeStep::Read`]: read exactly the given number of bytes, append
+///   them to the record buffer & call [`StorageRecordDecoder::read`].
+/// * [`DecodeStep::Done`]: return the result (on `Ok` the record buffer
+///   contains the DLT packet).
+///
+/// I/O errors are passed to [`StorageRecordDecoder::io_error`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct StorageRecordDecoder {
+    /// Continue search for next storage header if it is missing.
+    pub is_seeking_storage_pattern: bool,
+    pub read_error: bool,
+    pub num_read_packets: usize,
+    pub num_pattern_seeks: usize,
+    pub offset: u64,
+    pub record_offset: u64,
+    phase: Phase,
+    pattern_elements_found: usize,
+    storage_pattern_error: bool,
+}
+
+/// Part of the record that is currently decoded.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Phase {
+    /// Checking that data is left (strict) or searching the storage
+    /// pattern (seeking).
+    Start,
+    /// Reading the storage header (in the seeking case without the pattern).
+    StorageHeader,
+    /// Reading the first 4 bytes of the DLT header.
+    HeaderStart(StorageHeader),
+    /// Reading the rest of the DLT packet.
+    Packet(StorageHeader),
+}
+
+/// I/O step a storage reader has to perform next (see [`StorageRecordDecoder`]).
+#[derive(Debug)]
+pub(crate) enum DecodeStep {
+    /// Fill the read buffer of the underlying reader.
+    FillBuf,
+    /// Read exactly the given number of bytes into the record buffer.
+    Read(usize),
+    /// Decoding finished (`None` if the end of the data was reached).
+    Done(Option<Result<StorageHeader, ReadError>>),
+}
+
+impl StorageRecordDecoder {
+    /// Creates a new decoder.
+    pub fn new(is_seeking_storage_pattern: bool) -> StorageRecordDecoder {
+        StorageRecordDecoder {
+            is_seeking_storage_pattern,
+            read_error: false,
+            num_read_packets: 0,
+            num_pattern_seeks: 0,
+            offset: 0,
+            record_offset: 0,
+            phase: Phase::Start,
+            pattern_elements_found: 0,
+            storage_pattern_error: false,
+        }
+    }
+
+    /// Starts decoding the next record.
+    pub fn start(&mut self, buf: &mut Vec<u8>) -> DecodeStep {
+        // check if iteration is done based as
+        if self.read_error {
+            return DecodeStep::Done(None);
+        }
+        self.restart(buf)
+    }
+
+    /// Processes the data returned by a "fill buffer" call & returns the
+    /// number of bytes that have to be consumed & the next step.
+    pub fn buffered(&mut self, slice: &[u8]) -> (usize, DecodeStep) {
+        if slice.is_empty() {
+            // no more data left
+            if self.is_seeking_storage_pattern {
+                self.read_error = true;
+            }
+            return (0, DecodeStep::Done(None));
+        }
+
+        if false == self.is_seeking_storage_pattern {
+            // in the non seeking version a storage header is expected to be directly present
+            self.record_offset = self.offset;
+            return (
+                0,
+                self.expect(Phase::StorageHeader, StorageHeader::BYTE_LEN),
+            );
+        }
+
+        // check for the pattern
+        let mut consumed_len = 0;
+        for d in slice {
+            if *d == StorageHeader::PATTERN_AT_START[self.pattern_elements_found] {
+                self.pattern_elements_found += 1;
+            } else {
+                self.storage_pattern_error = true;
+                self.pattern_elements_found = 0;
+            }
+            consumed_len += 1;
+            if self.pattern_elements_found >= StorageHeader::PATTERN_AT_START.len() {
+                break;
+            }
+        }
+        self.offset += consumed_len as u64;
+
+        if self.pattern_elements_found < StorageHeader::PATTERN_AT_START.len() {
+            return (consumed_len, DecodeStep::FillBuf);
+        }
+        if self.storage_pattern_error {
+            self.num_pattern_seeks += 1;
+        }
+        self.record_offset = self.offset - StorageHeader::PATTERN_AT_START.len() as u64;
+
+        // read the rest of the storage header
+        (
+            consumed_len,
+            self.expect(
+                Phase::StorageHeader,
+                StorageHeader::BYTE_LEN - StorageHeader::PATTERN_AT_START.len(),
+            ),
+        )
+    }
+
+    /// Processes the data of a completed [`DecodeStep::Read`] (appended to
+    /// `buf`) & returns the next step.
+    pub fn read(&mut self, buf: &mut Vec<u8>) -> DecodeStep {
+        match core::mem::replace(&mut self.phase, Phase::Start) {
+            Phase::Start => self.restart(buf),
+            Phase::StorageHeader => {
+                self.offset += buf.len() as u64;
+                let storage_header = if self.is_seeking_storage_pattern {
+                    StorageHeader {
+                        timestamp_seconds: u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]),
+                        timestamp_microseconds: u32::from_le_bytes([
+                            buf[4], buf[5], buf[6], buf[7],
+                        ]),
+                        ecu_id: [buf[8], buf[9], buf[10], buf[11]],
+                    }
+                } else {
+                    let mut bytes = [0u8; StorageHeader::BYTE_LEN];
+                    bytes.copy_from_slice(buf);
+                    match StorageHeader::from_bytes(bytes) {
+                        Ok(value) => value,
+                        Err(err) => return self.fail(err.into()),
+                    }
+                };
+                buf.clear();
+
+                // read the start
+                self.expect(Phase::HeaderStart(storage_header), 4)
+            }
+            Phase::HeaderStart(storage_header) => {
+                self.offset += 4;
+
+                // check version
+                let version = (buf[0] >> 5) & MAX_VERSION;
+                if 0 != version && 1 != version {
+                    if self.is_seeking_storage_pattern {
+                        return self.restart(buf);
+                    }
+                    return self.fail(ReadError::UnsupportedDltVersion(
+                        UnsupportedDltVersionError {
+                            unsupported_version: version,
+                        },
+                    ));
+                }
+
+                // check the length to be at least the size of the header
+                // (strict: at least 4, the rest is checked when the
+                // packet gets parsed)
+                let length = u16::from_be_bytes([buf[2], buf[3]]) as usize;
+                if self.is_seeking_storage_pattern {
+                    if length < DltHeader::header_len_from_type(buf[0]) {
+                        return self.restart(buf);
+                    }
+                } else if length < 4 {
+                    return self.fail(ReadError::DltMessageLengthTooSmall(
+                        DltMessageLengthTooSmallError {
+                            required_length: 4,
+                            actual_length: length,
+                        },
+                    ));
+                }
+
+                // read the complete packet
+                if length > 4 {
+                    buf.reserve(length - 4);
+                    self.expect(Phase::Packet(storage_header), length - 4)
+                } else {
+                    self.complete(storage_header)
+                }
+            }
+            Phase::Packet(storage_header) => {
+                self.offset += (buf.len() - 4) as u64;
+                self.complete(storage_header)
+            }
+        }
+    }
+
+    /// Processes an I/O error of the underlying reader & returns the
+    /// next step.
+    pub fn io_error(&mut self, err: std::io::Error) -> DecodeStep {
+        self.phase = Phase::Start;
+        if self.is_seeking_storage_pattern && err.kind() == ErrorKind::UnexpectedEof {
+            self.read_error = true;
+            DecodeStep::Done(None)
+        } else {
+            self.fail(err.into())
+        }
+    }
+
+    /// Stops the iteration & returns the given error (the offset of the
+    /// record that failed to decode stays available in `record_offset`).
+    pub fn record_error(&mut self, err: ReadError) -> ReadError {
+        self.read_error = true;
+        err
+    }
+
+    /// Starts the search for the next storage header (again).
+    fn restart(&mut self, buf: &mut Vec<u8>) -> DecodeStep {
+        buf.clear();
+        self.phase = Phase::Start;
+        self.record_offset = self.offset;
+        self.pattern_elements_found = 0;
+        self.storage_pattern_error = false;
+        DecodeStep::FillBuf
+    }
+
+    /// Requests the given number of bytes for the given phase.
+    fn expect(&mut self, phase: Phase, len: usize) -> DecodeStep {
+        self.phase = phase;
+        DecodeStep::Read(len)
+    }
+
+    /// Marks the record as successfully read.
+    fn complete(&mut self, storage_header: StorageHeader) -> DecodeStep {
+        // packet successfully read
+        self.num_read_packets += 1;
+        DecodeStep::Done(Some(Ok(storage_header)))
+    }
+
+    /// Stops the iteration with the given error.
+    fn fail(&mut self, err: ReadError) -> DecodeStep {
+        DecodeStep::Done(Some(Err(self.record_error(err))))
+    }
+}
+
+#[cfg(test)]
+mod storage_record_decoder_tests {
+    use super::*;
+    use crate::test_packets::{packet, storage_header};
+    use std::format;
+
+    /// Drives the decoder over the given data (without a read buffer
+    /// limit) & returns the decoded result & record buffer.
+    fn decode(
+        decoder: &mut StorageRecordDecoder,
+        data: &mut &[u8],
+    ) -> (Option<Result<StorageHeader, ReadError>>, Vec<u8>) {
+        let mut buf = Vec::new();
+        let mut step = decoder.start(&mut buf);
+        loop {
+            step = match step {
+                DecodeStep::FillBuf => {
+                    let (consumed_len, step) = decoder.buffered(data);
+                    *data = &data[consumed_len..];
+                    step
+                }
+                DecodeStep::Read(len) => {
+                    if data.len() < len {
+                        decoder.io_error(ErrorKind::UnexpectedEof.into())
+                    } else {
+                        buf.extend_from_slice(&data[..len]);
+                        *data = &data[len..];
+                        decoder.read(&mut buf)
+                    }
+                }
+                DecodeStep::Done(result) => return (result, buf),
+            };
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let decoder = StorageRecordDecoder::new(true);
+        assert_eq!(decoder, decoder.clone());
+        assert!(false == format!("{:?}", decoder).is_empty());
+        assert!(false == format!("{:?}", DecodeStep::FillBuf).is_empty());
+    }
+
+    #[test]
+    fn decode_records() {
+        let packet0 = packet(1, &[1, 2, 3, 4]);
+        let mut v = Vec::from(&storage_header(1).to_bytes()[..]);
+        v.extend_from_slice(&packet0);
+        let record_len = v.len();
+
+        for seeking in [false, true] {
+            let mut decoder = StorageRecordDecoder::new(seeking);
+            let mut data = &v[..];
+            let (result, buf) = decode(&mut decoder, &mut data);
+            assert_eq!(storage_header(1), result.unwrap().unwrap());
+            assert_eq!(packet0, buf);
+            assert_eq!(1, decoder.num_read_packets);
+            assert_eq!(record_len as u64, decoder.offset);
+            assert_eq!(0, decoder.record_offset);
+
+            // end of data
+            assert!(decode(&mut decoder, &mut data).0.is_none());
+        }
+
+        // seeking decoder skips corrupted data & packets
+        {
+            let mut data = Vec::from(&[0, 0][..]);
+            data.extend_from_slice(&storage_header(1).to_bytes());
+            // version 7 is not supported
+            data.extend_from_slice(&[0b1110_0000, 0, 0, 4]);
+            data.extend_from_slice(&v);
+
+            let mut decoder = StorageRecordDecoder::new(true);
+            let mut slice = &data[..];
+            let (result, buf) = decode(&mut decoder, &mut slice);
+            assert_eq!(storage_header(1), result.unwrap().unwrap());
+            assert_eq!(packet0, buf);
+            assert_eq!(1, decoder.num_pattern_seeks);
+            assert_eq!((data.len() - record_len) as u64, decoder.record_offset);
+        }
+
+        // strict decoder stops on corrupted data
+        {
+            let mut data = Vec::from(&storage_header(1).to_bytes()[..]);
+            data.extend_from_slice(&[0b1110_0000, 0, 0, 4]);
+            let mut decoder = StorageRecordDecoder::new(false);
+            let mut slice = &data[..];
+            assert_matches!(
+                decode(&mut decoder, &mut slice).0,
+                Some(Err(ReadError::UnsupportedDltVersion(_)))
+            );
+            assert_eq!(0, decoder.record_offset);
+            assert!(decoder.read_error);
+            assert!(decode(&mut decoder, &mut slice).0.is_none());
+        }
+
+        // io errors
+        for seeking in [false, true] {
+            let mut decoder = StorageRecordDecoder::new(seeking);
+            let mut data = &v[..v.len() - 1];
+            let result = decode(&mut decoder, &mut data).0;
+            if seeking {
+                assert!(result.is_none());
+            } else {
+                assert_matches!(result, Some(Err(ReadError::IoError(_))));
+                assert_eq!(0, decoder.record_offset);
+            }
+            assert!(decoder.read_error);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::StorageHeader;
+use crate::error::{Layer, StorageSliceError, UnexpectedEndOfSliceError};
 use crate::DltPacketSlice;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -6,3 +7,98 @@ pub struct StorageSlice<'a> {
     pub storage_header: StorageHeader,
     pub packet: DltPacketSlice<'a>,
 }
+
+impl<'a> StorageSlice<'a> {
+    /// Decodes the storage header & slices the DLT packet following it
+    /// from the start of the given slice.
+    ///
+    /// The number of bytes used by the storage header & packet can be
+    /// determined via [`StorageSlice::consumed_len`].
+    pub fn from_slice(slice: &'a [u8]) -> Result<StorageSlice<'a>, StorageSliceError> {
+        if slice.len() < StorageHeader::BYTE_LEN {
+            return Err(StorageSliceError::UnexpectedEndOfSlice(
+                UnexpectedEndOfSliceError {
+                    layer: Layer::StorageHeader,
+                    minimum_size: StorageHeader::BYTE_LEN,
+                    actual_size: slice.len(),
+                },
+            ));
+        }
+        let mut header_bytes = [0u8; StorageHeader::BYTE_LEN];
+        header_bytes.copy_from_slice(&slice[..StorageHeader::BYTE_LEN]);
+        let storage_header = StorageHeader::from_bytes(header_bytes)?;
+        let packet = DltPacketSlice::from_slice(&slice[StorageHeader::BYTE_LEN..])?;
+        Ok(StorageSlice {
+            storage_header,
+            packet,
+        })
+    }
+
+    /// Length of the storage header plus the DLT packet in bytes.
+    #[inline]
+    pub fn consumed_len(&self) -> usize {
+        StorageHeader::BYTE_LEN + self.packet.slice().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{PacketSliceError, StorageHeaderStartPatternError};
+    use crate::test_packets::{packet, storage_entry, storage_header};
+
+    #[test]
+    fn from_slice() {
+        let storage_header = storage_header(1);
+        let mut data = storage_entry(&storage_header, &packet(0, &[1, 2, 3, 4]));
+        let len = data.len();
+        // additional data after the packet
+        data.extend_from_slice(&[5, 6]);
+
+        // ok
+        {
+            let actual = StorageSlice::from_slice(&data).unwrap();
+            assert_eq!(storage_header, actual.storage_header);
+            assert_eq!(&data[StorageHeader::BYTE_LEN..len], actual.packet.slice());
+            assert_eq!(len, actual.consumed_len());
+        }
+
+        // storage header too short
+        for l in 0..StorageHeader::BYTE_LEN {
+            assert_eq!(
+                Err(StorageSliceError::UnexpectedEndOfSlice(
+                    UnexpectedEndOfSliceError {
+                        layer: Layer::StorageHeader,
+                        minimum_size: StorageHeader::BYTE_LEN,
+                        actual_size: l,
+                    }
+                )),
+                StorageSlice::from_slice(&data[..l])
+            );
+        }
+
+        // packet too short
+        for l in StorageHeader::BYTE_LEN..len {
+            assert_matches!(
+                StorageSlice::from_slice(&data[..l]),
+                Err(StorageSliceError::Packet(
+                    PacketSliceError::UnexpectedEndOfSlice(_)
+                ))
+            );
+        }
+
+        // bad start pattern
+        {
+            let mut data = data.clone();
+            data[3] = 2;
+            assert_eq!(
+                Err(StorageSliceError::StorageHeaderStartPattern(
+                    StorageHeaderStartPatternError {
+                        actual_pattern: [0x44, 0x4C, 0x54, 0x02],
+                    }
+                )),
+                StorageSlice::from_slice(&data)
+            );
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use super::StorageSlice;
+use crate::error::StorageSliceError;
+
+/// Iterator over the storage headers & DLT packets in a slice (e.g. the
+/// content of a memory mapped .dlt file) without copying the data.
+///
+/// Every entry is expected to start with a storage header. The iteration
+/// ends after the first error (e.g. a missing storage header pattern or a
+/// partial entry at the end of the slice).
+///
+/// # Example
+/// ```
+/// use dlt_parse::storage::StorageSliceIterator;
+///
+/// # let data: &[u8] = &[];
+/// for entry in StorageSliceIterator::new(data) {
+///     match entry {
+///         Ok(entry) => println!("{:?} {:?}", entry.storage_header, entry.packet),
+///         Err(err) => println!("error: {}", err),
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageSliceIterator<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> StorageSliceIterator<'a> {
+    #[inline]
+    pub fn new(slice: &'a [u8]) -> StorageSliceIterator<'a> {
+        StorageSliceIterator { slice }
+    }
+
+    /// Returns the slice of data still left in the iterator.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns an iterator that additionally yields the byte offset of the
+    /// start of each entry (storage header).
+    ///
+    /// The offsets are relative to the start of the data still left in the
+    /// iterator (the original buffer if called on a newly created iterator).
+    /// Together with [`StorageSlice::consumed_len`] this gives the byte
+    /// range of each entry, e.g. to copy entries without re-encoding them.
+    pub fn with_offsets(
+        self,
+    ) -> impl Iterator<Item = (usize, Result<StorageSlice<'a>, StorageSliceError>)> + 'a {
+        let total_len = self.slice.len();
+        let mut iter = self;
+        core::iter::from_fn(move || {
+            let offset = total_len - iter.slice.len();
+            iter.next().map(|result| (offset, result))
+        })
+    }
+}
+
+impl<'a> Iterator for StorageSliceIterator<'a> {
+    type Item = Result<StorageSlice<'a>, StorageSliceError>;
+
+    fn next(&mut self) -> Option<Result<StorageSlice<'a>, StorageSliceError>> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let result = StorageSlice::from_slice(self.slice);
+        match &result {
+            Ok(value) => {
+                self.slice = &self.slice[value.consumed_len()..];
+            }
+            Err(_) => {
+                // error => move the slice to an len = 0 position so that the iterator ends
+                self.slice = &self.slice[self.slice.len()..];
+            }
+        }
+        Some(result)
+    }
+}
+
+// The iterator is fused as after an error or the end of the data is
+// reached the slice is empty & `None` is returned for all following calls.
+impl<'a> core::iter::FusedIterator for StorageSliceIterator<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{PacketSliceError, StorageHeaderStartPatternError};
+    use crate::storage::StorageHeader;
+    use crate::test_packets::{packet, storage_entry, storage_header};
+    use alloc::{format, vec::Vec};
+
+    fn entry(message_counter: u8) -> Vec<u8> {
+        storage_entry(
+            &storage_header(u32::from(message_counter)),
+            &packet(message_counter, &[1, 2, 3, 4]),
+        )
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let it = StorageSliceIterator::new(&[]);
+        assert_eq!(it, it.clone());
+        assert_eq!(
+            format!("StorageSliceIterator {{ slice: {:?} }}", it.slice()),
+            format!("{:?}", it)
+        );
+    }
+
+    #[test]
+    fn with_offsets() {
+        let mut data = Vec::new();
+        let mut ranges = Vec::new();
+        for i in 0..3u8 {
+            let start = data.len();
+            data.extend_from_slice(&entry(i));
+            // extend the payload so the entries have different lengths
+            let len_pos = start + StorageHeader::BYTE_LEN + 2;
+            let length = u16::from_be_bytes([data[len_pos], data[len_pos + 1]]) + u16::from(i);
+            data[len_pos..len_pos + 2].copy_from_slice(&length.to_be_bytes());
+            data.extend_from_slice(&[0; 4][..usize::from(i)]);
+            ranges.push(start..data.len());
+        }
+
+        // offsets & lengths match the entry ranges
+        {
+            let mut it = StorageSliceIterator::new(&data).with_offsets();
+            for (i, range) in ranges.iter().enumerate() {
+                let (offset, e) = it.next().unwrap();
+                let e = e.unwrap();
+                assert_eq!(range.start, offset);
+                assert_eq!(range.end - range.start, e.consumed_len());
+                assert_eq!(i as u8, e.packet.header().message_counter);
+                assert_eq!(
+                    Ok(e.clone()),
+                    StorageSlice::from_slice(&data[offset..offset + e.consumed_len()])
+                );
+            }
+            assert!(it.next().is_none());
+        }
+
+        // offsets relative to the data left in the iterator & error offset
+        {
+            let mut it = StorageSliceIterator::new(&data[..data.len() - 1]);
+            assert!(it.next().unwrap().is_ok());
+            let mut it = it.with_offsets();
+            let (offset, e) = it.next().unwrap();
+            assert_eq!(0, offset);
+            assert!(e.is_ok());
+            let (offset, e) = it.next().unwrap();
+            assert_eq!(ranges[2].start - ranges[1].start, offset);
+            assert!(e.is_err());
+            assert!(it.next().is_none());
+        }
+    }
+
+    #[test]
+    fn iterator() {
+        let mut data = Vec::new();
+        for i in 0..3 {
+            data.extend_from_slice(&entry(i));
+        }
+
+        // ok
+        {
+            let mut it = StorageSliceIterator::new(&data);
+            for i in 0..3u8 {
+                let e = it.next().unwrap().unwrap();
+                assert_eq!(u32::from(i), e.storage_header.timestamp_seconds);
+                assert_eq!(i, e.packet.header().message_counter);
+            }
+            assert!(it.slice().is_empty());
+            assert_eq!(None, it.next());
+            assert_eq!(None, it.next());
+        }
+
+        // partial record at the end
+        {
+            let mut it = StorageSliceIterator::new(&data[..data.len() - 1]);
+            assert!(it.next().unwrap().is_ok());
+            assert!(it.next().unwrap().is_ok());
+            assert_matches!(
+                it.next(),
+                Some(Err(StorageSliceError::Packet(
+                    PacketSliceError::UnexpectedEndOfSlice(_)
+                )))
+            );
+            assert!(it.slice().is_empty());
+            assert_eq!(None, it.next());
+        }
+
+        // partial storage header at the end
+        {
+            let end = data.len() - entry(0).len() + 3;
+            let mut it = StorageSliceIterator::new(&data[..end]);
+            assert!(it.next().unwrap().is_ok());
+            assert!(it.next().unwrap().is_ok());
+            assert_matches!(
+                it.next(),
+                Some(Err(StorageSliceError::UnexpectedEndOfSlice(_)))
+            );
+            assert_eq!(None, it.next());
+        }
+
+        // bad start pattern
+        {
+            let mut data = data.clone();
+            let second = entry(0).len();
+            data[second] = 0;
+            let mut it = StorageSliceIterator::new(&data);
+            assert!(it.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(StorageSliceError::StorageHeaderStartPattern(
+                    StorageHeaderStartPatternError {
+                        actual_pattern: [0, 0x4C, 0x54, 0x01],
+                    }
+                ))),
+                it.next()
+            );
+            assert_eq!(None, it.next());
+        }
+    }
+}
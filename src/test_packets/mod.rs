@@ -0,0 +1,63 @@
+//! Helpers to create DLT packets & storage entries in tests.
+
+use super::*;
+use alloc::vec::Vec;
+
+/// DLT header used as base for test messages (the `length` &
+/// `extended_header` fields are expected to be set by the caller).
+pub fn header(is_big_endian: bool) -> DltHeader {
+    DltHeader {
+        is_big_endian,
+        message_counter: 123,
+        length: 0,
+        ecu_id: Some(*b"ECU1"),
+        session_id: None,
+        timestamp: Some(1234),
+        extended_header: None,
+    }
+}
+
+/// Extended header of a log message with the given log level.
+pub fn log_extended_header(log_level: DltLogLevel) -> DltExtendedHeader {
+    DltExtendedHeader::new_non_verbose_log(log_level, *b"APP1", *b"CTX1")
+}
+
+/// Serialized big endian DLT packet without optional header fields &
+/// with the given payload.
+pub fn packet(message_counter: u8, payload: &[u8]) -> Vec<u8> {
+    packet_with_endianness(true, message_counter, payload)
+}
+
+/// Serialized DLT packet with the given endianness, without optional
+/// header fields & with the given payload.
+pub fn packet_with_endianness(is_big_endian: bool, message_counter: u8, payload: &[u8]) -> Vec<u8> {
+    let mut header = DltHeader {
+        is_big_endian,
+        message_counter,
+        length: 0,
+        ecu_id: None,
+        session_id: None,
+        timestamp: None,
+        extended_header: None,
+    };
+    header.length = header.header_len() + payload.len() as u16;
+    let mut packet = Vec::from(&header.to_bytes()[..]);
+    packet.extend_from_slice(payload);
+    packet
+}
+
+/// Storage header with the given seconds & the ecu id `ECU1`.
+pub fn storage_header(timestamp_seconds: u32) -> storage::StorageHeader {
+    storage::StorageHeader {
+        timestamp_seconds,
+        timestamp_microseconds: 2,
+        ecu_id: *b"ECU1",
+    }
+}
+
+/// Serialized storage entry (storage header followed by the packet).
+pub fn storage_entry(storage_header: &storage::StorageHeader, packet: &[u8]) -> Vec<u8> {
+    let mut entry = Vec::from(&storage_header.to_bytes()[..]);
+    entry.extend_from_slice(packet);
+    entry
+}
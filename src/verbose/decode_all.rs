@@ -0,0 +1,127 @@
+use super::VerboseValue;
+use crate::error::VerboseDecodeError;
+
+/// Decodes all verbose values in the given payload into `out` and returns
+/// the number of decoded values (no allocations needed).
+///
+/// The payload is decoded until no data is left. In case `out` is too
+/// small to hold all values a [`VerboseDecodeError::OutputBufferTooSmall`]
+/// error is returned. The number of arguments field of the extended header
+/// can be used to determine the needed buffer size in advance.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::verbose::{decode_all, BoolValue, VerboseValue};
+///
+/// // payload containing the bool values "true" & "false"
+/// let payload = [0x11, 0, 0, 0, 1, 0x11, 0, 0, 0, 0];
+///
+/// let mut values = [(); 4].map(|_| VerboseValue::Bool(BoolValue { name: None, value: false }));
+/// let len = decode_all(&payload, true, &mut values).unwrap();
+/// assert_eq!(2, len);
+/// assert_eq!(
+///     VerboseValue::Bool(BoolValue { name: None, value: true }),
+///     values[0]
+/// );
+/// ```
+pub fn decode_all<'a>(
+    payload: &'a [u8],
+    is_big_endian: bool,
+    out: &mut [VerboseValue<'a>],
+) -> Result<usize, VerboseDecodeError> {
+    let mut rest = payload;
+    let mut len = 0;
+    while false == rest.is_empty() {
+        if len >= out.len() {
+            return Err(VerboseDecodeError::OutputBufferTooSmall {
+                capacity: out.len(),
+            });
+        }
+        let (value, next) = VerboseValue::from_slice(rest, is_big_endian)?;
+        out[len] = value;
+        len += 1;
+        rest = next;
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Layer, UnexpectedEndOfSliceError};
+    use crate::verbose::{BoolValue, StringValue, U16Value};
+    use arrayvec::ArrayVec;
+
+    fn placeholder() -> VerboseValue<'static> {
+        VerboseValue::Bool(BoolValue {
+            name: None,
+            value: false,
+        })
+    }
+
+    #[test]
+    fn decode() {
+        for is_big_endian in [false, true] {
+            let values = [
+                VerboseValue::U16(U16Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 0x1234,
+                }),
+                VerboseValue::Str(StringValue::new(Some("name"), "text")),
+                VerboseValue::Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+            ];
+            let mut payload = ArrayVec::<u8, 64>::new();
+            for value in &values {
+                value.add_to_msg(&mut payload, is_big_endian).unwrap();
+            }
+
+            // buffer big enough
+            {
+                let mut out = [(); 4].map(|_| placeholder());
+                assert_eq!(Ok(3), decode_all(&payload, is_big_endian, &mut out));
+                assert_eq!(&values[..], &out[..3]);
+                assert_eq!(placeholder(), out[3]);
+
+                let mut out = [(); 3].map(|_| placeholder());
+                assert_eq!(Ok(3), decode_all(&payload, is_big_endian, &mut out));
+                assert_eq!(&values[..], &out[..]);
+            }
+
+            // empty payload
+            assert_eq!(Ok(0), decode_all(&[], is_big_endian, &mut []));
+
+            // buffer too small
+            {
+                let mut out = [(); 2].map(|_| placeholder());
+                assert_eq!(
+                    Err(VerboseDecodeError::OutputBufferTooSmall { capacity: 2 }),
+                    decode_all(&payload, is_big_endian, &mut out)
+                );
+                assert_eq!(
+                    Err(VerboseDecodeError::OutputBufferTooSmall { capacity: 0 }),
+                    decode_all(&payload, is_big_endian, &mut [])
+                );
+            }
+
+            // decoding error
+            {
+                let mut out = [(); 4].map(|_| placeholder());
+                assert_eq!(
+                    Err(VerboseDecodeError::UnexpectedEndOfSlice(
+                        UnexpectedEndOfSliceError {
+                            layer: Layer::VerboseValue,
+                            minimum_size: 5,
+                            actual_size: 4,
+                        }
+                    )),
+                    decode_all(&payload[..payload.len() - 1], is_big_endian, &mut out)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use arrayvec::{ArrayVec, CapacityError};
+
+/// Buffer verbose values can be encoded to (e.g. via
+/// [`super::VerboseValue::add_to_msg`]).
+///
+/// Implemented for [`ArrayVec<u8, CAP>`] and, if the `alloc` feature
+/// is activated, for `Vec<u8>`. The trait is sealed (can not be
+/// implemented outside of this crate) as the value encoders rely on
+/// `remaining_capacity` to decide when `push_unchecked` is safe to call.
+pub trait EncodeBuf: sealed::Sealed {
+    /// Number of bytes that can still be added to the buffer.
+    fn remaining_capacity(&self) -> usize;
+
+    /// Appends all bytes of the given slice or returns an error (without
+    /// modifying the buffer) if the remaining capacity is too small.
+    fn try_extend_from_slice(&mut self, other: &[u8]) -> Result<(), CapacityError>;
+
+    /// Appends a byte without checking the remaining capacity.
+    ///
+    /// # Safety
+    ///
+    /// The remaining capacity has to be at least 1.
+    unsafe fn push_unchecked(&mut self, value: u8);
+}
+
+pub(crate) mod sealed {
+    /// Supertrait of [`super::EncodeBuf`] that can only be implemented
+    /// inside of this crate.
+    pub trait Sealed {}
+}
+
+impl<const CAP: usize> sealed::Sealed for ArrayVec<u8, CAP> {}
+
+impl<const CAP: usize> EncodeBuf for ArrayVec<u8, CAP> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        ArrayVec::remaining_capacity(self)
+    }
+
+    #[inline]
+    fn try_extend_from_slice(&mut self, other: &[u8]) -> Result<(), CapacityError> {
+        ArrayVec::try_extend_from_slice(self, other)
+    }
+
+    #[inline]
+    unsafe fn push_unchecked(&mut self, value: u8) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { ArrayVec::push_unchecked(self, value) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for alloc::vec::Vec<u8> {}
+
+#[cfg(feature = "alloc")]
+impl EncodeBuf for alloc::vec::Vec<u8> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        // a vec can grow up to isize::MAX bytes
+        (isize::MAX as usize) - self.len()
+    }
+
+    #[inline]
+    fn try_extend_from_slice(&mut self, other: &[u8]) -> Result<(), CapacityError> {
+        if EncodeBuf::remaining_capacity(self) < other.len() {
+            return Err(CapacityError::new(()));
+        }
+        self.extend_from_slice(other);
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn push_unchecked(&mut self, value: u8) {
+        self.push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    fn fill<B: EncodeBuf>(buf: &mut B) -> Result<(), CapacityError> {
+        buf.try_extend_from_slice(&[1, 2])?;
+        if buf.remaining_capacity() > 0 {
+            // SAFETY: Capacity checked above.
+            unsafe { buf.push_unchecked(3) };
+        }
+        buf.try_extend_from_slice(&[4, 5])
+    }
+
+    #[test]
+    fn array_vec() {
+        let mut buf = ArrayVec::<u8, 5>::new();
+        assert_eq!(Ok(()), fill(&mut buf));
+        assert_eq!(&[1, 2, 3, 4, 5], &buf[..]);
+        assert_eq!(0, EncodeBuf::remaining_capacity(&buf));
+
+        // capacity error leaves the buffer unchanged
+        let mut buf = ArrayVec::<u8, 4>::new();
+        assert_eq!(Err(CapacityError::new(())), fill(&mut buf));
+        assert_eq!(&[1, 2, 3], &buf[..]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec() {
+        let mut buf = Vec::new();
+        assert_eq!(Ok(()), fill(&mut buf));
+        assert_eq!(&[1, 2, 3, 4, 5], &buf[..]);
+        assert_eq!(isize::MAX as usize - 5, EncodeBuf::remaining_capacity(&buf));
+    }
+}
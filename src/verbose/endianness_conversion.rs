@@ -0,0 +1,295 @@
+use super::*;
+use crate::error::VerboseDecodeError;
+use arrayvec::CapacityError;
+use core::ops::DerefMut;
+
+/// Errors that can occur when re-encoding verbose values with a
+/// different endianness.
+#[derive(Debug)]
+pub(crate) enum EndiannessConversionError {
+    /// Error while decoding the entries of a struct.
+    Decode(VerboseDecodeError),
+    /// Not enough space left in the target buffer.
+    Capacity(CapacityError),
+}
+
+impl From<VerboseDecodeError> for EndiannessConversionError {
+    fn from(err: VerboseDecodeError) -> Self {
+        EndiannessConversionError::Decode(err)
+    }
+}
+
+impl From<CapacityError> for EndiannessConversionError {
+    fn from(err: CapacityError) -> Self {
+        EndiannessConversionError::Capacity(err)
+    }
+}
+
+impl From<EndiannessConversionError> for std::io::Error {
+    fn from(err: EndiannessConversionError) -> Self {
+        use EndiannessConversionError::*;
+        match err {
+            Decode(err) => std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+            Capacity(err) => std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+        }
+    }
+}
+
+/// Adds the verbose value to the given dlt message buffer encoded with
+/// the given endianness.
+///
+/// In contrast to [`VerboseValue::add_to_msg`] the raw data of arrays
+/// (dimensions & elements) & the entries of structs are converted as well
+/// if they were decoded with a different endianness.
+pub(crate) fn add_converted_to_msg<B: EncodeBuf + DerefMut<Target = [u8]>>(
+    value: &VerboseValue<'_>,
+    buf: &mut B,
+    is_big_endian: bool,
+) -> Result<(), EndiannessConversionError> {
+    use VerboseValue::*;
+
+    let start = buf.len();
+    match value {
+        ArrBool(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 1, is_big_endian);
+        }
+        ArrI8(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 1, is_big_endian);
+        }
+        ArrI16(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 2, is_big_endian);
+        }
+        ArrI32(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 4, is_big_endian);
+        }
+        ArrI64(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 8, is_big_endian);
+        }
+        ArrI128(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 16, is_big_endian);
+        }
+        ArrU8(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 1, is_big_endian);
+        }
+        ArrU16(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 2, is_big_endian);
+        }
+        ArrU32(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 4, is_big_endian);
+        }
+        ArrU64(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 8, is_big_endian);
+        }
+        ArrU128(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 16, is_big_endian);
+        }
+        ArrF16(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 2, is_big_endian);
+        }
+        ArrF32(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 4, is_big_endian);
+        }
+        ArrF64(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 8, is_big_endian);
+        }
+        ArrF128(v) => {
+            v.add_to_msg(buf, is_big_endian)?;
+            swap_array(buf, start, &v.dimensions, v.data.len(), 16, is_big_endian);
+        }
+        Struct(v) => {
+            if v.is_big_endian == is_big_endian {
+                v.add_to_msg(buf, is_big_endian)?;
+            } else {
+                // write the struct header without the entries & add
+                // the converted entries afterwards
+                StructValue {
+                    is_big_endian,
+                    number_of_entries: v.number_of_entries,
+                    name: v.name,
+                    entries_data: &[],
+                }
+                .add_to_msg(buf, is_big_endian)?;
+                for entry in v.entries() {
+                    add_converted_to_msg(&entry?, buf, is_big_endian)?;
+                }
+            }
+        }
+        // all other values are fully decoded & re-encoded by add_to_msg
+        other => other.add_to_msg(buf, is_big_endian)?,
+    }
+    Ok(())
+}
+
+/// Byte swaps the dimensions & elements of an array written to `buf`
+/// at `start` if the array was decoded with a different endianness.
+fn swap_array(
+    buf: &mut [u8],
+    start: usize,
+    dimensions: &ArrayDimensions<'_>,
+    data_len: usize,
+    element_size: usize,
+    is_big_endian: bool,
+) {
+    if dimensions.is_big_endian == is_big_endian {
+        return;
+    }
+
+    // dimensions follow the type info & number of dimensions
+    let dims_start = start + 6;
+    for dim in buf[dims_start..dims_start + dimensions.dimensions.len()].chunks_exact_mut(2) {
+        dim.reverse();
+    }
+
+    // elements are written at the end
+    if element_size > 1 {
+        let data_start = buf.len() - data_len;
+        for element in buf[data_start..].chunks_exact_mut(element_size) {
+            element.reverse();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrayvec::ArrayVec;
+
+    /// Encodes the values with the given endianness.
+    fn encode(values: &[VerboseValue], is_big_endian: bool) -> ArrayVec<u8, 1024> {
+        let mut buf = ArrayVec::new();
+        for value in values {
+            value.add_to_msg(&mut buf, is_big_endian).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn convert_values() {
+        let dims_le = 3u16.to_le_bytes();
+        let dims_be = 3u16.to_be_bytes();
+        let mut i32_le = Vec::new();
+        let mut i32_be = Vec::new();
+        let mut f16_le = Vec::new();
+        let mut f16_be = Vec::new();
+        for v in [1i32, -2, 0x1234_5678] {
+            i32_le.extend_from_slice(&v.to_le_bytes());
+            i32_be.extend_from_slice(&v.to_be_bytes());
+        }
+        for v in [0x3c00u16, 0xc000, 0x7bff] {
+            f16_le.extend_from_slice(&v.to_le_bytes());
+            f16_be.extend_from_slice(&v.to_be_bytes());
+        }
+        let entries = [
+            VerboseValue::U16(U16Value {
+                variable_info: None,
+                scaling: None,
+                value: 0x1234,
+            }),
+            VerboseValue::Str(StringValue::new(Some("s"), "text")),
+        ];
+        let entries_le = encode(&entries, false);
+        let entries_be = encode(&entries, true);
+
+        let values = |is_big_endian: bool| {
+            let (dims, i32_data, f16_data, entries_data) = if is_big_endian {
+                (&dims_be, &i32_be, &f16_be, &entries_be)
+            } else {
+                (&dims_le, &i32_le, &f16_le, &entries_le)
+            };
+            [
+                VerboseValue::U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 0x1234_5678,
+                }),
+                VerboseValue::ArrI32(ArrayI32 {
+                    is_big_endian,
+                    dimensions: ArrayDimensions {
+                        is_big_endian,
+                        dimensions: &dims[..],
+                    },
+                    variable_info: Some(VariableInfoUnit {
+                        name: "n",
+                        unit: "u",
+                    }),
+                    scaling: Some(Scaling {
+                        quantization: 0.5,
+                        offset: 0x0102_0304,
+                    }),
+                    data: &i32_data[..],
+                }),
+                VerboseValue::ArrF16(ArrayF16 {
+                    is_big_endian,
+                    dimensions: ArrayDimensions {
+                        is_big_endian,
+                        dimensions: &dims[..],
+                    },
+                    variable_info: None,
+                    data: &f16_data[..],
+                }),
+                VerboseValue::Struct(StructValue {
+                    is_big_endian,
+                    number_of_entries: 2,
+                    name: Some("st"),
+                    entries_data: &entries_data[..],
+                }),
+            ]
+        };
+        let values_le = values(false);
+        let values_be = values(true);
+        let bytes_le = encode(&values_le, false);
+        let bytes_be = encode(&values_be, true);
+
+        // conversion in both directions
+        for (source, expected, is_big_endian) in [
+            (&values_le, &bytes_be, true),
+            (&values_be, &bytes_le, false),
+            (&values_le, &bytes_le, false),
+            (&values_be, &bytes_be, true),
+        ] {
+            let mut buf = ArrayVec::<u8, 1024>::new();
+            for value in source.iter() {
+                add_converted_to_msg(value, &mut buf, is_big_endian).unwrap();
+            }
+            assert_eq!(&expected[..], &buf[..]);
+        }
+
+        // capacity error
+        {
+            let mut buf = ArrayVec::<u8, 4>::new();
+            assert_matches!(
+                add_converted_to_msg(&values_le[1], &mut buf, true),
+                Err(EndiannessConversionError::Capacity(_))
+            );
+        }
+
+        // decoding error in struct entries
+        {
+            let value = VerboseValue::Struct(StructValue {
+                is_big_endian: false,
+                number_of_entries: 1,
+                name: None,
+                entries_data: &[],
+            });
+            let mut buf = ArrayVec::<u8, 64>::new();
+            assert_matches!(
+                add_converted_to_msg(&value, &mut buf, true),
+                Err(EndiannessConversionError::Decode(_))
+            );
+        }
+    }
+}
@@ -2,19 +2,41 @@ use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
 
 use super::{ArrayDimensions, RawF128, RawF16, Scaling};
 
-/// Helper for parsing verbose messages.
+/// Helper for parsing verbose messages (& other payloads consisting of
+/// fields, like control message payloads).
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct FieldSlicer<'a> {
     /// Unparsed part of the verbose message.
     rest: &'a [u8],
 
     /// Offset since the parsing has started.
     offset: usize,
+
+    /// Layer reported in length errors.
+    layer: Layer,
 }
 
 impl<'a> FieldSlicer<'a> {
     #[inline]
     pub fn new(data: &[u8], offset: usize) -> FieldSlicer {
-        FieldSlicer { rest: data, offset }
+        FieldSlicer::with_layer(data, offset, Layer::VerboseValue)
+    }
+
+    /// Creates a slicer that reports the given layer in length errors.
+    #[inline]
+    pub fn with_layer(data: &'a [u8], offset: usize, layer: Layer) -> FieldSlicer<'a> {
+        FieldSlicer {
+            rest: data,
+            offset,
+            layer,
+        }
+    }
+
+    /// Number of bytes read since the parsing has started (including
+    /// the start offset).
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
     }
 
     #[inline]
@@ -28,7 +50,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.is_empty() {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 1,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -56,7 +78,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 2 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 2,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -81,7 +103,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 4 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 4,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -113,7 +135,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 8 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 8,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -149,7 +171,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 16 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 16,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -302,7 +324,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 2 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 2,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -323,7 +345,7 @@ impl<'a> FieldSlicer<'a> {
         let total_size = 2 + name_length;
         if self.rest.len() < total_size {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + total_size,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -376,7 +398,7 @@ impl<'a> FieldSlicer<'a> {
         // check length
         if self.rest.len() < 4 {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + 4,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -406,7 +428,7 @@ impl<'a> FieldSlicer<'a> {
         let total_size = 4 + name_length + unit_length;
         if self.rest.len() < total_size {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + total_size,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -482,7 +504,7 @@ impl<'a> FieldSlicer<'a> {
         // check that the string length is present
         if self.rest.len() < len {
             return Err(UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
-                layer: Layer::VerboseValue,
+                layer: self.layer.clone(),
                 minimum_size: self.offset + len,
                 actual_size: self.offset + self.rest.len(),
             }));
@@ -774,6 +796,7 @@ mod test_field_slicer {
                 let mut slicer = FieldSlicer{
                     rest: &data[..slice_len],
                     offset,
+                    layer: Layer::VerboseValue,
                 };
                 prop_assert_eq!(
                     slicer.read_u8(),
@@ -787,6 +810,7 @@ mod test_field_slicer {
                 let mut slicer = FieldSlicer{
                     rest: &[],
                     offset,
+                    layer: Layer::VerboseValue,
                 };
                 prop_assert_eq!(
                     slicer.read_u8(),
@@ -815,6 +839,7 @@ mod test_field_slicer {
                 let mut slicer = FieldSlicer{
                     rest: &data[..slice_len],
                     offset,
+                    layer: Layer::VerboseValue,
                 };
                 prop_assert_eq!(
                     slicer.read_i8(),
@@ -828,6 +853,7 @@ mod test_field_slicer {
                 let mut slicer = FieldSlicer{
                     rest: &[],
                     offset,
+                    layer: Layer::VerboseValue,
                 };
                 prop_assert_eq!(
                     slicer.read_i8(),
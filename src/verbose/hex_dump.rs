@@ -0,0 +1,54 @@
+/// Formats a byte slice as space separated lower case hex values
+/// (e.g. `01 ab ff`) when displayed.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::verbose::HexDump;
+///
+/// assert_eq!("01 ab ff", format!("{}", HexDump(&[0x01, 0xab, 0xff])));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+impl core::fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut iter = self.0.iter();
+        if let Some(first) = iter.next() {
+            write!(f, "{:02x}", first)?;
+            for b in iter {
+                write!(f, " {:02x}", b)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::String, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn display() {
+        assert_eq!("", format!("{}", HexDump(&[])));
+        assert_eq!("00", format!("{}", HexDump(&[0])));
+        assert_eq!(
+            "00 0f a0 ff",
+            format!("{}", HexDump(&[0x00, 0x0f, 0xa0, 0xff]))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn display_proptest(ref data in proptest::collection::vec(any::<u8>(), 0..40)) {
+            let expected = data
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<String>>()
+                .join(" ");
+            prop_assert_eq!(expected, format!("{}", HexDump(data)));
+        }
+    }
+}
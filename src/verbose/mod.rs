@@ -1,5 +1,19 @@
+#[cfg(feature = "std")]
+mod endianness_conversion;
+#[cfg(feature = "std")]
+pub(crate) use endianness_conversion::*;
+
+mod decode_all;
+pub use decode_all::*;
+
+mod hex_dump;
+pub use hex_dump::*;
+
+mod encode_buf;
+pub use encode_buf::*;
+
 mod field_slicer;
-use field_slicer::*;
+pub(crate) use field_slicer::*;
 
 mod values;
 pub use values::*;
@@ -10,6 +24,18 @@ pub use verbose_iter::*;
 mod pre_checked_verbose_iter;
 pub use pre_checked_verbose_iter::*;
 
+mod reencode;
+pub use reencode::*;
+
+mod tolerant_verbose_iter;
+pub use tolerant_verbose_iter::*;
+
+mod verbose_scalar;
+pub use verbose_scalar::*;
+
+mod verbose_scalar_buf;
+pub use verbose_scalar_buf::*;
+
 mod verbose_value;
 pub use verbose_value::*;
 
@@ -23,6 +49,68 @@ pub struct Scaling<T: Sized> {
     offset: T,
 }
 
+impl<T: Sized + Copy> Scaling<T> {
+    /// Factor the raw value is multiplied with.
+    #[inline]
+    pub fn quantization(&self) -> f32 {
+        self.quantization
+    }
+
+    /// Offset added to the multiplied raw value.
+    #[inline]
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+}
+
+impl Scaling<i32> {
+    /// Scales an already converted raw value (used by the array
+    /// iterators whose unsigned values do not fit into `apply`).
+    #[inline]
+    pub(crate) fn scale(&self, raw: f64) -> f64 {
+        raw * f64::from(self.quantization) + f64::from(self.offset)
+    }
+
+    /// Returns the physical value of the given raw integer value
+    /// (`raw * quantization + offset`).
+    #[inline]
+    pub fn apply(&self, raw: i32) -> f64 {
+        self.scale(f64::from(raw))
+    }
+}
+
+impl Scaling<i64> {
+    /// Scales an already converted raw value (used by the array
+    /// iterators whose unsigned values do not fit into `apply`).
+    #[inline]
+    pub(crate) fn scale(&self, raw: f64) -> f64 {
+        raw * f64::from(self.quantization) + self.offset as f64
+    }
+
+    /// Returns the physical value of the given raw integer value
+    /// (`raw * quantization + offset`).
+    #[inline]
+    pub fn apply(&self, raw: i64) -> f64 {
+        self.scale(raw as f64)
+    }
+}
+
+impl Scaling<i128> {
+    /// Scales an already converted raw value (used by the array
+    /// iterators whose unsigned values do not fit into `apply`).
+    #[inline]
+    pub(crate) fn scale(&self, raw: f64) -> f64 {
+        raw * f64::from(self.quantization) + self.offset as f64
+    }
+
+    /// Returns the physical value of the given raw integer value
+    /// (`raw * quantization + offset`).
+    #[inline]
+    pub fn apply(&self, raw: i128) -> f64 {
+        self.scale(raw as f64)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct VariableInfoUnit<'a> {
@@ -46,6 +134,25 @@ impl<'a> ArrayDimensions<'a> {
             rest: self.dimensions,
         }
     }
+
+    /// Returns the number of dimensions.
+    #[inline]
+    pub fn num_dimensions(&self) -> usize {
+        self.dimensions.len() / 2
+    }
+
+    /// Returns the number of elements in the array (product of all
+    /// dimensions, saturating at `usize::MAX`).
+    ///
+    /// An array without any dimensions is treated as containing no
+    /// elements.
+    pub fn total_element_count(&self) -> usize {
+        if self.dimensions.len() < 2 {
+            return 0;
+        }
+        self.iter()
+            .fold(1usize, |acc, dim| acc.saturating_mul(usize::from(dim)))
+    }
 }
 
 impl<'a> IntoIterator for &'a ArrayDimensions<'a> {
@@ -80,3 +187,81 @@ impl<'a> Iterator for ArrayDimensionIterator<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaling() {
+        // i32
+        {
+            let s = Scaling {
+                quantization: 0.5,
+                offset: -10i32,
+            };
+            assert_eq!(0.5, s.quantization());
+            assert_eq!(-10, s.offset());
+            assert_eq!(-10.0, s.apply(0));
+            assert_eq!(-8.5, s.apply(3));
+            assert_eq!(-12.5, s.apply(-5));
+            assert_eq!(s.scale(3.0), s.apply(3));
+        }
+        // i64
+        {
+            let s = Scaling {
+                quantization: 0.25,
+                offset: 1_000_000_000_000i64,
+            };
+            assert_eq!(0.25, s.quantization());
+            assert_eq!(1_000_000_000_000, s.offset());
+            assert_eq!(1_000_000_000_000.75, s.apply(3));
+            assert_eq!(999_999_999_999.0, s.apply(-4));
+        }
+        // i128
+        {
+            let s = Scaling {
+                quantization: 1.5,
+                offset: 2i128,
+            };
+            assert_eq!(1.5, s.quantization());
+            assert_eq!(2, s.offset());
+            assert_eq!(17.0, s.apply(10));
+            assert_eq!(-1.0, s.apply(-2));
+        }
+    }
+
+    #[test]
+    fn array_dimensions() {
+        // (dimensions, expected element count)
+        let tests: [(&[u16], usize); 5] = [
+            (&[], 0),
+            (&[3], 3),
+            (&[2, 3, 4], 24),
+            (&[2, 0, 4], 0),
+            (
+                &[u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX],
+                usize::MAX,
+            ),
+        ];
+        for (dims, expected) in tests {
+            for is_big_endian in [false, true] {
+                let mut buffer = ArrayVec::<u8, 10>::new();
+                for dim in dims {
+                    let bytes = if is_big_endian {
+                        dim.to_be_bytes()
+                    } else {
+                        dim.to_le_bytes()
+                    };
+                    buffer.try_extend_from_slice(&bytes).unwrap();
+                }
+                let value = ArrayDimensions {
+                    is_big_endian,
+                    dimensions: &buffer,
+                };
+                assert_eq!(dims.len(), value.num_dimensions());
+                assert_eq!(expected, value.total_element_count());
+            }
+        }
+    }
+}
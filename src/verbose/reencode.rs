@@ -0,0 +1,246 @@
+use super::{EncodeBuf, VerboseValue};
+use crate::error::{EncodeError, VerboseDecodeError};
+use arrayvec::CapacityError;
+
+/// Encodes a (previously decoded) verbose value & appends it to `buf`.
+///
+/// Values decoded via [`VerboseValue::from_slice`] from data that was
+/// encoded by [`VerboseValue::add_to_msg`] (or any other encoder that
+/// uses the same canonical layout) are guaranteed to be re-encoded to
+/// exactly the original bytes if the same endianness is used. This holds
+/// for all scalar, array, string, raw, trace info & struct values.
+///
+/// Data from other encoders can differ in details that are not part of
+/// the decoded value (e.g. strings without a zero termination or set
+/// reserved type info bits). Use [`reencodes_identically`] to check if
+/// this is the case for a given value.
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use dlt_parse::verbose::{reencode, U16Value, VerboseValue};
+///
+/// let mut original = ArrayVec::<u8, 16>::new();
+/// U16Value { variable_info: None, scaling: None, value: 1234 }
+///     .add_to_msg(&mut original, true)
+///     .unwrap();
+///
+/// let (value, _) = VerboseValue::from_slice(&original, true).unwrap();
+/// let mut buf = ArrayVec::<u8, 16>::new();
+/// reencode(&value, true, &mut buf).unwrap();
+/// assert_eq!(original, buf);
+/// ```
+#[inline]
+pub fn reencode<B: EncodeBuf>(
+    value: &VerboseValue<'_>,
+    is_big_endian: bool,
+    buf: &mut B,
+) -> Result<(), EncodeError> {
+    Ok(value.add_to_msg(buf, is_big_endian)?)
+}
+
+/// Decodes the first verbose value in `slice` & returns true if
+/// re-encoding it via [`reencode`] reproduces the decoded bytes exactly.
+///
+/// Intended for fuzzers & encoder tests. The re-encoded bytes are
+/// compared while they are written, so no buffer is needed.
+pub fn reencodes_identically(
+    slice: &[u8],
+    is_big_endian: bool,
+) -> Result<bool, VerboseDecodeError> {
+    let (value, rest) = VerboseValue::from_slice(slice, is_big_endian)?;
+    let decoded = &slice[..slice.len() - rest.len()];
+    if value.encoded_len() != decoded.len() {
+        return Ok(false);
+    }
+    let mut buf = CompareBuf {
+        expected: decoded,
+        len: 0,
+        is_identical: true,
+    };
+    Ok(reencode(&value, is_big_endian, &mut buf).is_ok()
+        && buf.is_identical
+        && buf.len == decoded.len())
+}
+
+/// Encode buffer that compares the written bytes against the expected
+/// bytes instead of storing them (capacity is the expected length).
+struct CompareBuf<'a> {
+    expected: &'a [u8],
+    len: usize,
+    is_identical: bool,
+}
+
+impl super::encode_buf::sealed::Sealed for CompareBuf<'_> {}
+
+impl EncodeBuf for CompareBuf<'_> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        self.expected.len() - self.len
+    }
+
+    #[inline]
+    fn try_extend_from_slice(&mut self, other: &[u8]) -> Result<(), CapacityError> {
+        if self.remaining_capacity() < other.len() {
+            return Err(CapacityError::new(()));
+        }
+        let end = self.len + other.len();
+        if &self.expected[self.len..end] != other {
+            self.is_identical = false;
+        }
+        self.len = end;
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn push_unchecked(&mut self, value: u8) {
+        // the remaining capacity is guaranteed by the caller
+        if self.expected[self.len] != value {
+            self.is_identical = false;
+        }
+        self.len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verbose::*;
+    use arrayvec::ArrayVec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn reencode_round_trip(
+            ref name in "\\pc{0,10}",
+            ref unit in "\\pc{0,10}",
+            ref data in proptest::collection::vec(any::<u8>(), 0..64),
+            value in any::<u128>(),
+            has_name in any::<bool>(),
+            has_scaling in any::<bool>(),
+            is_big_endian in any::<bool>(),
+        ) {
+            use VerboseValue::*;
+
+            let name = if has_name { Some(name.as_str()) } else { None };
+            let variable_info = name.map(|name| VariableInfoUnit { name, unit });
+            let scaling_i32 = if has_scaling {
+                Some(Scaling { quantization: 1.5, offset: value as i32 })
+            } else {
+                None
+            };
+            let scaling_i64 = scaling_i32.as_ref().map(|s| Scaling {
+                quantization: s.quantization,
+                offset: value as i64,
+            });
+            let scaling_i128 = scaling_i32.as_ref().map(|s| Scaling {
+                quantization: s.quantization,
+                offset: value as i128,
+            });
+
+            // array data & a matching dimension for each element size
+            let bools: std::vec::Vec<u8> = data.iter().map(|b| b & 1).collect();
+            let dims = [1usize, 2, 4, 8, 16].map(|size| {
+                let len = (data.len() / size) as u16;
+                if is_big_endian {
+                    len.to_be_bytes()
+                } else {
+                    len.to_le_bytes()
+                }
+            });
+            let array = |index: usize| {
+                let size = 1 << index;
+                (
+                    ArrayDimensions { is_big_endian, dimensions: &dims[index][..] },
+                    &data[..data.len() / size * size],
+                )
+            };
+            let (dims8, data8) = array(0);
+            let (dims16, data16) = array(1);
+            let (dims32, data32) = array(2);
+            let (dims64, data64) = array(3);
+            let (dims128, data128) = array(4);
+
+            // struct containing one bool value
+            let mut entries = ArrayVec::<u8, 64>::new();
+            BoolValue { name, value: true }.add_to_msg(&mut entries, is_big_endian).unwrap();
+
+            let values = [
+                Bool(BoolValue { name, value: 0 != value & 1 }),
+                Str(StringValue::new(name, unit)),
+                TraceInfo(TraceInfoValue { value: unit }),
+                I8(I8Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as i8 }),
+                I16(I16Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as i16 }),
+                I32(I32Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as i32 }),
+                I64(I64Value { variable_info: variable_info.clone(), scaling: scaling_i64.clone(), value: value as i64 }),
+                I128(I128Value { variable_info: variable_info.clone(), scaling: scaling_i128.clone(), value: value as i128 }),
+                U8(U8Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as u8 }),
+                U16(U16Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as u16 }),
+                U32(U32Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: value as u32 }),
+                U64(U64Value { variable_info: variable_info.clone(), scaling: scaling_i64.clone(), value: value as u64 }),
+                U128(U128Value { variable_info: variable_info.clone(), scaling: scaling_i128.clone(), value }),
+                F16(F16Value { variable_info: variable_info.clone(), value: RawF16::from_bits(value as u16) }),
+                F32(F32Value { variable_info: variable_info.clone(), value: f32::from_bits(value as u32) }),
+                F64(F64Value { variable_info: variable_info.clone(), value: f64::from_bits(value as u64) }),
+                F128(F128Value { variable_info: variable_info.clone(), value: RawF128::from_bits(value) }),
+                ArrBool(ArrayBool { dimensions: dims8.clone(), variable_info: variable_info.clone(), data: &bools[..] }),
+                ArrI8(ArrayI8 { dimensions: dims8.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data8 }),
+                ArrI16(ArrayI16 { is_big_endian, dimensions: dims16.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data16 }),
+                ArrI32(ArrayI32 { is_big_endian, dimensions: dims32.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data32 }),
+                ArrI64(ArrayI64 { is_big_endian, dimensions: dims64.clone(), variable_info: variable_info.clone(), scaling: scaling_i64.clone(), data: data64 }),
+                ArrI128(ArrayI128 { is_big_endian, dimensions: dims128.clone(), variable_info: variable_info.clone(), scaling: scaling_i128.clone(), data: data128 }),
+                ArrU8(ArrayU8 { dimensions: dims8.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data8 }),
+                ArrU16(ArrayU16 { is_big_endian, dimensions: dims16.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data16 }),
+                ArrU32(ArrayU32 { is_big_endian, dimensions: dims32.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data: data32 }),
+                ArrU64(ArrayU64 { is_big_endian, dimensions: dims64.clone(), variable_info: variable_info.clone(), scaling: scaling_i64.clone(), data: data64 }),
+                ArrU128(ArrayU128 { is_big_endian, dimensions: dims128.clone(), variable_info: variable_info.clone(), scaling: scaling_i128.clone(), data: data128 }),
+                ArrF16(ArrayF16 { is_big_endian, dimensions: dims16.clone(), variable_info: variable_info.clone(), data: data16 }),
+                ArrF32(ArrayF32 { is_big_endian, dimensions: dims32.clone(), variable_info: variable_info.clone(), data: data32 }),
+                ArrF64(ArrayF64 { is_big_endian, dimensions: dims64.clone(), variable_info: variable_info.clone(), data: data64 }),
+                ArrF128(ArrayF128 { is_big_endian, dimensions: dims128.clone(), variable_info: variable_info.clone(), data: data128 }),
+                Struct(StructValue { is_big_endian, number_of_entries: 1, name, entries_data: &entries[..] }),
+                Raw(RawValue { name, data }),
+            ];
+            for value in &values {
+                let mut original = ArrayVec::<u8, 256>::new();
+                value.add_to_msg(&mut original, is_big_endian).unwrap();
+
+                let (decoded, rest) = VerboseValue::from_slice(&original, is_big_endian).unwrap();
+                prop_assert!(rest.is_empty(), "{:?}", value);
+                let mut buf = ArrayVec::<u8, 256>::new();
+                reencode(&decoded, is_big_endian, &mut buf).unwrap();
+                prop_assert_eq!(&original, &buf, "{:?}", value);
+                prop_assert_eq!(Ok(true), reencodes_identically(&original, is_big_endian), "{:?}", value);
+            }
+        }
+    }
+
+    #[test]
+    fn reencode_capacity_error() {
+        let value = VerboseValue::U32(U32Value {
+            variable_info: None,
+            scaling: None,
+            value: 1,
+        });
+        let mut buf = ArrayVec::<u8, 4>::new();
+        assert_eq!(
+            Err(EncodeError::CapacityExceeded),
+            reencode(&value, true, &mut buf)
+        );
+    }
+
+    #[test]
+    fn reencodes_identically_non_canonical() {
+        // string without zero termination
+        let data = [0x00, 0x82, 0x00, 0x00, 0x00, 0x02, b'a', b'b'];
+        assert_eq!(Ok(false), reencodes_identically(&data, true));
+
+        // string with zero termination
+        let data = [0x00, 0x82, 0x00, 0x00, 0x00, 0x03, b'a', b'b', 0];
+        assert_eq!(Ok(true), reencodes_identically(&data, true));
+
+        // decoding error
+        assert!(reencodes_identically(&[], true).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use super::{VerboseIter, VerboseValue};
+use crate::error::VerboseArgumentError;
+
+/// Iterator over verbose values that returns all values up to the first
+/// argument that can not be decoded, followed by an error containing the
+/// index & byte offset of that argument.
+///
+/// Created via [`VerboseIter::tolerant`].
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::verbose::{BoolValue, VerboseIter, VerboseValue};
+///
+/// // a valid bool value followed by an invalid one
+/// let payload = [0x11, 0, 0, 0, 1, 0x11, 0, 0, 0, 2];
+///
+/// let mut iter = VerboseIter::new(true, 2, &payload).tolerant();
+/// assert_eq!(
+///     Some(Ok(VerboseValue::Bool(BoolValue { name: None, value: true }))),
+///     iter.next()
+/// );
+/// let err = iter.next().unwrap().unwrap_err();
+/// assert_eq!(1, err.index);
+/// assert_eq!(5, err.offset);
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TolerantVerboseIter<'a> {
+    iter: VerboseIter<'a>,
+    payload_len: usize,
+    index: u16,
+}
+
+impl<'a> TolerantVerboseIter<'a> {
+    /// Creates a new iterator based on a verbose iterator (offsets are
+    /// relative to the remaining data of the given iterator).
+    #[inline]
+    pub fn new(iter: VerboseIter<'a>) -> TolerantVerboseIter<'a> {
+        TolerantVerboseIter {
+            payload_len: iter.raw().len(),
+            iter,
+            index: 0,
+        }
+    }
+
+    /// Byte offset of the next argument relative to the start of the payload.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.payload_len - self.iter.raw().len()
+    }
+}
+
+impl<'a> core::iter::Iterator for TolerantVerboseIter<'a> {
+    type Item = Result<VerboseValue<'a>, VerboseArgumentError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset();
+        match self.iter.next()? {
+            Ok(value) => {
+                self.index += 1;
+                Some(Ok(value))
+            }
+            Err(error) => Some(Err(VerboseArgumentError {
+                index: self.index,
+                offset,
+                error,
+            })),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{Layer, UnexpectedEndOfSliceError, VerboseDecodeError};
+    use crate::verbose::{U16Value, U32Value};
+    use arrayvec::ArrayVec;
+
+    #[test]
+    fn next() {
+        let first_value = U16Value {
+            variable_info: None,
+            scaling: None,
+            value: 1234,
+        };
+        let second_value = U32Value {
+            variable_info: None,
+            scaling: None,
+            value: 2345,
+        };
+        let mut data = ArrayVec::<u8, 1000>::new();
+        first_value.add_to_msg(&mut data, false).unwrap();
+        second_value.add_to_msg(&mut data, false).unwrap();
+
+        // all values ok
+        {
+            let mut iter = VerboseIter::new(false, 2, &data).tolerant();
+            assert_eq!(0, iter.offset());
+            assert_eq!(
+                Some(Ok(VerboseValue::U16(first_value.clone()))),
+                iter.next()
+            );
+            assert_eq!(6, iter.offset());
+            assert_eq!(
+                Some(Ok(VerboseValue::U32(second_value.clone()))),
+                iter.next()
+            );
+            assert_eq!(data.len(), iter.offset());
+            assert_eq!(None, iter.next());
+        }
+
+        // values before an error are returned
+        {
+            let mut iter = VerboseIter::new(false, 3, &data).tolerant();
+            assert_eq!(
+                Some(Ok(VerboseValue::U16(first_value.clone()))),
+                iter.next()
+            );
+            assert_eq!(
+                Some(Ok(VerboseValue::U32(second_value.clone()))),
+                iter.next()
+            );
+            assert_eq!(
+                Some(Err(VerboseArgumentError {
+                    index: 2,
+                    offset: data.len(),
+                    error: VerboseDecodeError::UnexpectedEndOfSlice(UnexpectedEndOfSliceError {
+                        layer: Layer::VerboseTypeInfo,
+                        minimum_size: 4,
+                        actual_size: 0,
+                    }),
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+            assert_eq!((0, Some(0)), iter.size_hint());
+        }
+
+        // error in the middle of the payload
+        {
+            let mut iter = VerboseIter::new(false, 2, &data[..data.len() - 1]).tolerant();
+            assert_eq!((1, Some(2)), iter.size_hint());
+            assert_eq!(Some(Ok(VerboseValue::U16(first_value))), iter.next());
+            let err = iter.next().unwrap().unwrap_err();
+            assert_eq!(1, err.index);
+            assert_eq!(6, err.offset);
+            assert_eq!(None, iter.next());
+        }
+    }
+}
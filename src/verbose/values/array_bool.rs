@@ -1,6 +1,8 @@
+use crate::error::VerboseDecodeError;
+use crate::verbose::EncodeBuf;
 use crate::verbose::{ArrayDimensions, VariableInfoUnit};
 
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 #[cfg(feature = "serde")]
 use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
 
@@ -57,13 +59,28 @@ impl<'a> ArrayBool<'a> {
     pub fn data(&self) -> &'a [u8] {
         self.data
     }
+
+    /// Checks that all elements are encoded as `0` or `1`.
+    ///
+    /// The DLT specification only defines these two values for booleans.
+    /// In contrast to the decoding of single bool values, arrays are not
+    /// checked during decoding and the iterator interprets all non zero
+    /// values as `true`. For the first element with a different value a
+    /// [`VerboseDecodeError::InvalidBoolValue`] error is returned.
+    pub fn verify(&self) -> Result<(), VerboseDecodeError> {
+        match self.data.iter().find(|v| **v > 1) {
+            Some(value) => Err(VerboseDecodeError::InvalidBoolValue(*value)),
+            None => Ok(()),
+        }
+    }
+
     pub fn iter(&'a self) -> ArrayBoolIterator<'a> {
         ArrayBoolIterator { rest: self.data }
     }
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -88,7 +105,7 @@ impl<'a> ArrayBool<'a> {
             buf.try_extend_from_slice(self.dimensions.dimensions)?;
             buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
             buf.try_extend_from_slice(var_info.name.as_bytes())?;
-            if buf.remaining_capacity() > var_info.unit.len() + 2 {
+            if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                 // Safe as capacity is checked earlier
                 unsafe { buf.push_unchecked(0) };
                 let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -191,6 +208,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::ArrBool;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -834,4 +852,23 @@ mod test {
             assert_eq!(convert_content, serde_json::to_string(&arr_u8).unwrap());
         }
     }
+
+    #[test]
+    fn verify() {
+        let dims = 3u16.to_be_bytes();
+        let value = |data| ArrayBool {
+            dimensions: ArrayDimensions {
+                is_big_endian: true,
+                dimensions: &dims,
+            },
+            variable_info: None,
+            data,
+        };
+        assert_eq!(Ok(()), value(&[]).verify());
+        assert_eq!(Ok(()), value(&[0, 1, 0]).verify());
+        assert_eq!(
+            Err(VerboseDecodeError::InvalidBoolValue(0xff)),
+            value(&[1, 0xff, 2]).verify()
+        );
+    }
 }
@@ -1,8 +1,9 @@
+use crate::verbose::EncodeBuf;
 use crate::verbose::{ArrayDimensions, RawF128, VariableInfoUnit};
 
 #[cfg(feature = "serde")]
 use super::ArrayItDimension;
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 #[cfg(feature = "serde")]
 use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
 
@@ -29,9 +30,9 @@ impl<'a> ArrayF128<'a> {
     }
 
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -56,7 +57,7 @@ impl<'a> ArrayF128<'a> {
             buf.try_extend_from_slice(self.dimensions.dimensions)?;
             buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
             buf.try_extend_from_slice(var_info.name.as_bytes())?;
-            if buf.remaining_capacity() > var_info.unit.len() + 2 {
+            if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                 // Safe as capacity is checked earlier
                 unsafe { buf.push_unchecked(0) };
                 let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -275,6 +276,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::ArrF128;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
     use std::mem::size_of;
@@ -1,8 +1,9 @@
+use crate::verbose::EncodeBuf;
 use crate::verbose::{ArrayDimensions, Scaling, VariableInfoUnit};
 
 #[cfg(feature = "serde")]
 use super::ArrayItDimension;
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 #[cfg(feature = "serde")]
 use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
 
@@ -25,10 +26,21 @@ impl<'a> ArrayI32<'a> {
             rest: self.data,
         }
     }
+    /// Returns an iterator over the scaled values (`value * quantization + offset`
+    /// or the unscaled value if no scaling is present) paired with the unit
+    /// of the array (empty if no variable info is present).
+    pub fn iter_with_units(&'a self) -> impl Iterator<Item = (f64, &'a str)> + 'a {
+        let unit = self.variable_info.as_ref().map(|v| v.unit).unwrap_or("");
+        let scaling = self.scaling.as_ref();
+        self.iter().map(move |value| {
+            let value = value as f64;
+            (scaling.map(|s| s.scale(value)).unwrap_or(value), unit)
+        })
+    }
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -65,7 +77,7 @@ impl<'a> ArrayI32<'a> {
                 buf.try_extend_from_slice(self.dimensions.dimensions)?;
                 buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
                 buf.try_extend_from_slice(var_info.name.as_bytes())?;
-                if buf.remaining_capacity() > var_info.unit.len() + 2 {
+                if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                     // Safe as capacity is checked earlier
                     unsafe { buf.push_unchecked(0) };
                     let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -85,7 +97,7 @@ impl<'a> ArrayI32<'a> {
                 buf.try_extend_from_slice(self.dimensions.dimensions)?;
                 buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
                 buf.try_extend_from_slice(var_info.name.as_bytes())?;
-                if buf.remaining_capacity() > var_info.unit.len() + 2 {
+                if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                     // Safe as capacity is checked earlier
                     unsafe { buf.push_unchecked(0) };
                     let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -212,6 +224,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::ArrI32;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
     use std::mem::size_of;
@@ -1048,6 +1061,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn iter_with_units() {
+        let dimensions = 3u16.to_be_bytes();
+        let mut content = Vec::new();
+        for value in [-2i32, 0, 10] {
+            content.extend_from_slice(&value.to_be_bytes());
+        }
+
+        // with scaling & unit
+        {
+            let arr = TestType {
+                is_big_endian: true,
+                dimensions: ArrayDimensions {
+                    is_big_endian: true,
+                    dimensions: &dimensions,
+                },
+                variable_info: Some(VariableInfoUnit {
+                    name: "speed",
+                    unit: "km/h",
+                }),
+                scaling: Some(Scaling {
+                    quantization: 0.5,
+                    offset: 100,
+                }),
+                data: &content,
+            };
+            assert_eq!(
+                arr.iter_with_units().collect::<Vec<_>>(),
+                [(99.0, "km/h"), (100.0, "km/h"), (105.0, "km/h")]
+            );
+        }
+
+        // without scaling & unit
+        {
+            let arr = TestType {
+                is_big_endian: true,
+                dimensions: ArrayDimensions {
+                    is_big_endian: true,
+                    dimensions: &dimensions,
+                },
+                variable_info: None,
+                scaling: None,
+                data: &content,
+            };
+            assert_eq!(
+                arr.iter_with_units().collect::<Vec<_>>(),
+                [(-2.0, ""), (0.0, ""), (10.0, "")]
+            );
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialization() {
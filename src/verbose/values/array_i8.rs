@@ -1,8 +1,9 @@
+use crate::verbose::EncodeBuf;
 use crate::verbose::{ArrayDimensions, Scaling, VariableInfoUnit};
 
 #[cfg(feature = "serde")]
 use super::ArrayItDimension;
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 #[cfg(feature = "serde")]
 use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
 
@@ -21,10 +22,21 @@ impl<'a> ArrayI8<'a> {
     pub fn iter(&'a self) -> ArrayI8Iterator<'a> {
         ArrayI8Iterator { rest: self.data }
     }
+    /// Returns an iterator over the scaled values (`value * quantization + offset`
+    /// or the unscaled value if no scaling is present) paired with the unit
+    /// of the array (empty if no variable info is present).
+    pub fn iter_with_units(&'a self) -> impl Iterator<Item = (f64, &'a str)> + 'a {
+        let unit = self.variable_info.as_ref().map(|v| v.unit).unwrap_or("");
+        let scaling = self.scaling.as_ref();
+        self.iter().map(move |value| {
+            let value = value as f64;
+            (scaling.map(|s| s.scale(value)).unwrap_or(value), unit)
+        })
+    }
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -61,7 +73,7 @@ impl<'a> ArrayI8<'a> {
                 buf.try_extend_from_slice(self.dimensions.dimensions)?;
                 buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
                 buf.try_extend_from_slice(var_info.name.as_bytes())?;
-                if buf.remaining_capacity() > var_info.unit.len() + 2 {
+                if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                     // Safe as capacity is checked earlier
                     unsafe { buf.push_unchecked(0) };
                     let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -81,7 +93,7 @@ impl<'a> ArrayI8<'a> {
                 buf.try_extend_from_slice(self.dimensions.dimensions)?;
                 buf.try_extend_from_slice(&[name_len[0], name_len[1], unit_len[0], unit_len[1]])?;
                 buf.try_extend_from_slice(var_info.name.as_bytes())?;
-                if buf.remaining_capacity() > var_info.unit.len() + 2 {
+                if buf.remaining_capacity() >= var_info.unit.len() + 2 {
                     // Safe as capacity is checked earlier
                     unsafe { buf.push_unchecked(0) };
                     let _ = buf.try_extend_from_slice(var_info.unit.as_bytes());
@@ -239,6 +251,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::ArrI8;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -1,5 +1,11 @@
-use arrayvec::{ArrayVec, CapacityError};
-
+use crate::verbose::EncodeBuf;
+use arrayvec::CapacityError;
+
+/// Verbose bool value.
+///
+/// Only the values `0` & `1` are accepted during decoding, all other
+/// values result in a [`crate::error::VerboseDecodeError::InvalidBoolValue`]
+/// error.
 #[derive(Debug, Eq, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BoolValue<'a> {
@@ -9,9 +15,9 @@ pub struct BoolValue<'a> {
 
 impl<'a> BoolValue<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if self.name.is_some() {
@@ -55,6 +61,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::Bool;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -211,4 +218,18 @@ mod test {
 
         }
     }
+
+    #[test]
+    fn read_invalid() {
+        use crate::error::VerboseDecodeError::InvalidBoolValue;
+
+        for value in 2..=u8::MAX {
+            for is_big_endian in [false, true] {
+                assert_eq!(
+                    Err(InvalidBoolValue(value)),
+                    VerboseValue::from_slice(&[0b0001_0001, 0, 0, 0, value], is_big_endian)
+                );
+            }
+        }
+    }
 }
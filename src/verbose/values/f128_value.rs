@@ -1,6 +1,7 @@
 use super::RawF128;
+use crate::verbose::EncodeBuf;
 use crate::verbose::VariableInfoUnit;
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 
 /// Verbose 128 bit float number.
 #[derive(Debug, PartialEq, Clone)]
@@ -12,9 +13,9 @@ pub struct F128Value<'a> {
 
 impl<'a> F128Value<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -58,6 +59,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::F128;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -1,7 +1,8 @@
+use crate::verbose::EncodeBuf;
 use crate::verbose::VariableInfoUnit;
-use arrayvec::{ArrayVec, CapacityError};
+use arrayvec::CapacityError;
 
-use super::RawF16;
+use super::{fmt_float_with_precision, RawF16};
 
 /// Verbose 16 bit float number.
 #[derive(Debug, PartialEq, Clone)]
@@ -13,9 +14,9 @@ pub struct F16Value<'a> {
 
 impl<'a> F16Value<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -51,6 +52,26 @@ impl<'a> F16Value<'a> {
             buf.try_extend_from_slice(&self.value.to_le_bytes())
         }
     }
+
+    /// Writes the value with the given number of digits after the
+    /// decimal point (width, fill, alignment & sign flags of the
+    /// formatter are applied, its precision is ignored).
+    pub fn fmt_with_precision(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        precision: usize,
+    ) -> core::fmt::Result {
+        fmt_float_with_precision(f, self.value.to_f32(), precision)
+    }
+}
+
+/// Writes the value (the unit & name are not included). The precision,
+/// width, fill, alignment & sign flags of the format string (e.g.
+/// `{:>10.3}`) are applied to the value.
+impl<'a> core::fmt::Display for F16Value<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.value.to_f32(), f)
+    }
 }
 
 #[cfg(test)]
@@ -59,6 +80,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::F16;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -244,4 +266,29 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn display() {
+        let v = F16Value {
+            variable_info: None,
+            value: RawF16::from_bits(0x4248),
+        };
+        assert_eq!("3.140625", format!("{}", v));
+        assert_eq!("3.14", format!("{:.2}", v));
+        assert_eq!("3", format!("{:.0}", v));
+        assert_eq!("      3.14", format!("{:>10.2}", v));
+        assert_eq!("3.14******", format!("{:*<10.2}", v));
+        assert_eq!("+3.14", format!("{:+.2}", v));
+
+        // fmt_with_precision
+        struct Precision<'a>(F16Value<'a>, usize);
+        impl<'a> core::fmt::Display for Precision<'a> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt_with_precision(f, self.1)
+            }
+        }
+        assert_eq!("3.14", format!("{}", Precision(v.clone(), 2)));
+        assert_eq!("      3.14", format!("{:>10}", Precision(v.clone(), 2)));
+        assert_eq!("3.1406250", format!("{}", Precision(v, 7)));
+    }
 }
@@ -1,4 +1,7 @@
-use arrayvec::{ArrayVec, CapacityError};
+use crate::verbose::EncodeBuf;
+use arrayvec::CapacityError;
+
+use super::fmt_float_with_precision;
 
 use crate::verbose::VariableInfoUnit;
 
@@ -12,9 +15,9 @@ pub struct F32Value<'a> {
 
 impl<'a> F32Value<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -50,6 +53,26 @@ impl<'a> F32Value<'a> {
             buf.try_extend_from_slice(&self.value.to_le_bytes())
         }
     }
+
+    /// Writes the value with the given number of digits after the
+    /// decimal point (width, fill, alignment & sign flags of the
+    /// formatter are applied, its precision is ignored).
+    pub fn fmt_with_precision(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        precision: usize,
+    ) -> core::fmt::Result {
+        fmt_float_with_precision(f, self.value, precision)
+    }
+}
+
+/// Writes the value (the unit & name are not included). The precision,
+/// width, fill, alignment & sign flags of the format string (e.g.
+/// `{:>10.3}`) are applied to the value.
+impl<'a> core::fmt::Display for F32Value<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.value, f)
+    }
 }
 
 #[cfg(test)]
@@ -58,6 +81,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::F32;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -245,4 +269,29 @@ mod test {
 
         }
     }
+
+    #[test]
+    fn display() {
+        let v = F32Value {
+            variable_info: None,
+            value: 12.34567f32,
+        };
+        assert_eq!("12.34567", format!("{}", v));
+        assert_eq!("12.35", format!("{:.2}", v));
+        assert_eq!("12", format!("{:.0}", v));
+        assert_eq!("     12.35", format!("{:>10.2}", v));
+        assert_eq!("12.35*****", format!("{:*<10.2}", v));
+        assert_eq!("+12.35", format!("{:+.2}", v));
+
+        // fmt_with_precision
+        struct Precision<'a>(F32Value<'a>, usize);
+        impl<'a> core::fmt::Display for Precision<'a> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt_with_precision(f, self.1)
+            }
+        }
+        assert_eq!("12.35", format!("{}", Precision(v.clone(), 2)));
+        assert_eq!("     12.35", format!("{:>10}", Precision(v.clone(), 2)));
+        assert_eq!("12.345670", format!("{}", Precision(v, 6)));
+    }
 }
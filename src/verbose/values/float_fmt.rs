@@ -0,0 +1,110 @@
+use core::fmt::{self, Alignment, Display, Formatter, Write};
+
+/// Writes the float value with the given number of digits after the
+/// decimal point & applies the width, fill, alignment & sign flags of
+/// the formatter (the precision of the formatter is ignored).
+pub(crate) fn fmt_float_with_precision<T: Display + Copy>(
+    f: &mut Formatter<'_>,
+    value: T,
+    precision: usize,
+) -> fmt::Result {
+    let width = f.width().unwrap_or(0);
+    let sign_plus = f.sign_plus();
+
+    // fill & alignment are ignored when zero padding
+    if f.sign_aware_zero_pad() {
+        return if sign_plus {
+            write!(f, "{:+0w$.p$}", value, w = width, p = precision)
+        } else {
+            write!(f, "{:0w$.p$}", value, w = width, p = precision)
+        };
+    }
+
+    let value = WithPrecision {
+        value,
+        precision,
+        sign_plus,
+    };
+    let mut len = CharCount(0);
+    write!(len, "{}", value)?;
+
+    let padding = width.saturating_sub(len.0);
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        // numbers are right aligned by default
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    write!(f, "{}", value)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Float value formatted with a fixed precision (without padding).
+#[derive(Clone, Copy)]
+struct WithPrecision<T: Display + Copy> {
+    value: T,
+    precision: usize,
+    sign_plus: bool,
+}
+
+impl<T: Display + Copy> Display for WithPrecision<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.sign_plus {
+            write!(f, "{:+.*}", self.precision, self.value)
+        } else {
+            write!(f, "{:.*}", self.precision, self.value)
+        }
+    }
+}
+
+/// Writer only counting the number of written characters.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    struct Precision(f64, usize);
+
+    impl Display for Precision {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            fmt_float_with_precision(f, self.0, self.1)
+        }
+    }
+
+    #[test]
+    fn fmt_float_with_precision_flags() {
+        // the format string precision is overridden
+        assert_eq!("1.50", format!("{}", Precision(1.5, 2)));
+        assert_eq!("1.50", format!("{:.5}", Precision(1.5, 2)));
+
+        // width, fill & alignment
+        assert_eq!("      1.50", format!("{:10}", Precision(1.5, 2)));
+        assert_eq!("      1.50", format!("{:>10}", Precision(1.5, 2)));
+        assert_eq!("1.50      ", format!("{:<10}", Precision(1.5, 2)));
+        assert_eq!("***1.50***", format!("{:*^10}", Precision(1.5, 2)));
+        assert_eq!("***1.50****", format!("{:*^11}", Precision(1.5, 2)));
+        assert_eq!("1.50", format!("{:2}", Precision(1.5, 2)));
+
+        // sign & zero padding
+        assert_eq!("+1.50", format!("{:+}", Precision(1.5, 2)));
+        assert_eq!("   +1.5", format!("{:+7}", Precision(1.5, 1)));
+        assert_eq!("-001.5", format!("{:06}", Precision(-1.5, 1)));
+        assert_eq!("+001.5", format!("{:+06}", Precision(1.5, 1)));
+    }
+}
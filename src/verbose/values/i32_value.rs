@@ -1,4 +1,5 @@
-use arrayvec::{ArrayVec, CapacityError};
+use crate::verbose::EncodeBuf;
+use arrayvec::CapacityError;
 
 use crate::verbose::{Scaling, VariableInfoUnit};
 
@@ -13,9 +14,9 @@ pub struct I32Value<'a> {
 
 impl<'a> I32Value<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(var_info) = &self.variable_info {
@@ -107,6 +108,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::I32;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
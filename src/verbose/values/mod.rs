@@ -43,9 +43,16 @@ pub use f64_value::*;
 mod f128_value;
 pub use f128_value::*;
 
+mod float_fmt;
+pub(crate) use float_fmt::*;
+
+mod string_encoding;
+pub use string_encoding::*;
+
 mod string_value;
 pub use string_value::*;
 
+
 mod raw_f16;
 pub use raw_f16::*;
 
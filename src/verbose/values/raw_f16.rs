@@ -17,9 +17,24 @@ impl RawF16 {
     pub const NEGATIVE_INFINITY: RawF16 = RawF16::from_bits(0b1111_1100_0000_0000);
 
     /// Converts the f16 to a f32.
+    ///
+    /// All f16 values (including subnormals, infinities & NaNs) can
+    /// be exactly represented as f32 values.
     #[inline]
     pub fn to_f32(self) -> f32 {
         let raw_u16 = self.0;
+
+        // subnormal numbers have no implicit leading one and a fixed
+        // exponent of -14 (value = fraction * 2^-24)
+        if 0 == raw_u16 & RawF16::EXPO_MASK && 0 != raw_u16 & RawF16::FRAC_MASK {
+            let abs = f32::from(raw_u16 & RawF16::FRAC_MASK) * (1.0 / 16_777_216.0);
+            return if 0 != raw_u16 & RawF16::SIGN_MASK {
+                -abs
+            } else {
+                abs
+            };
+        }
+
         // extract elements & re-shift to f32
         //
         // f16
@@ -50,6 +65,12 @@ impl RawF16 {
         f32::from_bits(sign | expo | frac)
     }
 
+    /// Converts the f16 to a f64.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.to_f32())
+    }
+
     /// Create a floating point value from its representation as a
     /// byte array in big endian.
     #[inline]
@@ -112,6 +133,13 @@ impl From<RawF16> for f32 {
     }
 }
 
+impl From<RawF16> for f64 {
+    #[inline]
+    fn from(value: RawF16) -> Self {
+        value.to_f64()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for RawF16 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -151,25 +179,72 @@ mod tests {
 
     #[test]
     fn to_f32() {
-        // zero
+        // zero & negative zero
         assert_eq!(0.0, RawF16(0).to_f32());
+        assert!(RawF16(0).to_f32().is_sign_positive());
+        assert_eq!(0.0, RawF16(0b1000_0000_0000_0000).to_f32());
+        assert!(RawF16(0b1000_0000_0000_0000).to_f32().is_sign_negative());
+
+        // smallest subnormal numbers (2^-24)
+        assert_eq!(
+            5.960_464_5e-8,
+            RawF16::from_bits(0b0000_0000_0000_0001).to_f32()
+        );
+        assert_eq!(
+            -5.960_464_5e-8,
+            RawF16::from_bits(0b1000_0000_0000_0001).to_f32()
+        );
+
+        // largest subnormal number (1023 * 2^-24)
+        assert_eq!(
+            1023.0 / 16_777_216.0,
+            RawF16::from_bits(0b0000_0011_1111_1111).to_f32()
+        );
+
+        // smallest normal number (2^-14)
+        assert_eq!(
+            1.0 / 16384.0,
+            RawF16::from_bits(0b0000_0100_0000_0000).to_f32()
+        );
 
         // one
         assert_eq!(1.0, RawF16::from_bits(0b0_01111_0000000000).to_f32());
 
         // infinite
-        assert!(RawF16::from_bits(0b0111_1100_0000_0000)
-            .to_f32()
-            .is_infinite());
+        assert_eq!(
+            f32::INFINITY,
+            RawF16::from_bits(0b0111_1100_0000_0000).to_f32()
+        );
+        assert_eq!(
+            f32::NEG_INFINITY,
+            RawF16::from_bits(0b1111_1100_0000_0000).to_f32()
+        );
 
         // nan
         assert!(RawF16::from_bits(0b0111_1100_0000_0001).to_f32().is_nan());
+        assert!(RawF16::from_bits(0b0111_1110_0000_0000).to_f32().is_nan());
+        assert!(RawF16::from_bits(0b1111_1111_1111_1111).to_f32().is_nan());
 
         // largest normal number
         assert_eq!(65504.0, RawF16::from_bits(0b0111_1011_1111_1111).to_f32());
         assert_eq!(-65504.0, RawF16::from_bits(0b1111_1011_1111_1111).to_f32());
     }
 
+    proptest! {
+        #[test]
+        fn to_f64(value in any::<u16>()) {
+            let v = RawF16(value);
+            let expected = f64::from(v.to_f32());
+            if expected.is_nan() {
+                assert!(v.to_f64().is_nan());
+                assert!(f64::from(v).is_nan());
+            } else {
+                assert_eq!(expected, v.to_f64());
+                assert_eq!(expected, f64::from(v));
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn from_be_bytes(value in any::<u16>()) {
@@ -1,4 +1,6 @@
-use arrayvec::{ArrayVec, CapacityError};
+use crate::verbose::EncodeBuf;
+use crate::verbose::HexDump;
+use arrayvec::CapacityError;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
@@ -8,10 +10,17 @@ pub struct RawValue<'a> {
 }
 
 impl<'a> RawValue<'a> {
+    /// Returns a wrapper that displays the data as space separated
+    /// hex values (e.g. `write!(f, "{}", raw.hex())`).
+    #[inline]
+    pub fn hex(&self) -> HexDump<'a> {
+        HexDump(self.data)
+    }
+
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(name) = self.name {
@@ -59,6 +68,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::Raw;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -234,4 +244,14 @@ mod test {
 
         }
     }
+
+    #[test]
+    fn hex() {
+        let raw_value = RawValue {
+            name: Some("name"),
+            data: &[0x12, 0xab],
+        };
+        assert_eq!(HexDump(&[0x12, 0xab]), raw_value.hex());
+        assert_eq!("12 ab", format!("{}", raw_value.hex()));
+    }
 }
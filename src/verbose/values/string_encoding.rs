@@ -0,0 +1,99 @@
+/// String coding (SCOD) of a verbose string argument.
+///
+/// The coding is stored in the bits 15-17 of the type info of a
+/// string argument.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StringEncoding {
+    /// String data is ASCII encoded (SCOD value 0).
+    #[default]
+    Ascii,
+    /// String data is UTF-8 encoded (SCOD value 1).
+    Utf8,
+    /// Reserved SCOD value (2-7).
+    Reserved(u8),
+}
+
+impl StringEncoding {
+    /// Decodes the string coding from the type info of a string argument.
+    #[inline]
+    pub fn from_type_info(type_info: [u8; 4]) -> StringEncoding {
+        let scod = (type_info[1] >> 7) | ((type_info[2] & 0b0000_0011) << 1);
+        match scod {
+            0 => StringEncoding::Ascii,
+            1 => StringEncoding::Utf8,
+            other => StringEncoding::Reserved(other),
+        }
+    }
+
+    /// Returns the SCOD value (only the lower 3 bits of reserved values
+    /// are used).
+    #[inline]
+    pub fn scod(&self) -> u8 {
+        match self {
+            StringEncoding::Ascii => 0,
+            StringEncoding::Utf8 => 1,
+            StringEncoding::Reserved(value) => value & 0b111,
+        }
+    }
+
+    /// Returns the type info of a string argument with the given
+    /// `type_info` bytes & the string coding bits set.
+    #[inline]
+    pub fn set_in_type_info(&self, mut type_info: [u8; 4]) -> [u8; 4] {
+        let scod = self.scod();
+        type_info[1] = (type_info[1] & 0b0111_1111) | ((scod & 1) << 7);
+        type_info[2] = (type_info[2] & 0b1111_1100) | (scod >> 1);
+        type_info
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let v = StringEncoding::Reserved(3);
+        assert_eq!(v, v.clone());
+        assert_eq!("Reserved(3)", format!("{:?}", v));
+        assert_eq!(StringEncoding::Ascii, StringEncoding::default());
+    }
+
+    #[test]
+    fn from_type_info() {
+        use StringEncoding::*;
+        let tests = [
+            ([0, 0b0000_0010, 0, 0], Ascii),
+            ([0, 0b1000_0010, 0, 0], Utf8),
+            ([0, 0b0000_0010, 0b01, 0], Reserved(2)),
+            ([0, 0b1000_0010, 0b01, 0], Reserved(3)),
+            ([0, 0b0000_0010, 0b10, 0], Reserved(4)),
+            ([0, 0b1000_0010, 0b11, 0], Reserved(7)),
+            // other bits are ignored
+            ([0xff, 0b0111_1111, 0b1111_1100, 0xff], Ascii),
+        ];
+        for (type_info, expected) in tests {
+            assert_eq!(expected, StringEncoding::from_type_info(type_info));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn scod_set_in_type_info(scod in 0u8..8, type_info in any::<[u8;4]>()) {
+            let encoding = StringEncoding::from_type_info(
+                StringEncoding::Reserved(scod).set_in_type_info(type_info)
+            );
+            prop_assert_eq!(scod, encoding.scod());
+
+            // only the scod bits are modified
+            let result = encoding.set_in_type_info(type_info);
+            prop_assert_eq!(type_info[0], result[0]);
+            prop_assert_eq!(type_info[1] & 0b0111_1111, result[1] & 0b0111_1111);
+            prop_assert_eq!(type_info[2] & 0b1111_1100, result[2] & 0b1111_1100);
+            prop_assert_eq!(type_info[3], result[3]);
+        }
+    }
+}
@@ -1,29 +1,123 @@
-use arrayvec::{ArrayVec, CapacityError};
-
+use super::StringEncoding;
+use crate::verbose::EncodeBuf;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use arrayvec::CapacityError;
+use core::str::Utf8Error;
+
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Verbose string value.
+///
+/// The raw string data is kept together with its string coding, as
+/// ASCII coded strings are not required to be valid UTF-8. Use
+/// [`StringValue::to_str`] to get the value as `&str`.
 #[derive(Debug, Eq, PartialEq, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StringValue<'a> {
     pub name: Option<&'a str>,
-    pub value: &'a str,
+    encoding: StringEncoding,
+    data: &'a [u8],
+}
+
+#[cfg(feature = "serde")]
+impl<'a> Serialize for StringValue<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("StringValue", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("encoding", &self.encoding)?;
+        // string data that is not valid UTF-8 is serialized as raw bytes
+        match self.to_str() {
+            Ok(value) => state.serialize_field("value", value)?,
+            Err(_) => state.serialize_field("value", self.data)?,
+        }
+        state.end()
+    }
 }
 
 impl<'a> StringValue<'a> {
+    /// Creates a new string value with the string coding
+    /// [`StringEncoding::Ascii`] (SCOD bits not set, same encoding as
+    /// before the string coding was exposed).
+    ///
+    /// Use [`StringValue::with_encoding`] to mark the string as UTF-8.
+    #[inline]
+    pub fn new(name: Option<&'a str>, value: &'a str) -> StringValue<'a> {
+        StringValue {
+            name,
+            encoding: StringEncoding::Ascii,
+            data: value.as_bytes(),
+        }
+    }
+
+    /// Creates a new string value with the given string coding & raw
+    /// string data (without the zero termination).
+    ///
+    /// The data is not required to be valid UTF-8 (e.g. ASCII coded
+    /// strings containing Latin-1 characters).
+    #[inline]
+    pub fn with_encoding(
+        name: Option<&'a str>,
+        encoding: StringEncoding,
+        data: &'a [u8],
+    ) -> StringValue<'a> {
+        StringValue {
+            name,
+            encoding,
+            data,
+        }
+    }
+
+    /// Returns the string coding (SCOD) set in the type info.
+    #[inline]
+    pub fn encoding(&self) -> StringEncoding {
+        self.encoding
+    }
+
+    /// Returns the raw bytes of the string data (without the zero
+    /// termination).
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the string data if it is valid UTF-8.
+    #[inline]
+    pub fn to_str(&self) -> Result<&'a str, Utf8Error> {
+        core::str::from_utf8(self.data)
+    }
+
+    /// Returns the string data as [`Cow`] (invalid UTF-8 sequences get
+    /// replaced by `U+FFFD`).
+    #[cfg(feature = "alloc")]
+    pub fn to_string_lossy(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.data)
+    }
+
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(name) = self.name {
-            let type_info = [0b0000_0000, 0b0000_1010, 0b0000_0000, 0b0000_0000];
+            let type_info = self.encoding.set_in_type_info([
+                0b0000_0000,
+                0b0000_1010,
+                0b0000_0000,
+                0b0000_0000,
+            ]);
             let (value_len, name_len) = if is_big_endian {
                 (
-                    (self.value.len() as u16 + 1).to_be_bytes(),
+                    (self.data.len() as u16 + 1).to_be_bytes(),
                     (name.len() as u16 + 1).to_be_bytes(),
                 )
             } else {
                 (
-                    (self.value.len() as u16 + 1).to_le_bytes(),
+                    (self.data.len() as u16 + 1).to_le_bytes(),
                     (name.len() as u16 + 1).to_le_bytes(),
                 )
             };
@@ -37,17 +131,22 @@ impl<'a> StringValue<'a> {
                 return Err(CapacityError::new(()));
             }
         } else {
-            let type_info = [0b0000_0000, 0b0000_0010, 0b0000_0000, 0b0000_0000];
+            let type_info = self.encoding.set_in_type_info([
+                0b0000_0000,
+                0b0000_0010,
+                0b0000_0000,
+                0b0000_0000,
+            ]);
             let value_len = if is_big_endian {
-                (self.value.len() as u16 + 1).to_be_bytes()
+                (self.data.len() as u16 + 1).to_be_bytes()
             } else {
-                (self.value.len() as u16 + 1).to_le_bytes()
+                (self.data.len() as u16 + 1).to_le_bytes()
             };
             buf.try_extend_from_slice(&type_info)?;
             buf.try_extend_from_slice(&[value_len[0], value_len[1]])?;
         }
 
-        buf.try_extend_from_slice(self.value.as_bytes())?;
+        buf.try_extend_from_slice(self.data)?;
         if buf.remaining_capacity() > 0 {
             // Safe as capacity is checked earlier
             unsafe { buf.push_unchecked(0) };
@@ -62,9 +161,11 @@ impl<'a> StringValue<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::error::VerboseDecodeError;
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::Str;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -85,7 +186,7 @@ mod test {
                 let slice_len = name.len() + value.len() + BYTES_NEEDED_WITH_NAME;
                 let is_big_endian = true;
 
-                let string_value = StringValue {name: Some(name), value};
+                let string_value = StringValue::new(Some(name), value);
                 let mut content_buff = Vec::with_capacity(slice_len);
 
                 let len_name_be = (name.len() as u16 + 1).to_be_bytes();
@@ -112,7 +213,7 @@ mod test {
                 let slice_len = name.len() + value.len() + BYTES_NEEDED_WITH_NAME;
                 let is_big_endian = false;
 
-                let string_value = StringValue {name: Some(name), value};
+                let string_value = StringValue::new(Some(name), value);
                 let mut content_buff = Vec::with_capacity(slice_len);
 
                 let len_name_le = (name.len() as u16 + 1).to_le_bytes();
@@ -139,7 +240,7 @@ mod test {
                 let slice_len = value.len() + BYTES_NEEDED;
                 let is_big_endian = true;
 
-                let string_value = StringValue {name: None, value};
+                let string_value = StringValue::new(None, value);
                 let mut content_buff = Vec::with_capacity(slice_len);
 
                 let len_value_be = (value.len() as u16 + 1).to_be_bytes();
@@ -163,7 +264,7 @@ mod test {
                 let slice_len = value.len() + BYTES_NEEDED;
                 let is_big_endian = false;
 
-                let string_value = StringValue {name: None, value};
+                let string_value = StringValue::new(None, value);
                 let mut content_buff = Vec::with_capacity(slice_len);
 
                 let len_value_le = (value.len() as u16 + 1).to_le_bytes();
@@ -185,7 +286,7 @@ mod test {
              {
                 const SLICE_LEN: usize = BYTES_NEEDED_WITH_NAME-1;
 
-                let string_value = StringValue {name: Some(name), value};
+                let string_value = StringValue::new(Some(name), value);
                 let is_big_endian = true;
 
                 let mut msg_buff: ArrayVec<u8, SLICE_LEN> = ArrayVec::new();
@@ -200,7 +301,7 @@ mod test {
             {
                 const SLICE_LEN: usize = BYTES_NEEDED_WITH_NAME-1;
 
-                let string_value = StringValue {name: Some(name), value};
+                let string_value = StringValue::new(Some(name), value);
                 let is_big_endian = false;
 
                 let mut msg_buff: ArrayVec<u8, SLICE_LEN> = ArrayVec::new();
@@ -215,7 +316,7 @@ mod test {
             {
                 const SLICE_LEN: usize = BYTES_NEEDED - 1;
 
-                let string_value = StringValue {name: None, value};
+                let string_value = StringValue::new(None, value);
                 let is_big_endian = true;
 
                 let mut msg_buff: ArrayVec<u8, SLICE_LEN> = ArrayVec::new();
@@ -230,7 +331,7 @@ mod test {
             {
                 const SLICE_LEN: usize = BYTES_NEEDED - 1;
 
-                let string_value = StringValue {name: None, value};
+                let string_value = StringValue::new(None, value);
                 let is_big_endian = true;
 
                 let mut msg_buff: ArrayVec<u8, SLICE_LEN> = ArrayVec::new();
@@ -244,4 +345,103 @@ mod test {
 
         }
     }
+
+    #[test]
+    fn from_slice_invalid_utf8() {
+        // string "a\xffb" with zero termination
+        let buffer = [0, 0b0000_0010, 0, 0, 0, 4, b'a', 0xff, b'b', 0];
+
+        // decoding keeps the raw data (validated when accessed)
+        let (value, rest) = VerboseValue::from_slice(&buffer, true).unwrap();
+        assert!(rest.is_empty());
+        let Str(value) = value else {
+            panic!("expected a string value");
+        };
+        assert_eq!(None, value.name);
+        assert_eq!(StringEncoding::Ascii, value.encoding());
+        assert_eq!(&[b'a', 0xff, b'b'], value.as_bytes());
+        assert!(value.to_str().is_err());
+        #[cfg(feature = "alloc")]
+        assert_eq!("a\u{FFFD}b", value.to_string_lossy());
+
+        // UTF-8 coded strings are still validated
+        let value = StringValue::with_encoding(None, StringEncoding::Utf8, &[b'a', 0xff, b'b']);
+        let mut buffer = ArrayVec::<u8, 16>::new();
+        value.add_to_msg(&mut buffer, true).unwrap();
+        assert_matches!(
+            VerboseValue::from_slice(&buffer, true),
+            Err(VerboseDecodeError::Utf8(_))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_lossy() {
+        let value = StringValue::new(None, "abc");
+        assert_eq!(alloc::borrow::Cow::Borrowed("abc"), value.to_string_lossy());
+    }
+
+    #[test]
+    fn encoding() {
+        for encoding in [
+            StringEncoding::Ascii,
+            StringEncoding::Utf8,
+            StringEncoding::Reserved(5),
+        ] {
+            for name in [None, Some("n")] {
+                for is_big_endian in [false, true] {
+                    let value = StringValue::with_encoding(name, encoding, b"abc");
+                    let mut buffer = ArrayVec::<u8, 64>::new();
+                    value.add_to_msg(&mut buffer, is_big_endian).unwrap();
+                    assert_eq!(
+                        encoding,
+                        StringEncoding::from_type_info([
+                            buffer[0], buffer[1], buffer[2], buffer[3]
+                        ])
+                    );
+
+                    // decoding keeps the encoding
+                    assert_eq!(
+                        VerboseValue::from_slice(&buffer, is_big_endian),
+                        Ok((Str(value.clone()), &[] as &[u8]))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn as_bytes() {
+        let value = StringValue::new(None, "abc");
+        assert_eq!(b"abc", value.as_bytes());
+    }
+
+    #[test]
+    fn new_to_str() {
+        let value = StringValue::new(Some("n"), "abc");
+        assert_eq!(Some("n"), value.name);
+        assert_eq!(StringEncoding::Ascii, value.encoding());
+        assert_eq!(Ok("abc"), value.to_str());
+
+        // latin-1 coded data
+        let value = StringValue::with_encoding(None, StringEncoding::Ascii, &[b'a', 0xe4]);
+        assert!(value.to_str().is_err());
+        #[cfg(feature = "alloc")]
+        assert_eq!("a\u{FFFD}", value.to_string_lossy());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let value = StringValue::new(Some("n"), "abc");
+        assert_eq!(
+            r#"{"name":"n","encoding":"Ascii","value":"abc"}"#,
+            serde_json::to_string(&value).unwrap()
+        );
+        let value = StringValue::with_encoding(None, StringEncoding::Ascii, &[0xff]);
+        assert_eq!(
+            r#"{"name":null,"encoding":"Ascii","value":[255]}"#,
+            serde_json::to_string(&value).unwrap()
+        );
+    }
 }
@@ -1,6 +1,8 @@
-use arrayvec::{ArrayVec, CapacityError};
+use crate::verbose::EncodeBuf;
+use arrayvec::CapacityError;
 
-use crate::verbose::VerboseIter;
+use crate::error::VerboseDecodeError;
+use crate::verbose::{VerboseIter, VerboseValue};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct StructValue<'a> {
@@ -12,9 +14,9 @@ pub struct StructValue<'a> {
 
 impl<'a> StructValue<'a> {
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         if let Some(name) = self.name {
@@ -66,6 +68,34 @@ impl<'a> StructValue<'a> {
         )
     }
 
+    /// Decodes all entries & verifies that the entries data matches the
+    /// declared `number_of_entries`.
+    ///
+    /// Returns [`VerboseDecodeError::StructNumberOfEntriesMismatch`] if
+    /// the data ends before all declared entries could be decoded and
+    /// [`VerboseDecodeError::UnexpectedTrailingBytes`] if data is left
+    /// after the last declared entry.
+    pub fn verify(&self) -> Result<(), VerboseDecodeError> {
+        let mut rest = self.entries_data;
+        for decoded in 0..self.number_of_entries {
+            if rest.is_empty() {
+                return Err(VerboseDecodeError::StructNumberOfEntriesMismatch {
+                    declared: self.number_of_entries,
+                    decoded,
+                });
+            }
+            (_, rest) = VerboseValue::from_slice(rest, self.is_big_endian)?;
+        }
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                expected_len: self.entries_data.len() - rest.len(),
+                actual_len: self.entries_data.len(),
+            })
+        }
+    }
+
     /// Returns the slice containing the raw entries data.
     #[inline]
     pub fn entries_raw_data(&self) -> &'a [u8] {
@@ -95,6 +125,7 @@ mod test {
     use super::*;
     use crate::verbose::VerboseValue::*;
     use crate::verbose::*;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -949,4 +980,129 @@ mod test {
 
             }
         }
+
+    #[test]
+    fn entries() {
+        for is_big_endian in [false, true] {
+            let values = [
+                I8(I8Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: -1,
+                }),
+                Str(StringValue::new(Some("s"), "text")),
+                U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 0x1234_5678,
+                }),
+            ];
+            let mut entries_data = ArrayVec::<u8, 128>::new();
+            for value in &values {
+                value.add_to_msg(&mut entries_data, is_big_endian).unwrap();
+            }
+            let struct_value = StructValue {
+                is_big_endian,
+                number_of_entries: 3,
+                name: None,
+                entries_data: &entries_data,
+            };
+            assert_eq!(&entries_data[..], struct_value.entries_raw_data());
+
+            // entries are returned in order
+            let mut iter = struct_value.entries();
+            for value in &values {
+                assert_eq!(Some(Ok(value.clone())), iter.next());
+            }
+            assert_eq!(None, iter.next());
+
+            // error if less entries are present then declared
+            let struct_value = StructValue {
+                is_big_endian,
+                number_of_entries: 4,
+                name: None,
+                entries_data: &entries_data,
+            };
+            let mut iter = struct_value.entries();
+            for value in &values {
+                assert_eq!(Some(Ok(value.clone())), iter.next());
+            }
+            assert!(matches!(iter.next(), Some(Err(_))));
+        }
+    }
+
+    #[test]
+    fn verify() {
+        for is_big_endian in [false, true] {
+            let values = [
+                Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+                U16(U16Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 1234,
+                }),
+            ];
+            let mut entries_data = ArrayVec::<u8, 64>::new();
+            for value in &values {
+                value.add_to_msg(&mut entries_data, is_big_endian).unwrap();
+            }
+
+            // matching number of entries
+            assert_eq!(
+                Ok(()),
+                StructValue {
+                    is_big_endian,
+                    number_of_entries: 2,
+                    name: None,
+                    entries_data: &entries_data,
+                }
+                .verify()
+            );
+
+            // struct claiming 3 entries but only containing 2
+            assert_eq!(
+                Err(VerboseDecodeError::StructNumberOfEntriesMismatch {
+                    declared: 3,
+                    decoded: 2
+                }),
+                StructValue {
+                    is_big_endian,
+                    number_of_entries: 3,
+                    name: Some("s"),
+                    entries_data: &entries_data,
+                }
+                .verify()
+            );
+
+            // struct claiming less entries than present
+            assert_eq!(
+                Err(VerboseDecodeError::UnexpectedTrailingBytes {
+                    expected_len: 5,
+                    actual_len: entries_data.len(),
+                }),
+                StructValue {
+                    is_big_endian,
+                    number_of_entries: 1,
+                    name: None,
+                    entries_data: &entries_data,
+                }
+                .verify()
+            );
+
+            // error while decoding an entry
+            assert_eq!(
+                Err(VerboseDecodeError::InvalidBoolValue(2)),
+                StructValue {
+                    is_big_endian,
+                    number_of_entries: 1,
+                    name: None,
+                    entries_data: &[0b0001_0001, 0, 0, 0, 2],
+                }
+                .verify()
+            );
+        }
+    }
 }
@@ -1,16 +1,42 @@
-use arrayvec::{ArrayVec, CapacityError};
-
+use crate::verbose::EncodeBuf;
+use arrayvec::CapacityError;
+
+/// Verbose trace info argument (TRAI type info bit), e.g. used by VFB
+/// trace messages to transfer the name of the traced function or event.
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use dlt_parse::verbose::{TraceInfoValue, VerboseValue};
+///
+/// let mut buf = ArrayVec::<u8, 32>::new();
+/// TraceInfoValue { value: "func_a" }.add_to_msg(&mut buf, true).unwrap();
+///
+/// if let Ok((VerboseValue::TraceInfo(trace_info), _)) = VerboseValue::from_slice(&buf, true) {
+///     assert_eq!("func_a", trace_info.value());
+/// } else {
+///     panic!("expected trace info value");
+/// }
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TraceInfoValue<'a> {
+    /// Trace info string (without the zero termination).
     pub value: &'a str,
 }
 
 impl<'a> TraceInfoValue<'a> {
+    /// Returns the trace info string (without the zero termination).
+    #[inline]
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
     /// Adds the verbose value to the given dlt mesage buffer.
-    pub fn add_to_msg<const CAP: usize>(
+    pub fn add_to_msg<B: EncodeBuf>(
         &self,
-        buf: &mut ArrayVec<u8, CAP>,
+        buf: &mut B,
         is_big_endian: bool,
     ) -> Result<(), CapacityError> {
         let type_info = [0b0000_0000, 0b0010_0000, 0b0000_0000, 0b0000_0000];
@@ -40,6 +66,7 @@ mod test {
     use crate::verbose::VerboseValue;
     use crate::verbose::VerboseValue::TraceInfo;
     use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::format;
 
@@ -60,6 +87,7 @@ mod test {
                 let is_big_endian = true;
 
                 let trace_value = TraceInfoValue {value};
+                prop_assert_eq!(value.as_str(), trace_value.value());
                 let mut content_buff = Vec::with_capacity(slice_len);
 
                 let len_value_be = (value.len() as u16 + 1).to_be_bytes();
@@ -121,7 +149,7 @@ mod test {
                 const SLICE_LEN: usize = BYTES_NEEDED - 1;
 
                 let trace_value = TraceInfoValue {value};
-                let is_big_endian = true;
+                let is_big_endian = false;
 
                 let mut msg_buff: ArrayVec<u8, SLICE_LEN> = ArrayVec::new();
                 prop_assert_eq!(trace_value.add_to_msg(&mut msg_buff, is_big_endian), Err(CapacityError::new(())));
@@ -1,4 +1,4 @@
-use super::VerboseValue;
+use super::{TolerantVerboseIter, VerboseValue};
 use crate::error::VerboseDecodeError;
 
 /// Iterator over verbose values.
@@ -36,11 +36,26 @@ impl<'a> VerboseIter<'a> {
         self.number_of_arguments
     }
 
-    /// Raw data.
+    /// Raw data that has not yet been decoded.
+    ///
+    /// After all arguments were decoded this contains the payload bytes
+    /// following the last argument (the complete payload if the message
+    /// has no arguments).
     #[inline]
     pub fn raw(&self) -> &'a [u8] {
         self.rest
     }
+
+    /// Converts the iterator into an iterator that reports the index &
+    /// byte offset of an argument that could not be decoded.
+    ///
+    /// Useful to get as many values as possible out of messages from
+    /// encoders that produce invalid arguments (all values before the
+    /// invalid argument are still returned).
+    #[inline]
+    pub fn tolerant(self) -> TolerantVerboseIter<'a> {
+        TolerantVerboseIter::new(self)
+    }
 }
 
 impl<'a> core::iter::Iterator for VerboseIter<'a> {
@@ -65,6 +80,17 @@ impl<'a> core::iter::Iterator for VerboseIter<'a> {
             }
         }
     }
+
+    /// Returns the number of arguments left as upper bound.
+    ///
+    /// The lower bound is at most 1 as the iteration ends after the
+    /// first value that can not be decoded (which is why
+    /// `ExactSizeIterator` is not implemented).
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::from(self.number_of_arguments);
+        (core::cmp::min(remaining, 1), Some(remaining))
+    }
 }
 
 #[cfg(test)]
@@ -172,4 +198,47 @@ mod test {
             assert_eq!(None, iter.next());
         }
     }
+
+    #[test]
+    fn size_hint() {
+        let mut data = ArrayVec::<u8, 1000>::new();
+        for value in 0..3 {
+            U16Value {
+                variable_info: None,
+                scaling: None,
+                value,
+            }
+            .add_to_msg(&mut data, true)
+            .unwrap();
+        }
+
+        // hint decreases with every value
+        {
+            let mut iter = VerboseIter::new(true, 3, &data);
+            assert_eq!((1, Some(3)), iter.size_hint());
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!((1, Some(2)), iter.size_hint());
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!((1, Some(1)), iter.size_hint());
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!((0, Some(0)), iter.size_hint());
+            assert_eq!(None, iter.next());
+            assert_eq!((0, Some(0)), iter.size_hint());
+        }
+
+        // iteration ends after an error
+        {
+            let mut iter = VerboseIter::new(true, 5, &data);
+            for _ in 0..3 {
+                assert!(iter.next().unwrap().is_ok());
+            }
+            assert_eq!((1, Some(2)), iter.size_hint());
+            assert!(iter.next().unwrap().is_err());
+            assert_eq!((0, Some(0)), iter.size_hint());
+            assert_eq!(None, iter.next());
+        }
+
+        // count matches the number of arguments for valid data
+        assert_eq!(3, VerboseIter::new(true, 3, &data).count());
+    }
 }
@@ -0,0 +1,229 @@
+use super::*;
+
+/// Type of a [`VerboseScalar`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u8)]
+pub enum VerboseScalarType {
+    Bool = 0,
+    I8 = 1,
+    I16 = 2,
+    I32 = 3,
+    I64 = 4,
+    I128 = 5,
+    U8 = 6,
+    U16 = 7,
+    U32 = 8,
+    U64 = 9,
+    U128 = 10,
+    F16 = 11,
+    F32 = 12,
+    F64 = 13,
+    F128 = 14,
+}
+
+/// Decoded scalar verbose value stored as a type tag & the raw bits of
+/// the value (see [`VerboseScalarBuf`]).
+///
+/// Signed integers are stored sign extended to 128 bits, unsigned integers
+/// & bools zero extended and floating point values as their bit pattern.
+/// Scaling & variable infos of the original value are not stored.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct VerboseScalar {
+    pub scalar_type: VerboseScalarType,
+    pub bits: u128,
+}
+
+impl VerboseScalar {
+    /// Converts a verbose value into a scalar (returns `None` for
+    /// strings, trace infos, arrays, structs & raw values).
+    pub fn from_value(value: &VerboseValue<'_>) -> Option<VerboseScalar> {
+        use VerboseScalarType as T;
+        use VerboseValue::*;
+
+        let (scalar_type, bits) = match value {
+            Bool(v) => (T::Bool, u128::from(v.value)),
+            I8(v) => (T::I8, i128::from(v.value) as u128),
+            I16(v) => (T::I16, i128::from(v.value) as u128),
+            I32(v) => (T::I32, i128::from(v.value) as u128),
+            I64(v) => (T::I64, i128::from(v.value) as u128),
+            I128(v) => (T::I128, v.value as u128),
+            U8(v) => (T::U8, u128::from(v.value)),
+            U16(v) => (T::U16, u128::from(v.value)),
+            U32(v) => (T::U32, u128::from(v.value)),
+            U64(v) => (T::U64, u128::from(v.value)),
+            U128(v) => (T::U128, v.value),
+            F16(v) => (T::F16, u128::from(v.value.to_bits())),
+            F32(v) => (T::F32, u128::from(v.value.to_bits())),
+            F64(v) => (T::F64, u128::from(v.value.to_bits())),
+            F128(v) => (T::F128, v.value.to_bits()),
+            _ => return None,
+        };
+        Some(VerboseScalar { scalar_type, bits })
+    }
+
+    /// Returns the value if the scalar is a bool.
+    pub fn as_bool(&self) -> Option<bool> {
+        if VerboseScalarType::Bool == self.scalar_type {
+            Some(0 != self.bits)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as `i128` if the scalar is an integer
+    /// that can be represented as `i128`.
+    pub fn as_i128(&self) -> Option<i128> {
+        use VerboseScalarType::*;
+        match self.scalar_type {
+            I8 | I16 | I32 | I64 | I128 => Some(self.bits as i128),
+            U8 | U16 | U32 | U64 | U128 => i128::try_from(self.bits).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as `u128` if the scalar is an integer
+    /// that can be represented as `u128`.
+    pub fn as_u128(&self) -> Option<u128> {
+        use VerboseScalarType::*;
+        match self.scalar_type {
+            I8 | I16 | I32 | I64 | I128 => u128::try_from(self.bits as i128).ok(),
+            U8 | U16 | U32 | U64 | U128 => Some(self.bits),
+            _ => None,
+        }
+    }
+
+    /// Returns the value converted to `f64` if the scalar is a number
+    /// (returns `None` for bools & 128 bit floating point values).
+    pub fn as_f64(&self) -> Option<f64> {
+        use VerboseScalarType::*;
+        match self.scalar_type {
+            Bool | F128 => None,
+            I8 | I16 | I32 | I64 | I128 => Some(self.bits as i128 as f64),
+            U8 | U16 | U32 | U64 | U128 => Some(self.bits as f64),
+            F16 => Some(f64::from(RawF16::from_bits(self.bits as u16).to_f32())),
+            F32 => Some(f64::from(f32::from_bits(self.bits as u32))),
+            F64 => Some(f64::from_bits(self.bits as u64)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_value_ints(value_i64 in any::<i64>(), value_u64 in any::<u64>(), value_i128 in any::<i128>()) {
+            // signed
+            let s = VerboseScalar::from_value(&VerboseValue::I64(I64Value {
+                variable_info: None,
+                scaling: None,
+                value: value_i64,
+            })).unwrap();
+            prop_assert_eq!(VerboseScalarType::I64, s.scalar_type);
+            prop_assert_eq!(None, s.as_bool());
+            prop_assert_eq!(Some(i128::from(value_i64)), s.as_i128());
+            prop_assert_eq!(u128::try_from(value_i64).ok(), s.as_u128());
+            prop_assert_eq!(Some(value_i64 as f64), s.as_f64());
+
+            // unsigned
+            let s = VerboseScalar::from_value(&VerboseValue::U64(U64Value {
+                variable_info: None,
+                scaling: None,
+                value: value_u64,
+            })).unwrap();
+            prop_assert_eq!(VerboseScalarType::U64, s.scalar_type);
+            prop_assert_eq!(Some(i128::from(value_u64)), s.as_i128());
+            prop_assert_eq!(Some(u128::from(value_u64)), s.as_u128());
+            prop_assert_eq!(Some(value_u64 as f64), s.as_f64());
+
+            // 128 bit
+            let s = VerboseScalar::from_value(&VerboseValue::I128(I128Value {
+                variable_info: None,
+                scaling: None,
+                value: value_i128,
+            })).unwrap();
+            prop_assert_eq!(Some(value_i128), s.as_i128());
+            prop_assert_eq!(u128::try_from(value_i128).ok(), s.as_u128());
+        }
+    }
+
+    #[test]
+    fn from_value() {
+        // bool
+        {
+            let s = VerboseScalar::from_value(&VerboseValue::Bool(BoolValue {
+                name: None,
+                value: true,
+            }))
+            .unwrap();
+            assert_eq!(VerboseScalarType::Bool, s.scalar_type);
+            assert_eq!(Some(true), s.as_bool());
+            assert_eq!(None, s.as_i128());
+            assert_eq!(None, s.as_u128());
+            assert_eq!(None, s.as_f64());
+        }
+        // u128 bigger than i128::MAX
+        {
+            let s = VerboseScalar::from_value(&VerboseValue::U128(U128Value {
+                variable_info: None,
+                scaling: None,
+                value: u128::MAX,
+            }))
+            .unwrap();
+            assert_eq!(None, s.as_i128());
+            assert_eq!(Some(u128::MAX), s.as_u128());
+        }
+        // floats
+        {
+            let s = VerboseScalar::from_value(&VerboseValue::F16(F16Value {
+                variable_info: None,
+                value: RawF16::ONE,
+            }))
+            .unwrap();
+            assert_eq!(VerboseScalarType::F16, s.scalar_type);
+            assert_eq!(Some(1.0), s.as_f64());
+
+            let s = VerboseScalar::from_value(&VerboseValue::F32(F32Value {
+                variable_info: None,
+                value: 1.5,
+            }))
+            .unwrap();
+            assert_eq!(VerboseScalarType::F32, s.scalar_type);
+            assert_eq!(Some(1.5), s.as_f64());
+            assert_eq!(None, s.as_i128());
+
+            let s = VerboseScalar::from_value(&VerboseValue::F64(F64Value {
+                variable_info: None,
+                value: -2.25,
+            }))
+            .unwrap();
+            assert_eq!(VerboseScalarType::F64, s.scalar_type);
+            assert_eq!(Some(-2.25), s.as_f64());
+
+            let s = VerboseScalar::from_value(&VerboseValue::F128(F128Value {
+                variable_info: None,
+                value: RawF128::from_bits(123),
+            }))
+            .unwrap();
+            assert_eq!(VerboseScalarType::F128, s.scalar_type);
+            assert_eq!(123, s.bits);
+            assert_eq!(None, s.as_f64());
+        }
+        // non scalars
+        {
+            assert_eq!(
+                None,
+                VerboseScalar::from_value(&VerboseValue::Str(StringValue::new(None, "a")))
+            );
+            assert_eq!(
+                None,
+                VerboseScalar::from_value(&VerboseValue::Raw(RawValue {
+                    name: None,
+                    data: &[1, 2],
+                }))
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+use arrayvec::ArrayVec;
+
+/// Fixed size buffer for storing the scalar arguments of a verbose
+/// message without allocations (filled via
+/// [`crate::DltPacketSlice::decode_scalars_into`]).
+///
+/// Non scalar arguments (strings, arrays, structs, ...) are skipped
+/// & only counted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerboseScalarBuf<const N: usize> {
+    values: ArrayVec<VerboseScalar, N>,
+    num_skipped: usize,
+    num_overflowed: usize,
+}
+
+impl<const N: usize> VerboseScalarBuf<N> {
+    /// Creates an empty buffer.
+    pub fn new() -> VerboseScalarBuf<N> {
+        VerboseScalarBuf {
+            values: ArrayVec::new(),
+            num_skipped: 0,
+            num_overflowed: 0,
+        }
+    }
+
+    /// Returns the stored scalar values.
+    #[inline]
+    pub fn values(&self) -> &[VerboseScalar] {
+        &self.values
+    }
+
+    /// Number of non scalar arguments that were skipped.
+    #[inline]
+    pub fn num_skipped(&self) -> usize {
+        self.num_skipped
+    }
+
+    /// Number of scalar arguments that were not stored as the
+    /// buffer was already full.
+    #[inline]
+    pub fn num_overflowed(&self) -> usize {
+        self.num_overflowed
+    }
+
+    /// Removes all values & resets the counters.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.num_skipped = 0;
+        self.num_overflowed = 0;
+    }
+
+    /// Adds the given value if it is a scalar & space is left.
+    pub fn push(&mut self, value: &VerboseValue<'_>) {
+        match VerboseScalar::from_value(value) {
+            Some(scalar) => {
+                if self.values.try_push(scalar).is_err() {
+                    self.num_overflowed += 1;
+                }
+            }
+            None => self.num_skipped += 1,
+        }
+    }
+}
+
+impl<const N: usize> Default for VerboseScalarBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_clear() {
+        let mut buf = VerboseScalarBuf::<2>::default();
+        assert!(buf.values().is_empty());
+
+        buf.push(&VerboseValue::U16(U16Value {
+            variable_info: None,
+            scaling: None,
+            value: 12,
+        }));
+        buf.push(&VerboseValue::Str(StringValue::new(None, "a")));
+        buf.push(&VerboseValue::Bool(BoolValue {
+            name: None,
+            value: true,
+        }));
+        buf.push(&VerboseValue::I8(I8Value {
+            variable_info: None,
+            scaling: None,
+            value: -1,
+        }));
+
+        assert_eq!(
+            buf.values(),
+            &[
+                VerboseScalar {
+                    scalar_type: VerboseScalarType::U16,
+                    bits: 12
+                },
+                VerboseScalar {
+                    scalar_type: VerboseScalarType::Bool,
+                    bits: 1
+                },
+            ]
+        );
+        assert_eq!(1, buf.num_skipped());
+        assert_eq!(1, buf.num_overflowed());
+
+        buf.clear();
+        assert_eq!(buf, VerboseScalarBuf::new());
+    }
+}
@@ -1,7 +1,9 @@
 use crate::error::VerboseDecodeError;
+use crate::verbose::EncodeBuf;
 
 use super::*;
 
+use arrayvec::CapacityError;
 use core::slice;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -619,12 +621,13 @@ impl<'a> VerboseValue<'a> {
             } else {
                 None
             };
-            let value = match slicer.read_raw(len) {
+            // ASCII coded strings are only validated to be UTF-8 when accessed
+            let data = match slicer.read_raw(len) {
                 Ok(valid_parse) => {
                     if len > 0 {
-                        core::str::from_utf8(&valid_parse[..valid_parse.len() - 1])?
+                        &valid_parse[..valid_parse.len() - 1]
                     } else {
-                        ""
+                        valid_parse
                     }
                 }
                 Err(_) => {
@@ -636,7 +639,15 @@ impl<'a> VerboseValue<'a> {
                 }
             };
 
-            Ok((Str(StringValue { name, value }), slicer.rest()))
+            let encoding = StringEncoding::from_type_info(type_info);
+            if StringEncoding::Utf8 == encoding {
+                str::from_utf8(data).map_err(Utf8)?;
+            }
+
+            Ok((
+                Str(StringValue::with_encoding(name, encoding, data)),
+                slicer.rest(),
+            ))
         } else if 0 != type_info[1] & RAW_FLAG_1 {
             // verify no conflicting information is present+
             const CONTRADICTING_MASK_0: u8 = 0b1111_0000;
@@ -747,6 +758,52 @@ impl<'a> VerboseValue<'a> {
         }
     }
 
+    /// Adds the verbose value to the given dlt mesage buffer.
+    pub fn add_to_msg<B: EncodeBuf>(
+        &self,
+        buf: &mut B,
+        is_big_endian: bool,
+    ) -> Result<(), CapacityError> {
+        use VerboseValue::*;
+
+        match self {
+            Bool(v) => v.add_to_msg(buf, is_big_endian),
+            Str(v) => v.add_to_msg(buf, is_big_endian),
+            TraceInfo(v) => v.add_to_msg(buf, is_big_endian),
+            I8(v) => v.add_to_msg(buf, is_big_endian),
+            I16(v) => v.add_to_msg(buf, is_big_endian),
+            I32(v) => v.add_to_msg(buf, is_big_endian),
+            I64(v) => v.add_to_msg(buf, is_big_endian),
+            I128(v) => v.add_to_msg(buf, is_big_endian),
+            U8(v) => v.add_to_msg(buf, is_big_endian),
+            U16(v) => v.add_to_msg(buf, is_big_endian),
+            U32(v) => v.add_to_msg(buf, is_big_endian),
+            U64(v) => v.add_to_msg(buf, is_big_endian),
+            U128(v) => v.add_to_msg(buf, is_big_endian),
+            F16(v) => v.add_to_msg(buf, is_big_endian),
+            F32(v) => v.add_to_msg(buf, is_big_endian),
+            F64(v) => v.add_to_msg(buf, is_big_endian),
+            F128(v) => v.add_to_msg(buf, is_big_endian),
+            ArrBool(v) => v.add_to_msg(buf, is_big_endian),
+            ArrI8(v) => v.add_to_msg(buf, is_big_endian),
+            ArrI16(v) => v.add_to_msg(buf, is_big_endian),
+            ArrI32(v) => v.add_to_msg(buf, is_big_endian),
+            ArrI64(v) => v.add_to_msg(buf, is_big_endian),
+            ArrI128(v) => v.add_to_msg(buf, is_big_endian),
+            ArrU8(v) => v.add_to_msg(buf, is_big_endian),
+            ArrU16(v) => v.add_to_msg(buf, is_big_endian),
+            ArrU32(v) => v.add_to_msg(buf, is_big_endian),
+            ArrU64(v) => v.add_to_msg(buf, is_big_endian),
+            ArrU128(v) => v.add_to_msg(buf, is_big_endian),
+            ArrF16(v) => v.add_to_msg(buf, is_big_endian),
+            ArrF32(v) => v.add_to_msg(buf, is_big_endian),
+            ArrF64(v) => v.add_to_msg(buf, is_big_endian),
+            ArrF128(v) => v.add_to_msg(buf, is_big_endian),
+            Struct(v) => v.add_to_msg(buf, is_big_endian),
+            Raw(v) => v.add_to_msg(buf, is_big_endian),
+        }
+    }
+
     /// Returns the name of the value (if it has one).
     pub fn name(&self) -> Option<&'a str> {
         use VerboseValue::*;
@@ -830,4 +887,906 @@ impl<'a> VerboseValue<'a> {
             Raw(_) => None,
         }
     }
+
+    /// Returns the name of the type of the value (equal to the name
+    /// of the enum variant, e.g. `"U32"` for [`VerboseValue::U32`]).
+    pub fn type_name(&self) -> &'static str {
+        use VerboseValue::*;
+
+        match self {
+            Bool(_) => "Bool",
+            Str(_) => "Str",
+            TraceInfo(_) => "TraceInfo",
+            I8(_) => "I8",
+            I16(_) => "I16",
+            I32(_) => "I32",
+            I64(_) => "I64",
+            I128(_) => "I128",
+            U8(_) => "U8",
+            U16(_) => "U16",
+            U32(_) => "U32",
+            U64(_) => "U64",
+            U128(_) => "U128",
+            F16(_) => "F16",
+            F32(_) => "F32",
+            F64(_) => "F64",
+            F128(_) => "F128",
+            ArrBool(_) => "ArrBool",
+            ArrI8(_) => "ArrI8",
+            ArrI16(_) => "ArrI16",
+            ArrI32(_) => "ArrI32",
+            ArrI64(_) => "ArrI64",
+            ArrI128(_) => "ArrI128",
+            ArrU8(_) => "ArrU8",
+            ArrU16(_) => "ArrU16",
+            ArrU32(_) => "ArrU32",
+            ArrU64(_) => "ArrU64",
+            ArrU128(_) => "ArrU128",
+            ArrF16(_) => "ArrF16",
+            ArrF32(_) => "ArrF32",
+            ArrF64(_) => "ArrF64",
+            ArrF128(_) => "ArrF128",
+            Struct(_) => "Struct",
+            Raw(_) => "Raw",
+        }
+    }
+
+    /// Returns the type info (first 4 bytes of the encoded value) that
+    /// identifies the type of the value.
+    ///
+    /// The type info is not stored during decoding but determined based
+    /// on the type and the optional fields (e.g. name, unit & scaling) of
+    /// the value. It is identical to the type info written by
+    /// [`VerboseValue::add_to_msg`] and to the decoded type info, with the
+    /// exception of bits that are ignored during decoding (string coding
+    /// bits of non string values & the type length of raw values).
+    pub fn type_info(&self) -> [u8; 4] {
+        use VerboseValue::*;
+
+        const BOOL_FLAG_0: u8 = 0b0001_0000;
+        const SIGNED_FLAG_0: u8 = 0b0010_0000;
+        const UNSIGNED_FLAG_0: u8 = 0b0100_0000;
+        const FLOAT_FLAG_0: u8 = 0b1000_0000;
+
+        const ARRAY_FLAG_1: u8 = 0b0000_0001;
+        const STRING_FLAG_1: u8 = 0b0000_0010;
+        const RAW_FLAG_1: u8 = 0b0000_0100;
+        const VARINFO_FLAG_1: u8 = 0b0000_1000;
+        const FIXED_POINT_FLAG_1: u8 = 0b0001_0000;
+        const TRACE_INFO_FLAG_1: u8 = 0b0010_0000;
+        const STRUCT_FLAG_1: u8 = 0b0100_0000;
+
+        fn flags_1(is_array: bool, has_variable_info: bool, has_scaling: bool) -> u8 {
+            let mut result = 0;
+            if is_array {
+                result |= ARRAY_FLAG_1;
+            }
+            if has_variable_info {
+                result |= VARINFO_FLAG_1;
+            }
+            if has_scaling {
+                result |= FIXED_POINT_FLAG_1;
+            }
+            result
+        }
+
+        let (type_info_0, type_info_1) = match self {
+            Bool(v) => (BOOL_FLAG_0 | 1, flags_1(false, v.name.is_some(), false)),
+            Str(v) => {
+                return v.encoding().set_in_type_info([
+                    0,
+                    STRING_FLAG_1 | flags_1(false, v.name.is_some(), false),
+                    0,
+                    0,
+                ])
+            }
+            TraceInfo(_) => (0, TRACE_INFO_FLAG_1),
+            I8(v) => (
+                SIGNED_FLAG_0 | 1,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            I16(v) => (
+                SIGNED_FLAG_0 | 2,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            I32(v) => (
+                SIGNED_FLAG_0 | 3,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            I64(v) => (
+                SIGNED_FLAG_0 | 4,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            I128(v) => (
+                SIGNED_FLAG_0 | 5,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            U8(v) => (
+                UNSIGNED_FLAG_0 | 1,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            U16(v) => (
+                UNSIGNED_FLAG_0 | 2,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            U32(v) => (
+                UNSIGNED_FLAG_0 | 3,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            U64(v) => (
+                UNSIGNED_FLAG_0 | 4,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            U128(v) => (
+                UNSIGNED_FLAG_0 | 5,
+                flags_1(false, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            F16(v) => (
+                FLOAT_FLAG_0 | 2,
+                flags_1(false, v.variable_info.is_some(), false),
+            ),
+            F32(v) => (
+                FLOAT_FLAG_0 | 3,
+                flags_1(false, v.variable_info.is_some(), false),
+            ),
+            F64(v) => (
+                FLOAT_FLAG_0 | 4,
+                flags_1(false, v.variable_info.is_some(), false),
+            ),
+            F128(v) => (
+                FLOAT_FLAG_0 | 5,
+                flags_1(false, v.variable_info.is_some(), false),
+            ),
+            ArrBool(v) => (
+                BOOL_FLAG_0 | 1,
+                flags_1(true, v.variable_info.is_some(), false),
+            ),
+            ArrI8(v) => (
+                SIGNED_FLAG_0 | 1,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrI16(v) => (
+                SIGNED_FLAG_0 | 2,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrI32(v) => (
+                SIGNED_FLAG_0 | 3,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrI64(v) => (
+                SIGNED_FLAG_0 | 4,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrI128(v) => (
+                SIGNED_FLAG_0 | 5,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrU8(v) => (
+                UNSIGNED_FLAG_0 | 1,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrU16(v) => (
+                UNSIGNED_FLAG_0 | 2,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrU32(v) => (
+                UNSIGNED_FLAG_0 | 3,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrU64(v) => (
+                UNSIGNED_FLAG_0 | 4,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrU128(v) => (
+                UNSIGNED_FLAG_0 | 5,
+                flags_1(true, v.variable_info.is_some(), v.scaling.is_some()),
+            ),
+            ArrF16(v) => (
+                FLOAT_FLAG_0 | 2,
+                flags_1(true, v.variable_info.is_some(), false),
+            ),
+            ArrF32(v) => (
+                FLOAT_FLAG_0 | 3,
+                flags_1(true, v.variable_info.is_some(), false),
+            ),
+            ArrF64(v) => (
+                FLOAT_FLAG_0 | 4,
+                flags_1(true, v.variable_info.is_some(), false),
+            ),
+            ArrF128(v) => (
+                FLOAT_FLAG_0 | 5,
+                flags_1(true, v.variable_info.is_some(), false),
+            ),
+            Struct(v) => (0, STRUCT_FLAG_1 | flags_1(false, v.name.is_some(), false)),
+            Raw(v) => (0, RAW_FLAG_1 | flags_1(false, v.name.is_some(), false)),
+        };
+        [type_info_0, type_info_1, 0, 0]
+    }
+
+    /// Returns the number of bytes [`VerboseValue::add_to_msg`] writes
+    /// for the value (type info, name & unit including their zero
+    /// terminations, array dimensions, scaling & data).
+    ///
+    /// The length does not depend on the endianness used for encoding.
+    pub fn encoded_len(&self) -> usize {
+        use VerboseValue::*;
+
+        /// Length of a name (length field, string & zero termination).
+        fn name_len(name: Option<&str>) -> usize {
+            name.map(|n| 2 + n.len() + 1).unwrap_or(0)
+        }
+
+        /// Length of a variable info (length fields, strings & zero terminations).
+        fn var_info_len(var_info: &Option<VariableInfoUnit<'_>>) -> usize {
+            var_info
+                .as_ref()
+                .map(|v| 4 + v.name.len() + 1 + v.unit.len() + 1)
+                .unwrap_or(0)
+        }
+
+        /// Length of the scaling (quantization & offset).
+        fn scaling_len<T>(scaling: &Option<Scaling<T>>) -> usize {
+            scaling
+                .as_ref()
+                .map(|_| 4 + core::mem::size_of::<T>())
+                .unwrap_or(0)
+        }
+
+        /// Length of the number of dimensions & the dimensions.
+        fn dims_len(dimensions: &ArrayDimensions<'_>) -> usize {
+            2 + dimensions.dimensions.len()
+        }
+
+        const TYPE_INFO_LEN: usize = 4;
+
+        TYPE_INFO_LEN
+            + match self {
+                Bool(v) => name_len(v.name) + 1,
+                Str(v) => 2 + name_len(v.name) + v.as_bytes().len() + 1,
+                TraceInfo(v) => 2 + v.value.len() + 1,
+                I8(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 1,
+                I16(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 2,
+                I32(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 4,
+                I64(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 8,
+                I128(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 16,
+                U8(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 1,
+                U16(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 2,
+                U32(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 4,
+                U64(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 8,
+                U128(v) => var_info_len(&v.variable_info) + scaling_len(&v.scaling) + 16,
+                F16(v) => var_info_len(&v.variable_info) + 2,
+                F32(v) => var_info_len(&v.variable_info) + 4,
+                F64(v) => var_info_len(&v.variable_info) + 8,
+                F128(v) => var_info_len(&v.variable_info) + 16,
+                ArrBool(v) => {
+                    dims_len(&v.dimensions) + var_info_len(&v.variable_info) + v.data.len()
+                }
+                ArrI8(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrI16(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrI32(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrI64(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrI128(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrU8(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrU16(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrU32(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrU64(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrU128(v) => {
+                    dims_len(&v.dimensions)
+                        + var_info_len(&v.variable_info)
+                        + scaling_len(&v.scaling)
+                        + v.data.len()
+                }
+                ArrF16(v) => {
+                    dims_len(&v.dimensions) + var_info_len(&v.variable_info) + v.data.len()
+                }
+                ArrF32(v) => {
+                    dims_len(&v.dimensions) + var_info_len(&v.variable_info) + v.data.len()
+                }
+                ArrF64(v) => {
+                    dims_len(&v.dimensions) + var_info_len(&v.variable_info) + v.data.len()
+                }
+                ArrF128(v) => {
+                    dims_len(&v.dimensions) + var_info_len(&v.variable_info) + v.data.len()
+                }
+                Struct(v) => 2 + name_len(v.name) + v.entries_data.len(),
+                Raw(v) => 2 + name_len(v.name) + v.data.len(),
+            }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::Bool`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_bool(&self) -> Result<bool, error::TypedPayloadError> {
+        match self {
+            VerboseValue::Bool(v) => Ok(v.value),
+            other => Err(other.type_mismatch("Bool")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::Str`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned
+    /// ([`error::TypedPayloadError::Utf8`] if the string data is not
+    /// valid UTF-8).
+    pub fn expect_str(&self) -> Result<&'a str, error::TypedPayloadError> {
+        match self {
+            VerboseValue::Str(v) => v.to_str().map_err(error::TypedPayloadError::Utf8),
+            other => Err(other.type_mismatch("Str")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::I8`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_i8(&self) -> Result<i8, error::TypedPayloadError> {
+        match self {
+            VerboseValue::I8(v) => Ok(v.value),
+            other => Err(other.type_mismatch("I8")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::I16`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_i16(&self) -> Result<i16, error::TypedPayloadError> {
+        match self {
+            VerboseValue::I16(v) => Ok(v.value),
+            other => Err(other.type_mismatch("I16")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::I32`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_i32(&self) -> Result<i32, error::TypedPayloadError> {
+        match self {
+            VerboseValue::I32(v) => Ok(v.value),
+            other => Err(other.type_mismatch("I32")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::I64`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_i64(&self) -> Result<i64, error::TypedPayloadError> {
+        match self {
+            VerboseValue::I64(v) => Ok(v.value),
+            other => Err(other.type_mismatch("I64")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::U8`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_u8(&self) -> Result<u8, error::TypedPayloadError> {
+        match self {
+            VerboseValue::U8(v) => Ok(v.value),
+            other => Err(other.type_mismatch("U8")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::U16`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_u16(&self) -> Result<u16, error::TypedPayloadError> {
+        match self {
+            VerboseValue::U16(v) => Ok(v.value),
+            other => Err(other.type_mismatch("U16")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::U32`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_u32(&self) -> Result<u32, error::TypedPayloadError> {
+        match self {
+            VerboseValue::U32(v) => Ok(v.value),
+            other => Err(other.type_mismatch("U32")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::U64`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_u64(&self) -> Result<u64, error::TypedPayloadError> {
+        match self {
+            VerboseValue::U64(v) => Ok(v.value),
+            other => Err(other.type_mismatch("U64")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::F32`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_f32(&self) -> Result<f32, error::TypedPayloadError> {
+        match self {
+            VerboseValue::F32(v) => Ok(v.value),
+            other => Err(other.type_mismatch("F32")),
+        }
+    }
+
+    /// Returns the value if it is a [`VerboseValue::F64`] otherwise a
+    /// [`error::TypedPayloadError::TypeMismatch`] error is returned.
+    pub fn expect_f64(&self) -> Result<f64, error::TypedPayloadError> {
+        match self {
+            VerboseValue::F64(v) => Ok(v.value),
+            other => Err(other.type_mismatch("F64")),
+        }
+    }
+
+    /// Returns the value of an integer scalar as `u64` if it can be
+    /// represented as `u64`.
+    ///
+    /// Unsigned integers up to 64 bits are always returned. Signed
+    /// integers & `U128` values are only returned if they are in the
+    /// range of `u64` (e.g. not negative). For all other values (floats,
+    /// bools, strings, arrays, structs ...) `None` is returned.
+    ///
+    /// Note that the raw value is returned without applying the scaling.
+    pub fn as_u64(&self) -> Option<u64> {
+        use VerboseValue::*;
+        match self {
+            I8(v) => u64::try_from(v.value).ok(),
+            I16(v) => u64::try_from(v.value).ok(),
+            I32(v) => u64::try_from(v.value).ok(),
+            I64(v) => u64::try_from(v.value).ok(),
+            I128(v) => u64::try_from(v.value).ok(),
+            U8(v) => Some(u64::from(v.value)),
+            U16(v) => Some(u64::from(v.value)),
+            U32(v) => Some(u64::from(v.value)),
+            U64(v) => Some(v.value),
+            U128(v) => u64::try_from(v.value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an integer or float scalar as `f64`.
+    ///
+    /// Integers are converted with `as` & are rounded to the nearest
+    /// representable `f64` if they have more than 53 significant bits.
+    /// `F16` & `F32` values are converted exactly. For `F128` values & all
+    /// non numeric values (bools, strings, arrays, structs ...) `None` is
+    /// returned.
+    ///
+    /// Note that the raw value is returned without applying the scaling.
+    pub fn as_f64(&self) -> Option<f64> {
+        use VerboseValue::*;
+        match self {
+            I8(v) => Some(f64::from(v.value)),
+            I16(v) => Some(f64::from(v.value)),
+            I32(v) => Some(f64::from(v.value)),
+            I64(v) => Some(v.value as f64),
+            I128(v) => Some(v.value as f64),
+            U8(v) => Some(f64::from(v.value)),
+            U16(v) => Some(f64::from(v.value)),
+            U32(v) => Some(f64::from(v.value)),
+            U64(v) => Some(v.value as f64),
+            U128(v) => Some(v.value as f64),
+            F16(v) => Some(v.value.to_f64()),
+            F32(v) => Some(f64::from(v.value)),
+            F64(v) => Some(v.value),
+            _ => None,
+        }
+    }
+
+    /// Returns a type mismatch error with the given expected type name
+    /// and the type name of the value as actual type.
+    fn type_mismatch(&self, expected: &'static str) -> error::TypedPayloadError {
+        error::TypedPayloadError::TypeMismatch {
+            expected,
+            actual: self.type_name(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TypedPayloadError::TypeMismatch;
+    use arrayvec::ArrayVec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn type_name() {
+        assert_eq!(
+            "Bool",
+            VerboseValue::Bool(BoolValue {
+                name: None,
+                value: true
+            })
+            .type_name()
+        );
+        assert_eq!(
+            "U32",
+            VerboseValue::U32(U32Value {
+                variable_info: None,
+                scaling: None,
+                value: 1
+            })
+            .type_name()
+        );
+        assert_eq!(
+            "ArrU8",
+            VerboseValue::ArrU8(ArrayU8 {
+                dimensions: ArrayDimensions {
+                    is_big_endian: false,
+                    dimensions: &[],
+                },
+                variable_info: None,
+                scaling: None,
+                data: &[],
+            })
+            .type_name()
+        );
+    }
+
+    #[test]
+    fn expect() {
+        let b = VerboseValue::Bool(BoolValue {
+            name: None,
+            value: true,
+        });
+        let s = VerboseValue::Str(StringValue::new(None, "abc"));
+        let i16_value = VerboseValue::I16(I16Value {
+            variable_info: None,
+            scaling: None,
+            value: -2,
+        });
+        let u32_value = VerboseValue::U32(U32Value {
+            variable_info: None,
+            scaling: None,
+            value: 1234,
+        });
+        let f64_value = VerboseValue::F64(F64Value {
+            variable_info: None,
+            value: 1.5,
+        });
+
+        // matching types
+        assert_eq!(Ok(true), b.expect_bool());
+        assert_eq!(Ok("abc"), s.expect_str());
+        assert_eq!(Ok(-2), i16_value.expect_i16());
+        assert_eq!(Ok(1234), u32_value.expect_u32());
+        assert_eq!(Ok(1.5), f64_value.expect_f64());
+
+        // mismatching types
+        assert_eq!(
+            Err(TypeMismatch {
+                expected: "U32",
+                actual: "I16"
+            }),
+            i16_value.expect_u32()
+        );
+        assert_eq!(
+            Err(TypeMismatch {
+                expected: "Bool",
+                actual: "Str"
+            }),
+            s.expect_bool()
+        );
+        assert_eq!(
+            Err(TypeMismatch {
+                expected: "F32",
+                actual: "F64"
+            }),
+            f64_value.expect_f32()
+        );
+        assert_eq!(
+            Err(TypeMismatch {
+                expected: "I8",
+                actual: "U32"
+            }),
+            u32_value.expect_i8()
+        );
+    }
+
+    #[test]
+    fn as_u64_as_f64() {
+        use VerboseValue::*;
+
+        let values = [
+            (
+                I8(I8Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 12,
+                }),
+                Some(12),
+                Some(12.0),
+            ),
+            (
+                I8(I8Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: -12,
+                }),
+                None,
+                Some(-12.0),
+            ),
+            (
+                I16(I16Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: -1234,
+                }),
+                None,
+                Some(-1234.0),
+            ),
+            (
+                I32(I32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 123_456,
+                }),
+                Some(123_456),
+                Some(123_456.0),
+            ),
+            (
+                I64(I64Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: i64::MIN,
+                }),
+                None,
+                Some(i64::MIN as f64),
+            ),
+            (
+                I128(I128Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: i128::from(u64::MAX),
+                }),
+                Some(u64::MAX),
+                Some(u64::MAX as f64),
+            ),
+            (
+                U8(U8Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u8::MAX,
+                }),
+                Some(u64::from(u8::MAX)),
+                Some(f64::from(u8::MAX)),
+            ),
+            (
+                U16(U16Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u16::MAX,
+                }),
+                Some(u64::from(u16::MAX)),
+                Some(f64::from(u16::MAX)),
+            ),
+            (
+                U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u32::MAX,
+                }),
+                Some(u64::from(u32::MAX)),
+                Some(f64::from(u32::MAX)),
+            ),
+            (
+                U64(U64Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u64::MAX,
+                }),
+                Some(u64::MAX),
+                Some(u64::MAX as f64),
+            ),
+            (
+                U128(U128Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: u128::from(u64::MAX) + 1,
+                }),
+                None,
+                Some((u128::from(u64::MAX) + 1) as f64),
+            ),
+            (
+                F16(F16Value {
+                    variable_info: None,
+                    value: RawF16::from_bits(0x3e00),
+                }),
+                None,
+                Some(1.5),
+            ),
+            (
+                F32(F32Value {
+                    variable_info: None,
+                    value: -2.5,
+                }),
+                None,
+                Some(-2.5),
+            ),
+            (
+                F64(F64Value {
+                    variable_info: None,
+                    value: 1.0e100,
+                }),
+                None,
+                Some(1.0e100),
+            ),
+            (
+                F128(F128Value {
+                    variable_info: None,
+                    value: RawF128::from_bits(0),
+                }),
+                None,
+                None,
+            ),
+            (
+                Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+                None,
+                None,
+            ),
+            (
+                Str(StringValue::with_encoding(
+                    None,
+                    StringEncoding::Ascii,
+                    b"1",
+                )),
+                None,
+                None,
+            ),
+        ];
+        for (value, expected_u64, expected_f64) in values.iter() {
+            assert_eq!(*expected_u64, value.as_u64(), "{:?}", value);
+            assert_eq!(*expected_f64, value.as_f64(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn type_info() {
+        // decode all type infos with zeroed data & check that the
+        // decodable ones are returned unchanged
+        let mut num_decoded = 0;
+        for type_info_0 in 0..=u8::MAX {
+            for type_info_1 in 0..=u8::MAX {
+                for type_info_2 in 0..=0b11 {
+                    // string coding bits are ignored for non string types
+                    let is_string = 0 != type_info_1 & 0b0000_0010;
+                    if false == is_string && (0 != type_info_2 || 0 != type_info_1 & 0b1000_0000) {
+                        continue;
+                    }
+                    // type length is ignored for raw values
+                    let is_raw = 0 != type_info_1 & 0b0000_0100;
+                    if is_raw && 0 != type_info_0 & 0b0000_1111 {
+                        continue;
+                    }
+                    let mut data = [0u8; 64];
+                    data[..4].copy_from_slice(&[type_info_0, type_info_1, type_info_2, 0]);
+                    for is_big_endian in [false, true] {
+                        if let Ok((value, _)) = VerboseValue::from_slice(&data, is_big_endian) {
+                            num_decoded += 1;
+                            assert_eq!(&data[..4], &value.type_info(), "{:?}", value);
+
+                            // identical to the written type info
+                            let mut buf = ArrayVec::<u8, 64>::new();
+                            value.add_to_msg(&mut buf, is_big_endian).unwrap();
+                            assert_eq!(&buf[..4], &value.type_info(), "{:?}", value);
+                            assert_eq!(buf.len(), value.encoded_len(), "{:?}", value);
+                        }
+                    }
+                }
+            }
+        }
+        assert!(num_decoded > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn encoded_len(
+            ref name in "\\pc{0,10}",
+            ref unit in "\\pc{0,10}",
+            ref data in proptest::collection::vec(any::<u8>(), 0..32),
+            ref dims in proptest::collection::vec(any::<u8>(), 0..4),
+            has_name in any::<bool>(),
+            has_scaling in any::<bool>(),
+            is_big_endian in any::<bool>(),
+        ) {
+            use VerboseValue::*;
+
+            let name = if has_name { Some(name.as_str()) } else { None };
+            let variable_info = name.map(|name| VariableInfoUnit { name, unit });
+            let scaling_i32 = if has_scaling {
+                Some(Scaling { quantization: 1.0, offset: 2i32 })
+            } else {
+                None
+            };
+            let scaling_i64 = scaling_i32.as_ref().map(|s| Scaling {
+                quantization: s.quantization,
+                offset: i64::from(s.offset),
+            });
+            let scaling_i128 = scaling_i32.as_ref().map(|s| Scaling {
+                quantization: s.quantization,
+                offset: i128::from(s.offset),
+            });
+            let dimensions = ArrayDimensions {
+                is_big_endian,
+                dimensions: &dims[..dims.len() / 2 * 2],
+            };
+            let data = &data[..];
+
+            let values = [
+                Bool(BoolValue { name, value: true }),
+                Str(StringValue::new(name, unit)),
+                TraceInfo(TraceInfoValue { value: unit }),
+                I8(I8Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                I16(I16Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                I32(I32Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                I64(I64Value { variable_info: variable_info.clone(), scaling: scaling_i64.clone(), value: 1 }),
+                I128(I128Value { variable_info: variable_info.clone(), scaling: scaling_i128.clone(), value: 1 }),
+                U8(U8Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                U16(U16Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                U32(U32Value { variable_info: variable_info.clone(), scaling: scaling_i32.clone(), value: 1 }),
+                U64(U64Value { variable_info: variable_info.clone(), scaling: scaling_i64.clone(), value: 1 }),
+                U128(U128Value { variable_info: variable_info.clone(), scaling: scaling_i128.clone(), value: 1 }),
+                F16(F16Value { variable_info: variable_info.clone(), value: RawF16::from_bits(1) }),
+                F32(F32Value { variable_info: variable_info.clone(), value: 1.0 }),
+                F64(F64Value { variable_info: variable_info.clone(), value: 1.0 }),
+                F128(F128Value { variable_info: variable_info.clone(), value: RawF128::from_bits(1) }),
+                ArrBool(ArrayBool { dimensions: dimensions.clone(), variable_info: variable_info.clone(), data }),
+                ArrI8(ArrayI8 { dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrI16(ArrayI16 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrI32(ArrayI32 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrI64(ArrayI64 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i64.clone(), data }),
+                ArrI128(ArrayI128 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i128.clone(), data }),
+                ArrU8(ArrayU8 { dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrU16(ArrayU16 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrU32(ArrayU32 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i32.clone(), data }),
+                ArrU64(ArrayU64 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i64.clone(), data }),
+                ArrU128(ArrayU128 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), scaling: scaling_i128.clone(), data }),
+                ArrF16(ArrayF16 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), data }),
+                ArrF32(ArrayF32 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), data }),
+                ArrF64(ArrayF64 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), data }),
+                ArrF128(ArrayF128 { is_big_endian, dimensions: dimensions.clone(), variable_info: variable_info.clone(), data }),
+                Struct(StructValue { is_big_endian, number_of_entries: 0, name, entries_data: data }),
+                Raw(RawValue { name, data }),
+            ];
+            for value in &values {
+                let mut buf = ArrayVec::<u8, 256>::new();
+                value.add_to_msg(&mut buf, is_big_endian).unwrap();
+                prop_assert_eq!(buf.len(), value.encoded_len(), "{:?}", value);
+            }
+        }
+    }
 }
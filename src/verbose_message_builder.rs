@@ -0,0 +1,277 @@
+use crate::error::EncodeError;
+use crate::verbose::VerboseValue;
+use crate::*;
+
+/// Builder for complete serialized verbose DLT messages that writes
+/// directly into an [`ArrayVec`] (no allocations needed).
+///
+/// The `length` field of the header, the `number_of_arguments` field &
+/// the verbose flag of the extended header are set automatically based
+/// on the pushed values.
+///
+/// # Example
+///
+/// ```
+/// use dlt_parse::{DltHeader, DltExtendedHeader, DltLogLevel, DltPacketSlice, VerboseMessageBuilder};
+/// use dlt_parse::verbose::{U32Value, VerboseValue};
+///
+/// let header = DltHeader {
+///     is_big_endian: true,
+///     message_counter: 0,
+///     length: 0, // set by the builder
+///     ecu_id: None,
+///     session_id: None,
+///     timestamp: None,
+///     extended_header: None, // set by the builder
+/// };
+/// let extended_header = DltExtendedHeader::new_non_verbose_log(
+///     DltLogLevel::Info,
+///     *b"APP1",
+///     *b"CTX1",
+/// );
+///
+/// let mut builder = VerboseMessageBuilder::<256>::new(header, extended_header).unwrap();
+/// builder.push(&VerboseValue::U32(U32Value {
+///     variable_info: None,
+///     scaling: None,
+///     value: 1234,
+/// })).unwrap();
+/// let bytes = builder.finalize().unwrap();
+///
+/// let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+/// assert_eq!(Some(1), slice.verbose_arg_count());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerboseMessageBuilder<const CAP: usize> {
+    header: DltHeader,
+    /// Buffer containing space for the header followed by the already
+    /// pushed values.
+    buffer: ArrayVec<u8, CAP>,
+}
+
+impl<const CAP: usize> VerboseMessageBuilder<CAP> {
+    /// Creates a new builder without any values.
+    ///
+    /// The `length` & `extended_header` fields of the given header are
+    /// ignored and set when the message is finalized. An error is returned
+    /// if `CAP` is too small for the header.
+    pub fn new(
+        header: DltHeader,
+        extended_header: DltExtendedHeader,
+    ) -> Result<VerboseMessageBuilder<CAP>, EncodeError> {
+        let header = verbose_header(header, extended_header, 0);
+
+        // reserve the space for the header
+        let mut buffer = ArrayVec::new();
+        let header_len = usize::from(header.header_len());
+        if buffer.remaining_capacity() < header_len {
+            return Err(EncodeError::CapacityExceeded);
+        }
+        for _ in 0..header_len {
+            // SAFETY: Safe as the capacity was checked above.
+            unsafe { buffer.push_unchecked(0) };
+        }
+
+        Ok(VerboseMessageBuilder { header, buffer })
+    }
+
+    /// Number of values pushed to the builder.
+    #[inline]
+    pub fn number_of_arguments(&self) -> u8 {
+        self.header
+            .extended_header
+            .as_ref()
+            .map(|e| e.number_of_arguments)
+            .unwrap_or(0)
+    }
+
+    /// Serialized length of the message (header & pushed values) in
+    /// its current state.
+    #[inline]
+    pub fn message_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Encodes the given value & adds it as argument to the message.
+    ///
+    /// An error is returned if the value does not fit into the buffer or
+    /// if already 255 values were pushed. In this case the builder is left
+    /// unchanged.
+    pub fn push(&mut self, value: &VerboseValue) -> Result<(), EncodeError> {
+        let number_of_arguments = self
+            .number_of_arguments()
+            .checked_add(1)
+            .ok_or(EncodeError::CapacityExceeded)?;
+
+        let start = self.buffer.len();
+        if let Err(err) = value.add_to_msg(&mut self.buffer, self.header.is_big_endian) {
+            // remove partially written data
+            self.buffer.truncate(start);
+            return Err(err.into());
+        }
+
+        if let Some(extended_header) = self.header.extended_header.as_mut() {
+            extended_header.number_of_arguments = number_of_arguments;
+        }
+        Ok(())
+    }
+
+    /// Sets the header length & writes the header, returning the
+    /// complete serialized message.
+    ///
+    /// An error is returned if the message would be bigger then the
+    /// maximum DLT message length of 65535 bytes.
+    pub fn finalize(self) -> Result<ArrayVec<u8, CAP>, EncodeError> {
+        let VerboseMessageBuilder {
+            mut header,
+            mut buffer,
+        } = self;
+        header.length = message_length_field(buffer.len())?;
+        let header_bytes = header.to_bytes();
+        buffer[..header_bytes.len()].copy_from_slice(&header_bytes);
+        Ok(buffer)
+    }
+}
+
+/// Returns the header with the given extended header set up for a
+/// verbose message with the given number of arguments.
+pub(crate) fn verbose_header(
+    mut header: DltHeader,
+    mut extended_header: DltExtendedHeader,
+    number_of_arguments: u8,
+) -> DltHeader {
+    extended_header.set_is_verbose(true);
+    extended_header.number_of_arguments = number_of_arguments;
+    header.extended_header = Some(extended_header);
+    header
+}
+
+/// Converts the complete length of an encoded message into the value
+/// of the header length field.
+pub(crate) fn message_length_field(len: usize) -> Result<u16, EncodeError> {
+    u16::try_from(len).map_err(|_| EncodeError::MessageTooLong { len })
+}
+
+#[cfg(test)]
+mod verbose_message_builder_tests {
+    use super::*;
+    use crate::test_packets::{header, log_extended_header};
+    use crate::verbose::{BoolValue, StringEncoding, StringValue, U32Value};
+
+    fn extended_header() -> DltExtendedHeader {
+        log_extended_header(DltLogLevel::Warn)
+    }
+
+    #[test]
+    fn new() {
+        let builder = VerboseMessageBuilder::<64>::new(header(true), extended_header()).unwrap();
+        assert_eq!(0, builder.number_of_arguments());
+        assert_eq!(22, builder.message_len());
+
+        // capacity too small for the header
+        assert!(VerboseMessageBuilder::<21>::new(header(true), extended_header()).is_err());
+    }
+
+    #[test]
+    fn push_finalize() {
+        // round trip with three arguments
+        for is_big_endian in [false, true] {
+            let values = [
+                VerboseValue::U32(U32Value {
+                    variable_info: None,
+                    scaling: None,
+                    value: 0x1234_5678,
+                }),
+                VerboseValue::Str(StringValue::new(Some("name"), "text")),
+                VerboseValue::Bool(BoolValue {
+                    name: None,
+                    value: true,
+                }),
+            ];
+
+            let mut builder =
+                VerboseMessageBuilder::<256>::new(header(is_big_endian), extended_header())
+                    .unwrap();
+            for value in &values {
+                builder.push(value).unwrap();
+            }
+            assert_eq!(3, builder.number_of_arguments());
+
+            let bytes = builder.finalize().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(usize::from(slice.header().length), bytes.len());
+            assert_eq!(Some(3), slice.verbose_arg_count());
+            assert_eq!(
+                Some(DltMessageType::Log(DltLogLevel::Warn)),
+                slice.message_type()
+            );
+            let mut iter = slice.verbose_value_iter().unwrap();
+            for value in &values {
+                assert_eq!(Some(Ok(value.clone())), iter.next());
+            }
+            assert_eq!(None, iter.next());
+        }
+
+        // no arguments
+        {
+            let builder =
+                VerboseMessageBuilder::<64>::new(header(true), extended_header()).unwrap();
+            let bytes = builder.finalize().unwrap();
+            let slice = DltPacketSlice::from_slice(&bytes).unwrap();
+            assert!(slice.is_verbose());
+            assert_eq!(Some(0), slice.verbose_arg_count());
+            assert_eq!(usize::from(slice.header().header_len()), bytes.len());
+        }
+    }
+
+    #[test]
+    fn push_errors() {
+        let value = VerboseValue::Bool(BoolValue {
+            name: None,
+            value: false,
+        });
+
+        // value does not fit, builder stays unchanged
+        {
+            let mut builder =
+                VerboseMessageBuilder::<30>::new(header(true), extended_header()).unwrap();
+            builder.push(&value).unwrap();
+            let before = builder.clone();
+            assert_eq!(Err(EncodeError::CapacityExceeded), builder.push(&value));
+            assert_eq!(before, builder);
+        }
+
+        // too many arguments
+        {
+            let mut builder =
+                VerboseMessageBuilder::<2048>::new(header(true), extended_header()).unwrap();
+            for _ in 0..255 {
+                builder.push(&value).unwrap();
+            }
+            let before = builder.clone();
+            assert_eq!(Err(EncodeError::CapacityExceeded), builder.push(&value));
+            assert_eq!(before, builder);
+            assert_eq!(255, builder.number_of_arguments());
+        }
+    }
+
+    #[test]
+    fn finalize_too_big() {
+        let text = [b'a'; 0x1_0000];
+        let mut builder =
+            VerboseMessageBuilder::<0x2_0000>::new(header(true), extended_header()).unwrap();
+        builder
+            .push(&VerboseValue::Str(StringValue::with_encoding(
+                None,
+                StringEncoding::Ascii,
+                &text[..0xfff0],
+            )))
+            .unwrap();
+        assert_eq!(
+            Err(EncodeError::MessageTooLong {
+                len: 22 + 4 + 2 + 0xfff0 + 1
+            }),
+            builder.finalize()
+        );
+    }
+}